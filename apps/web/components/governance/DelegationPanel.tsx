@@ -8,7 +8,7 @@ import { toast } from "sonner";
 import { PublicKey } from "@solana/web3.js";
 import { Program } from "@coral-xyz/anchor";
 import type { Idl } from "@coral-xyz/anchor";
-import { useAnchorProvider, PROGRAM_ID } from "@/lib/anchor/provider";
+import { useAnchorProvider, PROGRAM_ID, REALM } from "@/lib/anchor/provider";
 import { getDelegationPDA } from "@gsd/utils";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
@@ -106,7 +106,7 @@ export function DelegationPanel() {
       const program = new Program(idlJson as Idl, provider);
 
       const delegatePubkey = new PublicKey(delegateAddress);
-      const [delegationPda] = getDelegationPDA(publicKey, PROGRAM_ID);
+      const [delegationPda] = getDelegationPDA(REALM, publicKey, PROGRAM_ID);
 
       const tx = await program.methods
         .delegateVote()
@@ -152,7 +152,7 @@ export function DelegationPanel() {
       setActionStep("Confirm revocation in your wallet...");
       const program = new Program(idlJson as Idl, provider);
 
-      const [delegationPda] = getDelegationPDA(publicKey, PROGRAM_ID);
+      const [delegationPda] = getDelegationPDA(REALM, publicKey, PROGRAM_ID);
 
       const tx = await program.methods
         .revokeDelegation()
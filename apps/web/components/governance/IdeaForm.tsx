@@ -6,7 +6,7 @@ import { useSession } from "next-auth/react";
 import { useQueryClient } from "@tanstack/react-query";
 import { toast } from "sonner";
 import { PublicKey } from "@solana/web3.js";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import type { Idl } from "@coral-xyz/anchor";
 import { getIdeaPDA } from "@gsd/utils";
 import { useAnchorProvider, PROGRAM_ID } from "@/lib/anchor/provider";
@@ -87,7 +87,7 @@ export function IdeaForm({
       const [ideaPda] = getIdeaPDA(roundKey, ideaCount, PROGRAM_ID);
 
       const tx = await program.methods
-        .submitIdea(hashArray)
+        .submitIdea(hashArray, new BN(0))
         .accounts({
           author: publicKey,
           ideaRound: roundKey,
@@ -5,7 +5,7 @@ import { useQuery } from "@tanstack/react-query";
 import { Button } from "@/components/ui/button";
 import { truncateAddress } from "@/lib/config/transparency-config";
 
-type RoundStatus = "open" | "voting" | "closed";
+type RoundStatus = "open" | "voting" | "closed" | "cancelled";
 
 interface IdeaData {
   id: string;
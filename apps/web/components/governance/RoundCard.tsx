@@ -14,7 +14,7 @@ interface RoundCardRound {
   id: string;
   title: string;
   description: string;
-  status: "open" | "voting" | "closed";
+  status: "open" | "voting" | "closed" | "cancelled";
   submissionStart: string;
   submissionEnd: string;
   votingEnd: string;
@@ -2,7 +2,7 @@
 
 import { Badge } from "@/components/ui/badge";
 
-type RoundStatus = "open" | "voting" | "closed";
+type RoundStatus = "open" | "voting" | "closed" | "cancelled";
 
 interface RoundStatusBadgeProps {
   status: RoundStatus;
@@ -36,6 +36,10 @@ function getEffectiveStatus(
     return { label: "Voting", variant: "warning" };
   }
 
+  if (status === "cancelled") {
+    return { label: "Cancelled", variant: "secondary" };
+  }
+
   return { label: "Closed", variant: "secondary" };
 }
 
@@ -186,7 +186,7 @@ export function VotePanel({
       const voteArg = { [choice]: {} };
 
       const tx = await program.methods
-        .castVote(voteArg)
+        .castVote(voteArg, null)
         .accounts({
           voter: publicKey,
           ideaRound: roundKey,
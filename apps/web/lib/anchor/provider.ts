@@ -15,6 +15,11 @@ const PROGRAM_ID = new PublicKey(
     "Gn3kafdEiBZ51T5ewMTtXLUDYzECk87kPwxDAjspqYhw"
 );
 
+// Realm whose governance, revenue and verification PDAs this app targets
+const REALM = new PublicKey(
+  process.env.NEXT_PUBLIC_GOVERNANCE_REALM || PublicKey.default.toBase58()
+);
+
 export function useAnchorProvider(): AnchorProvider | null {
   const { connection } = useConnection();
   const wallet = useAnchorWallet();
@@ -36,4 +41,4 @@ export function useGsdProgram(idl: Idl | null): Program | null {
   }, [provider, idl]);
 }
 
-export { PROGRAM_ID };
+export { PROGRAM_ID, REALM };
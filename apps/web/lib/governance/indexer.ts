@@ -93,7 +93,7 @@ function encodeBase58(bytes: Uint8Array): string {
 
 /**
  * Map Anchor RoundStatus enum variant index to string.
- * Borsh serialization uses a single byte for enum discriminant (0, 1, 2).
+ * Borsh serialization uses a single byte for enum discriminant (0, 1, 2, 3).
  */
 function parseRoundStatus(byte: number): string {
   switch (byte) {
@@ -103,6 +103,8 @@ function parseRoundStatus(byte: number): string {
       return "voting";
     case 2:
       return "closed";
+    case 3:
+      return "cancelled";
     default:
       return "open";
   }
@@ -137,6 +139,8 @@ function parseIdeaStatus(byte: number): string {
       return "rejected";
     case 3:
       return "vetoed";
+    case 4:
+      return "void";
     default:
       return "submitted";
   }
@@ -443,6 +447,7 @@ async function processTransitionRound(
  *
  * Instruction data after 8-byte discriminator:
  *   vote: u8 (1 byte -- VoteChoice enum variant)
+ *   reason_hash: Option<[u8; 32]> (1-byte tag + 32 bytes if Some)
  *
  * The weight comes from the VoteDeposit's deposited_amount. We read
  * it from the database since it was indexed when the deposit occurred.
@@ -458,6 +463,8 @@ async function processCastVote(
   const voteChoiceByte = ixData[8];
 
   const voteChoice = parseVoteChoice(voteChoiceByte);
+  const reasonHash =
+    ixData[9] === 1 ? bytesToHex(ixData.slice(10, 42)) : null;
 
   // Look up the idea
   const idea = await prisma.idea.findUnique({
@@ -491,6 +498,7 @@ async function processCastVote(
     update: {
       vote: voteChoice,
       weight,
+      reasonHash,
       transactionSignature: signature,
     },
     create: {
@@ -499,6 +507,7 @@ async function processCastVote(
       voterWallet,
       vote: voteChoice,
       weight,
+      reasonHash,
       transactionSignature: signature,
       votedAt: new Date(),
     },
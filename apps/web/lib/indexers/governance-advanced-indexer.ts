@@ -185,7 +185,7 @@ export async function processAdvancedGovernanceEvent(transaction: {
  *
  * Accounts (from DelegateVote struct):
  *   [0] governance_config
- *   [1] delegation_record (PDA) -- seeds: ["delegation", delegator.key()]
+ *   [1] delegation_record (PDA) -- seeds: ["delegation", realm, delegator.key()]
  *   [2] vote_deposit (delegator's deposit)
  *   [3] delegator (signer)
  *   [4] delegate
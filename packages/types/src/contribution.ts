@@ -30,6 +30,36 @@ export interface ContributionData {
   contentHash: string;
 }
 
+/** Kind of work recorded by a v2 contribution leaf (Borsh enum tag order). */
+export type ContributionKind = "code" | "review" | "docs" | "design";
+
+/**
+ * Contribution data matching the on-chain ContributionLeafV2 fields.
+ *
+ * Serialized with a leading version byte (2) followed by the v1 fields and:
+ * - kind: 1 byte (enum tag)
+ * - projectId: 32 bytes (SHA-256 hash of the project or repository id)
+ * - difficultyWeight: 2 bytes (u16, basis points, 10000 = 1.0x)
+ * - coAuthorCount: 1 byte (u8, co-authors besides the developer)
+ * - shareBps: 2 bytes (u16, this developer's share in basis points)
+ *
+ * A co-authored contribution is recorded as one leaf per author.
+ *
+ * Total serialized: 145 bytes
+ */
+export interface ContributionDataV2 extends ContributionData {
+  /** Kind of work */
+  kind: ContributionKind;
+  /** SHA-256 hash of the project or repository identifier (hex string, 32 bytes on-chain) */
+  projectId: string;
+  /** Difficulty multiplier in basis points, 10000 = 1.0x */
+  difficultyWeight: number;
+  /** Number of co-authors besides the developer, 0 = solo */
+  coAuthorCount: number;
+  /** This developer's share of the contribution in basis points (10000 = solo) */
+  shareBps: number;
+}
+
 /**
  * Full contribution record including off-chain metadata.
  * Extends ContributionData with database fields.
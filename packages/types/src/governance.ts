@@ -13,13 +13,18 @@
 // --- Enums (matching on-chain Rust enums) ---
 
 /** Matches on-chain RoundStatus enum (idea_round.rs) */
-export type RoundStatus = "open" | "voting" | "closed";
+export type RoundStatus = "open" | "voting" | "closed" | "cancelled";
 
 /** Matches on-chain QuorumType enum (idea_round.rs) */
 export type QuorumType = "small" | "treasury" | "parameter_change";
 
 /** Matches on-chain IdeaStatus enum (idea.rs) */
-export type IdeaStatus = "submitted" | "approved" | "rejected" | "vetoed";
+export type IdeaStatus =
+  | "submitted"
+  | "approved"
+  | "rejected"
+  | "vetoed"
+  | "void";
 
 /** Matches on-chain VoteChoice enum (vote_record.rs) */
 export type VoteChoice = "yes" | "no" | "abstain";
@@ -113,6 +118,8 @@ export interface VoteRecord {
   vote: VoteChoice;
   /** Token weight applied to this vote (string-serialized u64) */
   weight: string;
+  /** SHA-256 hash of the voter's off-chain rationale, or null if none */
+  reasonHash: string | null;
   /** Timestamp when vote was cast */
   votedAt: Date;
 }
@@ -6,6 +6,8 @@ export type {
 
 export type {
   ContributionData,
+  ContributionDataV2,
+  ContributionKind,
   ContributionRecord,
   ContributionScore,
 } from "./contribution";
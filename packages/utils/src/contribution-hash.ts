@@ -1,4 +1,4 @@
-import type { ContributionData } from "@gsd/types";
+import type { ContributionData, ContributionDataV2, ContributionKind } from "@gsd/types";
 import { PublicKey } from "@solana/web3.js";
 
 /**
@@ -89,3 +89,65 @@ export async function computeContributionLeafHash(
   const hashBuffer = await crypto.subtle.digest("SHA-256", serialized.buffer as ArrayBuffer);
   return bytesToHex(new Uint8Array(hashBuffer));
 }
+
+/** Leading version byte of ContributionLeafV2 payloads. */
+export const CONTRIBUTION_LEAF_V2_VERSION = 2;
+
+const CONTRIBUTION_KINDS: ContributionKind[] = ["code", "review", "docs", "design"];
+
+/**
+ * Serialize a ContributionDataV2 object into a 145-byte buffer matching
+ * the on-chain Borsh layout of ContributionLeafV2.
+ *
+ * Layout (all multi-byte integers are little-endian):
+ *   [0]        version      - u8, always 2
+ *   [1..107)   the 106-byte v1 layout (see serializeContributionLeaf)
+ *   [107]      kind         - u8 enum tag (code, review, docs, design)
+ *   [108..140) project_id   - [u8; 32]
+ *   [140..142) difficulty_weight - u16 LE
+ *   [142]      co_author_count   - u8
+ *   [143..145) share_bps    - u16 LE
+ *
+ * Total: 145 bytes. v1 payloads are always 106 bytes, so the length and
+ * leading byte tell the two encodings apart.
+ */
+export function serializeContributionLeafV2(data: ContributionDataV2): Uint8Array {
+  const buf = new Uint8Array(145);
+  const view = new DataView(buf.buffer);
+
+  buf[0] = CONTRIBUTION_LEAF_V2_VERSION;
+  buf.set(serializeContributionLeaf(data), 1);
+
+  const kind = CONTRIBUTION_KINDS.indexOf(data.kind);
+  if (kind < 0) {
+    throw new Error(`Unknown contribution kind: ${data.kind}`);
+  }
+  buf[107] = kind;
+
+  const projectIdBytes = hexToBytes(data.projectId);
+  if (projectIdBytes.length !== 32) {
+    throw new Error(`projectId must be 32 bytes (64 hex chars), got ${projectIdBytes.length}`);
+  }
+  buf.set(projectIdBytes, 108);
+
+  view.setUint16(140, data.difficultyWeight, true);
+  buf[142] = data.coAuthorCount;
+  view.setUint16(143, data.shareBps, true);
+
+  return buf;
+}
+
+/**
+ * Compute the SHA-256 leaf hash of a v2 contribution, matching the
+ * on-chain `ContributionLeafV2::to_leaf_hash()` method. The version byte
+ * is hashed too, so v1 and v2 leaves never share a hash.
+ *
+ * @returns Lowercase hex string of the 32-byte SHA-256 hash
+ */
+export async function computeContributionLeafV2Hash(
+  data: ContributionDataV2
+): Promise<string> {
+  const serialized = serializeContributionLeafV2(data);
+  const hashBuffer = await crypto.subtle.digest("SHA-256", serialized.buffer as ArrayBuffer);
+  return bytesToHex(new Uint8Array(hashBuffer));
+}
@@ -8,6 +8,13 @@ export const IDEA_SEED = "idea";
 export const VOTE_DEPOSIT_SEED = "vote_deposit";
 export const VOTE_RECORD_SEED = "vote_record";
 export const DELEGATION_SEED = "delegation";
+export const ROUND_SCHEDULE_SEED = "round_schedule";
+export const ROUND_BUDGET_SEED = "round_budget";
+export const IDEA_ESCROW_SEED = "idea_escrow";
+export const DELEGATE_PROFILE_SEED = "delegate_profile";
+export const PAUSE_CONFIG_SEED = "pause_config";
+export const ROLE_REGISTRY_SEED = "role_registry";
+export const OPERATOR_ROLE_SEED = "operator_role";
 
 /**
  * Encode a number as a little-endian u32 buffer (4 bytes).
@@ -20,28 +27,34 @@ function u32ToLeBytes(value: number): Buffer {
 }
 
 /**
- * Derive the GovernanceConfig singleton PDA.
- * Seeds: ["governance_config"]
+ * Derive the GovernanceConfig PDA of a realm.
+ * Seeds: ["governance_config", realm]
  */
 export function getGovernanceConfigPDA(
+  realm: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(GOVERNANCE_CONFIG_SEED)],
+    [Buffer.from(GOVERNANCE_CONFIG_SEED), realm.toBuffer()],
     programId
   );
 }
 
 /**
- * Derive an IdeaRound PDA by round index.
- * Seeds: ["idea_round", round_index.to_le_bytes()]
+ * Derive an IdeaRound PDA by realm and round index.
+ * Seeds: ["idea_round", realm, round_index.to_le_bytes()]
  */
 export function getIdeaRoundPDA(
+  realm: PublicKey,
   roundIndex: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(IDEA_ROUND_SEED), u32ToLeBytes(roundIndex)],
+    [
+      Buffer.from(IDEA_ROUND_SEED),
+      realm.toBuffer(),
+      u32ToLeBytes(roundIndex),
+    ],
     programId
   );
 }
@@ -62,15 +75,16 @@ export function getIdeaPDA(
 }
 
 /**
- * Derive a VoteDeposit PDA by wallet address.
- * Seeds: ["vote_deposit", authority.key()]
+ * Derive a VoteDeposit PDA by realm and wallet address.
+ * Seeds: ["vote_deposit", realm, authority.key()]
  */
 export function getVoteDepositPDA(
+  realm: PublicKey,
   wallet: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(VOTE_DEPOSIT_SEED), wallet.toBuffer()],
+    [Buffer.from(VOTE_DEPOSIT_SEED), realm.toBuffer(), wallet.toBuffer()],
     programId
   );
 }
@@ -91,15 +105,119 @@ export function getVoteRecordPDA(
 }
 
 /**
- * Derive a DelegationRecord PDA by delegator wallet.
- * Seeds: ["delegation", delegator.key()]
+ * Derive a DelegationRecord PDA by realm and delegator wallet.
+ * Seeds: ["delegation", realm, delegator.key()]
  */
 export function getDelegationPDA(
+  realm: PublicKey,
   delegator: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(DELEGATION_SEED), delegator.toBuffer()],
+    [Buffer.from(DELEGATION_SEED), realm.toBuffer(), delegator.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive the RoundSchedule PDA of a realm.
+ * Seeds: ["round_schedule", realm]
+ */
+export function getRoundSchedulePDA(
+  realm: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(ROUND_SCHEDULE_SEED), realm.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive the budget escrow token account PDA of a round.
+ * Seeds: ["round_budget", idea_round.key()]
+ */
+export function getRoundBudgetPDA(
+  ideaRound: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(ROUND_BUDGET_SEED), ideaRound.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive the payout escrow token account PDA of an idea.
+ * Seeds: ["idea_escrow", idea.key()]
+ */
+export function getIdeaEscrowPDA(
+  idea: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(IDEA_ESCROW_SEED), idea.toBuffer()],
+    programId
+  );
+}
+
+/**
+ * Derive the DelegateProfile PDA of a delegate wallet within a realm.
+ * Seeds: ["delegate_profile", realm, delegate.key()]
+ */
+export function getDelegateProfilePDA(
+  realm: PublicKey,
+  delegate: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(DELEGATE_PROFILE_SEED),
+      realm.toBuffer(),
+      delegate.toBuffer(),
+    ],
+    programId
+  );
+}
+
+/**
+ * Derive the program-wide PauseConfig singleton PDA.
+ * Seeds: ["pause_config"]
+ */
+export function getPauseConfigPDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PAUSE_CONFIG_SEED)],
+    programId
+  );
+}
+
+/**
+ * Derive the program-wide RoleRegistry singleton PDA.
+ * Seeds: ["role_registry"]
+ */
+export function getRoleRegistryPDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(ROLE_REGISTRY_SEED)],
+    programId
+  );
+}
+
+/**
+ * Derive the OperatorRole PDA holding an operator key's role bitmask in a realm.
+ * Hub-wide roles (score oracle, tree writer) use `PublicKey.default` as the realm.
+ * Seeds: ["operator_role", realm, holder]
+ */
+export function getOperatorRolePDA(
+  realm: PublicKey,
+  holder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(OPERATOR_ROLE_SEED), realm.toBuffer(), holder.toBuffer()],
     programId
   );
 }
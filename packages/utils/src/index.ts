@@ -1,8 +1,24 @@
-export { getDeveloperProfilePDA, DEVELOPER_SEED } from "./pda";
+export {
+  getDeveloperProfilePDA,
+  getScoreConfigPDA,
+  getScoreAuditEntryPDA,
+  getContributionAttestationPDA,
+  getContributionNullifierPDA,
+  getContributionTreeRegistryPDA,
+  DEVELOPER_SEED,
+  SCORE_CONFIG_SEED,
+  SCORE_AUDIT_SEED,
+  CONTRIBUTION_ATTESTATION_SEED,
+  CONTRIBUTION_NULLIFIER_SEED,
+  CONTRIBUTION_TREE_REGISTRY_SEED,
+} from "./pda";
 export { computeProfileHash, profileHashToBytes32 } from "./hash";
 export {
   computeContributionLeafHash,
   serializeContributionLeaf,
+  computeContributionLeafV2Hash,
+  serializeContributionLeafV2,
+  CONTRIBUTION_LEAF_V2_VERSION,
   hexToBytes,
   bytesToHex,
 } from "./contribution-hash";
@@ -22,12 +38,26 @@ export {
   getVoteDepositPDA,
   getVoteRecordPDA,
   getDelegationPDA,
+  getRoundSchedulePDA,
+  getRoundBudgetPDA,
+  getIdeaEscrowPDA,
+  getDelegateProfilePDA,
+  getPauseConfigPDA,
+  getRoleRegistryPDA,
+  getOperatorRolePDA,
   GOVERNANCE_CONFIG_SEED,
   IDEA_ROUND_SEED,
   IDEA_SEED,
   VOTE_DEPOSIT_SEED,
   VOTE_RECORD_SEED,
   DELEGATION_SEED,
+  ROUND_SCHEDULE_SEED,
+  ROUND_BUDGET_SEED,
+  IDEA_ESCROW_SEED,
+  DELEGATE_PROFILE_SEED,
+  PAUSE_CONFIG_SEED,
+  ROLE_REGISTRY_SEED,
+  OPERATOR_ROLE_SEED,
 } from "./governance-pda";
 export {
   getRevenueConfigPDA,
@@ -1,6 +1,11 @@
 import { PublicKey } from "@solana/web3.js";
 
 export const DEVELOPER_SEED = "developer";
+export const SCORE_CONFIG_SEED = "score_config";
+export const SCORE_AUDIT_SEED = "score_audit";
+export const CONTRIBUTION_ATTESTATION_SEED = "contribution_attestation";
+export const CONTRIBUTION_NULLIFIER_SEED = "contribution_nullifier";
+export const CONTRIBUTION_TREE_REGISTRY_SEED = "contribution_tree_registry";
 
 export function getDeveloperProfilePDA(
   wallet: PublicKey,
@@ -11,3 +16,86 @@ export function getDeveloperProfilePDA(
     programId
   );
 }
+
+/**
+ * Derive the program-wide ScoreConfig PDA holding per-update score limits.
+ * Seeds: ["score_config"]
+ */
+export function getScoreConfigPDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SCORE_CONFIG_SEED)],
+    programId
+  );
+}
+
+/**
+ * Derive the ScoreAuditEntry PDA of a developer's nth score update.
+ * Seeds: ["score_audit", wallet, sequence (u32 LE)]
+ */
+export function getScoreAuditEntryPDA(
+  wallet: PublicKey,
+  sequence: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  const sequenceBytes = Buffer.alloc(4);
+  sequenceBytes.writeUInt32LE(sequence);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SCORE_AUDIT_SEED), wallet.toBuffer(), sequenceBytes],
+    programId
+  );
+}
+
+/**
+ * Derive the ContributionAttestation PDA of a proven contribution.
+ * Seeds: ["contribution_attestation", merkle_tree, wallet, task_ref]
+ */
+export function getContributionAttestationPDA(
+  merkleTree: PublicKey,
+  wallet: PublicKey,
+  taskRef: Uint8Array,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(CONTRIBUTION_ATTESTATION_SEED),
+      merkleTree.toBuffer(),
+      wallet.toBuffer(),
+      Buffer.from(taskRef),
+    ],
+    programId
+  );
+}
+
+/**
+ * Derive the ContributionNullifier PDA marking a task as counted for a developer.
+ * Seeds: ["contribution_nullifier", wallet, task_ref]
+ */
+export function getContributionNullifierPDA(
+  wallet: PublicKey,
+  taskRef: Uint8Array,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(CONTRIBUTION_NULLIFIER_SEED),
+      wallet.toBuffer(),
+      Buffer.from(taskRef),
+    ],
+    programId
+  );
+}
+
+/**
+ * Derive the program-wide ContributionTreeRegistry PDA listing every contribution tree.
+ * Seeds: ["contribution_tree_registry"]
+ */
+export function getContributionTreeRegistryPDA(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(CONTRIBUTION_TREE_REGISTRY_SEED)],
+    programId
+  );
+}
@@ -18,28 +18,34 @@ function u32ToLeBytes(value: number): Buffer {
 }
 
 /**
- * Derive the RevenueConfig singleton PDA.
- * Seeds: ["revenue_config"]
+ * Derive the RevenueConfig PDA of a realm.
+ * Seeds: ["revenue_config", realm]
  */
 export function getRevenueConfigPDA(
+  realm: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(REVENUE_CONFIG_SEED)],
+    [Buffer.from(REVENUE_CONFIG_SEED), realm.toBuffer()],
     programId
   );
 }
 
 /**
- * Derive a RevenueEvent PDA by event index.
- * Seeds: ["revenue_event", event_index.to_le_bytes()]
+ * Derive a RevenueEvent PDA by realm and event index.
+ * Seeds: ["revenue_event", realm, event_index.to_le_bytes()]
  */
 export function getRevenueEventPDA(
+  realm: PublicKey,
   eventIndex: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(REVENUE_EVENT_SEED), u32ToLeBytes(eventIndex)],
+    [
+      Buffer.from(REVENUE_EVENT_SEED),
+      realm.toBuffer(),
+      u32ToLeBytes(eventIndex),
+    ],
     programId
   );
 }
@@ -64,18 +70,23 @@ export function getRevenueClaimPDA(
 }
 
 /**
- * Derive a RevenueVault PDA by event index.
- * Seeds: ["revenue_vault", event_index.to_le_bytes()]
+ * Derive a RevenueVault PDA by realm and event index.
+ * Seeds: ["revenue_vault", realm, event_index.to_le_bytes()]
  *
  * This is a SystemAccount PDA that holds SOL for the developer pool.
  * For USDC: the vault is an ATA derived from this PDA.
  */
 export function getRevenueVaultPDA(
+  realm: PublicKey,
   eventIndex: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(REVENUE_VAULT_SEED), u32ToLeBytes(eventIndex)],
+    [
+      Buffer.from(REVENUE_VAULT_SEED),
+      realm.toBuffer(),
+      u32ToLeBytes(eventIndex),
+    ],
     programId
   );
 }
@@ -8,29 +8,36 @@ export const PEER_REVIEW_SEED = "peer_review";
 export const REVIEWER_PROFILE_SEED = "reviewer";
 
 /**
- * Derive the VerificationConfig singleton PDA.
- * Seeds: ["verification_config"]
+ * Derive the VerificationConfig PDA of a realm.
+ * Seeds: ["verification_config", realm]
  */
 export function getVerificationConfigPDA(
+  realm: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(VERIFICATION_CONFIG_SEED)],
+    [Buffer.from(VERIFICATION_CONFIG_SEED), realm.toBuffer()],
     programId
   );
 }
 
 /**
- * Derive a VerificationReport PDA by developer and task reference.
- * Seeds: ["verification", developer.key(), task_ref]
+ * Derive a VerificationReport PDA by issuing realm, developer and task reference.
+ * Seeds: ["verification", realm, developer.key(), task_ref]
  */
 export function getVerificationReportPDA(
+  realm: PublicKey,
   developer: PublicKey,
   taskRef: Buffer,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(VERIFICATION_REPORT_SEED), developer.toBuffer(), taskRef],
+    [
+      Buffer.from(VERIFICATION_REPORT_SEED),
+      realm.toBuffer(),
+      developer.toBuffer(),
+      taskRef,
+    ],
     programId
   );
 }
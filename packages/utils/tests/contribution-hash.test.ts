@@ -2,9 +2,11 @@ import { describe, it } from "node:test";
 import assert from "node:assert/strict";
 import {
   computeContributionLeafHash,
+  computeContributionLeafV2Hash,
   serializeContributionLeaf,
+  serializeContributionLeafV2,
 } from "../src/contribution-hash.js";
-import type { ContributionData } from "@gsd/types";
+import type { ContributionData, ContributionDataV2 } from "@gsd/types";
 
 /**
  * Test data: a known contribution for deterministic testing.
@@ -148,3 +150,47 @@ describe("computeContributionLeafHash", () => {
     assert.match(hash, /^[0-9a-f]{64}$/, "Hash must be a valid lowercase hex string");
   });
 });
+
+const TEST_CONTRIBUTION_V2: ContributionDataV2 = {
+  ...TEST_CONTRIBUTION,
+  kind: "review",
+  projectId: "42".repeat(32),
+  difficultyWeight: 15000,
+  coAuthorCount: 1,
+  shareBps: 7000,
+};
+
+describe("serializeContributionLeafV2", () => {
+  it("prefixes the v1 layout with version byte 2", () => {
+    const bytes = serializeContributionLeafV2(TEST_CONTRIBUTION_V2);
+    assert.equal(bytes.length, 145);
+    assert.equal(bytes[0], 2);
+    assert.deepEqual(
+      bytes.slice(1, 107),
+      serializeContributionLeaf(TEST_CONTRIBUTION)
+    );
+  });
+
+  it("serializes kind, project_id, difficulty_weight, co_author_count and share_bps", () => {
+    const bytes = serializeContributionLeafV2(TEST_CONTRIBUTION_V2);
+    assert.equal(bytes[107], 1, "review is enum tag 1");
+    assert.equal(bytes[108], 0x42);
+    assert.equal(bytes[139], 0x42);
+    // 15000 = 0x3A98, little-endian: [0x98, 0x3A]
+    assert.equal(bytes[140], 0x98);
+    assert.equal(bytes[141], 0x3a);
+    assert.equal(bytes[142], 1);
+    // 7000 = 0x1B58, little-endian: [0x58, 0x1B]
+    assert.equal(bytes[143], 0x58);
+    assert.equal(bytes[144], 0x1b);
+  });
+});
+
+describe("computeContributionLeafV2Hash", () => {
+  it("differs from the v1 hash of the same contribution", async () => {
+    const v1 = await computeContributionLeafHash(TEST_CONTRIBUTION);
+    const v2 = await computeContributionLeafV2Hash(TEST_CONTRIBUTION_V2);
+    assert.match(v2, /^[0-9a-f]{64}$/);
+    assert.notEqual(v1, v2);
+  });
+});
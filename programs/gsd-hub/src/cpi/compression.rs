@@ -73,6 +73,80 @@ pub fn append_leaf<'info>(
     Ok(())
 }
 
+/// A single leaf swap for `replace_leaf`, proven against `root`.
+pub struct LeafReplacement {
+    /// Tree root the proof was built against; must still be in the changelog
+    pub root: [u8; 32],
+    /// 32-byte leaf hash currently stored at `index`
+    pub previous_leaf: [u8; 32],
+    /// 32-byte leaf hash to store instead
+    pub new_leaf: [u8; 32],
+    /// Leaf index in the tree
+    pub index: u32,
+}
+
+/// Replace a leaf of a concurrent Merkle tree via raw CPI to spl-account-compression.
+/// The CPI fails unless `previous_leaf` is proven at `index`.
+///
+/// # Arguments
+/// * `merkle_tree` - The concurrent Merkle tree account (writable)
+/// * `authority` - The tree authority (signer)
+/// * `noop_program` - The spl-noop program for logging
+/// * `compression_program` - The spl-account-compression program
+/// * `proof` - Proof nodes, leaf level first (typically `ctx.remaining_accounts`)
+/// * `replacement` - Root, previous and new leaf hashes, and leaf index
+/// * `signer_seeds` - If Some, uses invoke_signed (PDA authority); if None, uses invoke
+pub fn replace_leaf<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    replacement: &LeafReplacement,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> Result<()> {
+    // Instruction data: 8-byte discriminator + 32-byte root + 32-byte previous
+    // leaf + 32-byte new leaf + u32 index
+    let discriminator = anchor_discriminator("replace_leaf");
+    let mut data = Vec::with_capacity(108);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(&replacement.root);
+    data.extend_from_slice(&replacement.previous_leaf);
+    data.extend_from_slice(&replacement.new_leaf);
+    data.extend_from_slice(&replacement.index.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new(*merkle_tree.key, false),
+        AccountMeta::new_readonly(*authority.key, true),
+        AccountMeta::new_readonly(*noop_program.key, false),
+    ];
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node.key, false)),
+    );
+
+    let ix = Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    };
+
+    let mut account_infos = Vec::with_capacity(proof.len() + 4);
+    account_infos.push(merkle_tree.clone());
+    account_infos.push(authority.clone());
+    account_infos.push(noop_program.clone());
+    account_infos.extend(proof.iter().cloned());
+    account_infos.push(compression_program.clone());
+
+    match signer_seeds {
+        Some(seeds) => invoke_signed(&ix, &account_infos, seeds)?,
+        None => invoke(&ix, &account_infos)?,
+    }
+
+    Ok(())
+}
+
 /// Initialize an empty concurrent Merkle tree via raw CPI to spl-account-compression.
 ///
 /// # Arguments
@@ -125,3 +199,114 @@ pub fn init_empty_merkle_tree<'info>(
 
     Ok(())
 }
+
+/// Hand a concurrent Merkle tree to a new authority via raw CPI to spl-account-compression.
+///
+/// # Arguments
+/// * `merkle_tree` - The concurrent Merkle tree account (writable)
+/// * `authority` - The current tree authority (signer)
+/// * `compression_program` - The spl-account-compression program
+/// * `new_authority` - Key that will own the tree afterwards
+/// * `signer_seeds` - If Some, uses invoke_signed (PDA authority); if None, uses invoke
+pub fn transfer_authority<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    new_authority: Pubkey,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> Result<()> {
+    // Instruction data: 8-byte discriminator + 32-byte new authority
+    let discriminator = anchor_discriminator("transfer_authority");
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(new_authority.as_ref());
+
+    let accounts = vec![
+        AccountMeta::new(*merkle_tree.key, false),
+        AccountMeta::new_readonly(*authority.key, true),
+    ];
+
+    let ix = Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    };
+
+    let account_infos = &[
+        merkle_tree.clone(),
+        authority.clone(),
+        compression_program.clone(),
+    ];
+
+    match signer_seeds {
+        Some(seeds) => invoke_signed(&ix, account_infos, seeds)?,
+        None => invoke(&ix, account_infos)?,
+    }
+
+    Ok(())
+}
+
+/// Check that `leaf` sits at `index` of a concurrent Merkle tree via raw CPI
+/// to spl-account-compression. The CPI fails unless the proof is valid.
+///
+/// # Arguments
+/// * `merkle_tree` - The concurrent Merkle tree account (read-only)
+/// * `compression_program` - The spl-account-compression program
+/// * `proof` - Proof nodes, leaf level first (typically `ctx.remaining_accounts`)
+/// * `root` - Tree root the proof was built against; must still be in the changelog
+/// * `leaf` - 32-byte leaf hash to verify
+/// * `index` - Leaf index in the tree
+pub fn verify_leaf<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    // Instruction data: 8-byte discriminator + 32-byte root + 32-byte leaf + u32 index
+    let discriminator = anchor_discriminator("verify_leaf");
+    let mut data = Vec::with_capacity(76);
+    data.extend_from_slice(&discriminator);
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(*merkle_tree.key, false)];
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node.key, false)),
+    );
+
+    let ix = Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    };
+
+    let mut account_infos = Vec::with_capacity(proof.len() + 2);
+    account_infos.push(merkle_tree.clone());
+    account_infos.extend(proof.iter().cloned());
+    account_infos.push(compression_program.clone());
+
+    invoke(&ix, &account_infos)?;
+
+    Ok(())
+}
+
+/// Read `max_depth` from a concurrent Merkle tree account header.
+///
+/// Header layout: account_type[1] (1 = ConcurrentMerkleTree) | version[1] (0 = V1) |
+/// max_buffer_size[4] | max_depth[4] | authority[32] | creation_slot[8] | ...
+/// Returns None if the account is not an initialized V1 tree owned by spl-account-compression.
+pub fn tree_max_depth(merkle_tree: &AccountInfo) -> Option<u32> {
+    if merkle_tree.owner != &ACCOUNT_COMPRESSION_PROGRAM_ID {
+        return None;
+    }
+    let data = merkle_tree.try_borrow_data().ok()?;
+    if data.len() < 10 || data[0] != 1 || data[1] != 0 {
+        return None;
+    }
+    Some(u32::from_le_bytes(data[6..10].try_into().unwrap()))
+}
@@ -1,5 +1,7 @@
 pub mod compression;
 pub mod noop;
+pub mod resize;
 
 pub use compression::*;
 pub use noop::*;
+pub use resize::*;
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Grow a program-owned account written with an older, shorter layout to
+/// `new_len`, topping its rent up from `payer`.
+///
+/// The new bytes are zeroed, so every field appended since the account was
+/// created reads back as its default.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let transfer_ctx = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(transfer_ctx, shortfall)?;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
+
+/// Close a program-owned account held only as an `AccountInfo`, moving its
+/// rent to `destination` the way Anchor's `close` constraint does.
+pub fn close_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    destination.add_lamports(account.lamports())?;
+    account.sub_lamports(account.lamports())?;
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+
+    Ok(())
+}
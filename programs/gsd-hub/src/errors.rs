@@ -25,6 +25,75 @@ pub enum GsdHubError {
 
     #[msg("Contribution score calculation overflow")]
     ScoreOverflow,
+
+    #[msg("Account does not hold the expected type")]
+    InvalidMigrationAccount,
+
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Score increase exceeds the per-update limit")]
+    ScoreIncreaseTooLarge,
+
+    #[msg("Tasks completed increase exceeds the per-update limit")]
+    TaskIncreaseTooLarge,
+
+    #[msg("Score version cannot go backwards")]
+    ScoreVersionRegressed,
+
+    #[msg("Score was updated by the oracle too recently")]
+    ScoreUpdateTooSoon,
+
+    #[msg("Total verification score exceeds 10000 per completed task")]
+    VerificationTotalTooLarge,
+
+    #[msg("Active days exceed the age of the profile")]
+    ActiveDaysTooLarge,
+
+    #[msg("Profile is scored from proven contributions; the oracle cannot overwrite it")]
+    ProfileScoredByProofs,
+
+    #[msg("Score was decayed too recently")]
+    DecayTooSoon,
+
+    #[msg("Governance config of the score decay realm is required")]
+    MissingDecayConfig,
+
+    #[msg("Contribution batch is empty or too large")]
+    InvalidBatchSize,
+
+    #[msg("Merkle tree is not the registry's active contribution tree")]
+    InactiveContributionTree,
+
+    #[msg("Contribution tree is already registered")]
+    TreeAlreadyRegistered,
+
+    #[msg("Contribution tree registry is full")]
+    TreeRegistryFull,
+
+    #[msg("Account is not a concurrent Merkle tree")]
+    InvalidMerkleTreeAccount,
+
+    #[msg("Too many tree writers")]
+    TooManyTreeWriters,
+
+    #[msg("Only registered, program-owned contribution trees can be proven")]
+    UntrustedContributionTree,
+
+    #[msg("Contribution has been revoked")]
+    ContributionRevoked,
+
+    #[msg("Amendments cannot change the developer or task of a contribution")]
+    ImmutableContributionKey,
+
+    #[msg("Developer profile credited with this contribution is required")]
+    CreditedProfileRequired,
+
+    #[msg("Attestation rent must return to the wallet that paid for it")]
+    InvalidAttestationPayer,
+
+    #[msg("Author shares must name each author once, lead developer first, and sum to 10000 bps")]
+    InvalidAuthorShares,
 }
 
 #[error_code]
@@ -112,6 +181,285 @@ pub enum GovernanceError {
 
     #[msg("Cannot delegate with active votes")]
     CannotDelegateWithActiveVotes,
+
+    #[msg("Round has been cancelled")]
+    RoundCancelled,
+
+    #[msg("Round is not cancelled")]
+    RoundNotCancelled,
+
+    #[msg("Round is not live -- must be Open or Voting")]
+    RoundNotLive,
+
+    #[msg("Deadlines can only be extended, not shortened")]
+    DeadlineNotExtended,
+
+    #[msg("Round has already started -- cannot reschedule")]
+    RoundAlreadyStarted,
+
+    #[msg("Vote record does not belong to this round")]
+    VoteRecordRoundMismatch,
+
+    #[msg("Idea is not in a voidable state")]
+    NotVoidable,
+
+    #[msg("Round is paused by the admin")]
+    RoundPaused,
+
+    #[msg("Round is already in the requested pause state")]
+    RoundPauseUnchanged,
+
+    #[msg("Invalid round schedule cadence")]
+    InvalidSchedule,
+
+    #[msg("Round schedule is not active")]
+    ScheduleInactive,
+
+    #[msg("Previous scheduled round is missing or does not match the schedule")]
+    InvalidPreviousRound,
+
+    #[msg("Previous scheduled round is still accepting submissions")]
+    PreviousRoundStillOpen,
+
+    #[msg("Budget accounts are required when a funding budget is set")]
+    BudgetAccountsMissing,
+
+    #[msg("Treasury signer or token account does not match revenue config")]
+    InvalidTreasury,
+
+    #[msg("Round has no funding budget")]
+    NoBudget,
+
+    #[msg("Requested amount exceeds the round budget")]
+    RequestExceedsBudget,
+
+    #[msg("Round is not closed")]
+    RoundNotClosed,
+
+    #[msg("Idea has already been finalized")]
+    IdeaAlreadyFinalized,
+
+    #[msg("Every idea in the round must be finalized first")]
+    IdeaNotFinalized,
+
+    #[msg("Idea accounts must cover every idea of the round in index order")]
+    InvalidIdeaAccounts,
+
+    #[msg("Round budget has already been allocated")]
+    BudgetAlreadyFinalized,
+
+    #[msg("Round budget has not been allocated yet")]
+    BudgetNotFinalized,
+
+    #[msg("Idea has no funding allocation")]
+    NoAllocation,
+
+    #[msg("Idea payout escrow already funded")]
+    EscrowAlreadyFunded,
+
+    #[msg("Idea payout escrow not funded yet")]
+    EscrowNotFunded,
+
+    #[msg("Idea is not approved")]
+    IdeaNotApproved,
+
+    #[msg("Execution timelock has not elapsed")]
+    ExecutionTimelockActive,
+
+    #[msg("Idea payout already claimed")]
+    PayoutAlreadyClaimed,
+
+    #[msg("Ranking payer does not match the budget ranking")]
+    InvalidRankingPayer,
+
+    #[msg("Too many approved ideas to rank for the budget")]
+    BudgetRankingFull,
+
+    #[msg("Round has reached its idea capacity")]
+    RoundFull,
+
+    #[msg("Instruction does not apply to this round type")]
+    WrongRoundType,
+
+    #[msg("Matching pool mint must be the GSD or USDC mint")]
+    InvalidContributionMint,
+
+    #[msg("Contribution amount must be greater than zero")]
+    ZeroContribution,
+
+    #[msg("Contribution already refunded")]
+    ContributionAlreadyRefunded,
+
+    #[msg("Milestone amount must be positive and min score at most 10000")]
+    InvalidMilestone,
+
+    #[msg("Milestone plan is locked once funds have been released")]
+    MilestonePlanLocked,
+
+    #[msg("Maximum number of milestones reached")]
+    TooManyMilestones,
+
+    #[msg("Milestones exceed the idea's allocation")]
+    MilestonesExceedAllocation,
+
+    #[msg("Milestones must cover the full allocation before release")]
+    MilestonesIncomplete,
+
+    #[msg("Milestone already released")]
+    MilestoneAlreadyReleased,
+
+    #[msg("Verification report is not completed")]
+    VerificationNotCompleted,
+
+    #[msg("Verification score is below the milestone threshold")]
+    MilestoneScoreTooLow,
+
+    #[msg("Verification predates the milestone")]
+    VerificationPredatesMilestone,
+
+    #[msg("Milestone min score is below the verification config floor")]
+    MilestoneScoreBelowFloor,
+
+    #[msg("Milestone deadline must be in the future")]
+    InvalidMilestoneDeadline,
+
+    #[msg("Milestones can no longer be registered for this idea")]
+    MilestonePlanningClosed,
+
+    #[msg("Only the round authority or governance admin can approve milestones")]
+    UnauthorizedMilestoneApprover,
+
+    #[msg("Milestone already approved")]
+    MilestoneAlreadyApproved,
+
+    #[msg("Milestone has not been approved")]
+    MilestoneNotApproved,
+
+    #[msg("Verification came after the milestone deadline")]
+    VerificationAfterDeadline,
+
+    #[msg("Milestone funds were returned to the round budget")]
+    MilestoneReclaimed,
+
+    #[msg("Deadline has not passed yet")]
+    MilestoneDeadlineNotReached,
+
+    #[msg("No unplanned allocation to reclaim")]
+    NoUnplannedAllocation,
+
+    #[msg("Voting on this idea has already been decided")]
+    IdeaVotingClosed,
+
+    #[msg("Remaining eligible weight could still change the outcome")]
+    OutcomeNotDecided,
+
+    #[msg("Every token behind the vote was deposited after the round's eligible weight snapshot")]
+    DepositAfterSnapshot,
+
+    #[msg("Invalid conviction voting parameters")]
+    InvalidConvictionParams,
+
+    #[msg("Conviction voting is not configured")]
+    ConvictionDisabled,
+
+    #[msg("Round already has a vote tree")]
+    VoteTreeAlreadySet,
+
+    #[msg("Round has no vote tree")]
+    NoVoteTree,
+
+    #[msg("Round records votes as compressed receipts")]
+    CompressedVotingEnabled,
+
+    #[msg("Idea index exceeds the vote nullifier capacity")]
+    IdeaIndexOutOfRange,
+
+    #[msg("Voter has already voted on this idea")]
+    AlreadyVoted,
+
+    #[msg("Idea still holds unreleased funds or conviction stake")]
+    IdeaFundsOutstanding,
+
+    #[msg("Not every idea in the round has been archived")]
+    IdeasNotArchived,
+
+    #[msg("Round still has open vote records")]
+    VotesStillOpen,
+
+    #[msg("Round budget escrow still holds tokens")]
+    BudgetNotReclaimed,
+
+    #[msg("Idea's quadratic funding match has not been paid yet")]
+    QfMatchPending,
+
+    #[msg("QF contributions to this idea have not all been refunded")]
+    QfRefundsPending,
+
+    #[msg("Delegate must have a registered delegate profile")]
+    DelegateProfileRequired,
+
+    #[msg("Delegate is not accepting new delegations")]
+    DelegateNotAccepting,
+
+    #[msg("Rounds without ideas stay on-chain for delegate tallies")]
+    EmptyRoundNotArchivable,
+
+    #[msg("Account belongs to a different realm")]
+    RealmMismatch,
+
+    #[msg("This subsystem is paused by the guardian")]
+    SubsystemPaused,
+
+    #[msg("Neither voting nor deposits are paused")]
+    ProtocolNotPaused,
+
+    #[msg("Unauthorized -- not the pause guardian")]
+    UnauthorizedGuardian,
+
+    #[msg("Unauthorized -- not the program upgrade authority")]
+    UnauthorizedUpgradeAuthority,
+
+    #[msg("Pause flags contain undefined bits")]
+    InvalidPauseFlags,
+
+    #[msg("Pause config account is not owned by this program")]
+    InvalidPauseConfig,
+
+    #[msg("Conviction stake accounts must be (idea, stake) pairs of the depositor")]
+    InvalidStakeAccounts,
+
+    #[msg("Every conviction stake of the deposit must be passed")]
+    ConvictionStakesOutstanding,
+
+    #[msg("Vote accounts must be (round, idea, vote record) triples of the depositor")]
+    InvalidVoteAccounts,
+
+    #[msg("Every vote of the deposit must be passed or stripped first")]
+    VotesOutstanding,
+
+    #[msg("Vote receipt carries no weight to strip")]
+    ReceiptAlreadyStripped,
+
+    #[msg("Handover expiry must be in the future")]
+    InvalidHandoverExpiry,
+
+    #[msg("No admin handover is pending")]
+    NoPendingAdmin,
+
+    #[msg("Signer is not the proposed admin")]
+    NotPendingAdmin,
+
+    #[msg("Proposed admin handover has expired")]
+    PendingAdminExpired,
+
+    #[msg("Signer lacks the required operator role")]
+    MissingOperatorRole,
+
+    #[msg("Operator roles contain bits this realm scope cannot grant")]
+    InvalidOperatorRoles,
+
+    #[msg("Unauthorized -- not the role registry admin")]
+    UnauthorizedRoleAdmin,
 }
 
 #[error_code]
@@ -146,6 +494,9 @@ pub enum RevenueError {
     #[msg("Not authorized to execute burn")]
     UnauthorizedBurnAuthority,
 
+    #[msg("Not authorized to manage revenue config")]
+    UnauthorizedRevenueAdmin,
+
     #[msg("Burn already executed for this event")]
     BurnAlreadyExecuted,
 }
@@ -190,4 +541,7 @@ pub enum VerificationError {
 
     #[msg("Not authorized to manage verification config")]
     UnauthorizedVerificationAdmin,
+
+    #[msg("Milestone score floor must be 1-10000")]
+    InvalidMilestoneScoreFloor,
 }
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VoteChoice;
+
+/// Emitted by cast_vote and cast_vote_compressed so indexers can attach off-chain rationale to votes.
+#[event]
+pub struct VoteCast {
+    /// Wallet that cast the vote
+    pub voter: Pubkey,
+    /// The Idea account voted on
+    pub idea: Pubkey,
+    /// The IdeaRound the idea belongs to
+    pub round: Pubkey,
+    /// The voter's choice
+    pub vote: VoteChoice,
+    /// Token weight applied, including delegated weight
+    pub weight: u64,
+    /// SHA-256 hash of the off-chain rationale, if the voter published one
+    pub reason_hash: Option<[u8; 32]>,
+    /// Unix timestamp of the vote
+    pub voted_at: i64,
+}
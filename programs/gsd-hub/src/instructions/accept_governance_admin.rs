@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GovernanceConfig;
+
+#[derive(Accounts)]
+pub struct AcceptGovernanceAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptGovernanceAdmin>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.governance_config;
+    config.pending_admin.accept(ctx.accounts.new_admin.key(), now)?;
+    config.admin = ctx.accounts.new_admin.key();
+
+    Ok(())
+}
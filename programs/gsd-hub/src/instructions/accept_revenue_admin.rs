@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::RevenueConfig;
+
+#[derive(Accounts)]
+pub struct AcceptRevenueAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"revenue_config".as_ref(), revenue_config.realm.as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptRevenueAdmin>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.revenue_config;
+    config.pending_admin.accept(ctx.accounts.new_admin.key(), now)?;
+    config.admin = ctx.accounts.new_admin.key();
+
+    Ok(())
+}
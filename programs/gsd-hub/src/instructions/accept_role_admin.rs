@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::RoleRegistry;
+
+#[derive(Accounts)]
+pub struct AcceptRoleAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"role_registry".as_ref()],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptRoleAdmin>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.role_registry;
+    registry.pending_admin.accept(ctx.accounts.new_admin.key(), now)?;
+    registry.admin = ctx.accounts.new_admin.key();
+
+    Ok(())
+}
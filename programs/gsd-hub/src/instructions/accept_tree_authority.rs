@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use crate::state::ContributionTreeConfig;
+
+#[derive(Accounts)]
+pub struct AcceptTreeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub new_authority: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptTreeAuthority>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let new_authority = ctx.accounts.new_authority.key();
+    ctx.accounts
+        .tree_config
+        .pending_authority
+        .accept(new_authority, now)?;
+
+    if ctx.accounts.tree_config.pda_authority {
+        ctx.accounts.tree_config.authority = new_authority;
+        return Ok(());
+    }
+
+    // Release the parked tree from the config PDA to the new authority
+    let merkle_key = ctx.accounts.merkle_tree.key();
+    let tree_config_seeds: &[&[u8]] = &[
+        b"contribution_tree",
+        merkle_key.as_ref(),
+        &[ctx.accounts.tree_config.bump],
+    ];
+    crate::cpi::compression::transfer_authority(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.tree_config.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        new_authority,
+        Some(&[tree_config_seeds]),
+    )?;
+
+    ctx.accounts.tree_config.authority = new_authority;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VerificationConfig;
+
+#[derive(Accounts)]
+pub struct AcceptVerificationAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"verification_config".as_ref(), verification_config.realm.as_ref()],
+        bump = verification_config.bump
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptVerificationAdmin>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.verification_config;
+    config.pending_admin.accept(ctx.accounts.new_admin.key(), now)?;
+    config.admin = ctx.accounts.new_admin.key();
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{
+    Idea, IdeaMilestone, IdeaRound, IdeaStatus, MilestoneTask, PauseConfig, RoundType,
+    VerificationConfig, MAX_MILESTONES, MILESTONE_PLANNING_PERIOD,
+};
+
+#[derive(Accounts)]
+#[instruction(task_ref: [u8; 32])]
+pub struct AddIdeaMilestone<'info> {
+    #[account(
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.round_type != RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump,
+        has_one = author @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub idea: Account<'info, Idea>,
+
+    /// Source of the realm-wide floor for milestone min scores
+    #[account(
+        seeds = [b"verification_config".as_ref(), round.realm.as_ref()],
+        bump = verification_config.bump
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + IdeaMilestone::INIT_SPACE,
+        seeds = [b"idea_milestone".as_ref(), idea.key().as_ref(), &[idea.milestone_count]],
+        bump
+    )]
+    pub milestone: Account<'info, IdeaMilestone>,
+
+    /// Created once per idea and task; reusing a task_ref fails here
+    #[account(
+        init,
+        payer = author,
+        space = 8 + MilestoneTask::INIT_SPACE,
+        seeds = [b"milestone_task".as_ref(), idea.key().as_ref(), task_ref.as_ref()],
+        bump
+    )]
+    pub milestone_task: Account<'info, MilestoneTask>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Author proposes the next milestone of a funded idea.
+///
+/// Milestones can only be added before the first release and within the
+/// planning period, and together may not exceed the amount held in the
+/// idea's escrow. Each needs its own task_ref, verified after the milestone
+/// is registered and by its deadline, a min score of at least the realm's
+/// floor, and approval by the round authority or governance admin.
+pub fn handler(
+    ctx: Context<AddIdeaMilestone>,
+    task_ref: [u8; 32],
+    amount: u64,
+    min_score: u16,
+    deadline: i64,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let idea = &ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Approved,
+        GovernanceError::IdeaNotApproved
+    );
+    require!(idea.escrow_funded, GovernanceError::EscrowNotFunded);
+    require!(
+        idea.released_amount == 0,
+        GovernanceError::MilestonePlanLocked
+    );
+    require!(
+        now < idea.execution_eligible_at.saturating_add(MILESTONE_PLANNING_PERIOD),
+        GovernanceError::MilestonePlanningClosed
+    );
+    require!(
+        idea.milestone_count < MAX_MILESTONES,
+        GovernanceError::TooManyMilestones
+    );
+    require!(
+        amount > 0 && min_score <= 10000,
+        GovernanceError::InvalidMilestone
+    );
+    require!(
+        min_score >= ctx.accounts.verification_config.min_milestone_score,
+        GovernanceError::MilestoneScoreBelowFloor
+    );
+    require!(deadline > now, GovernanceError::InvalidMilestoneDeadline);
+
+    let milestone_total = idea
+        .milestone_total
+        .checked_add(amount)
+        .ok_or(GovernanceError::Overflow)?;
+    require!(
+        milestone_total <= idea.allocated_amount,
+        GovernanceError::MilestonesExceedAllocation
+    );
+
+    let milestone = &mut ctx.accounts.milestone;
+    milestone.idea = ctx.accounts.idea.key();
+    milestone.milestone_index = ctx.accounts.idea.milestone_count;
+    milestone.bump = ctx.bumps.milestone;
+    milestone.amount = amount;
+    milestone.task_ref = task_ref;
+    milestone.min_score = min_score;
+    milestone.released = false;
+    milestone.released_at = 0;
+    milestone.created_at = now;
+    milestone.approved = false;
+    milestone.deadline = deadline;
+    milestone.reclaimed = false;
+
+    let milestone_task = &mut ctx.accounts.milestone_task;
+    milestone_task.milestone = ctx.accounts.milestone.key();
+    milestone_task.bump = ctx.bumps.milestone_task;
+
+    let idea = &mut ctx.accounts.idea;
+    idea.milestone_count += 1;
+    idea.milestone_total = milestone_total;
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use crate::cpi::compression::{LeafReplacement, ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GsdHubError;
+use crate::state::{
+    ContributionAttestation, ContributionChange, ContributionInput, ContributionNullifier,
+    ContributionTreeConfig, DeveloperProfile, GovernanceConfig, PauseConfig, ScoreConfig,
+    VersionedContributionLeaf,
+};
+
+#[derive(Accounts)]
+#[instruction(previous: VersionedContributionLeaf)]
+pub struct AmendContribution<'info> {
+    #[account(
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: ["contribution_nullifier", developer, task_ref] PDA, read by
+    /// `settle_counted_contribution`; empty when the leaf was never credited
+    #[account(
+        mut,
+        seeds = [
+            b"contribution_nullifier".as_ref(),
+            previous.developer().as_ref(),
+            previous.task_ref().as_ref(),
+        ],
+        bump
+    )]
+    pub nullifier: UncheckedAccount<'info>,
+
+    /// Profile of the developer, required when the nullifier counted `previous`
+    #[account(
+        mut,
+        seeds = [b"developer", previous.developer().as_ref()],
+        bump = developer_profile.bump,
+    )]
+    pub developer_profile: Option<Account<'info, DeveloperProfile>>,
+
+    /// CHECK: ["contribution_attestation", merkle_tree, developer, task_ref]
+    /// PDA of `previous`, closed by `close_attestation`; empty when the leaf
+    /// was never attested
+    #[account(
+        mut,
+        seeds = [
+            b"contribution_attestation".as_ref(),
+            merkle_tree.key().as_ref(),
+            previous.developer().as_ref(),
+            previous.task_ref().as_ref(),
+        ],
+        bump
+    )]
+    pub attestation: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the attestation's payer; receives its rent when it
+    /// closes. Required only when `attestation` exists
+    #[account(mut)]
+    pub attestation_payer: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Governance config of the realm chosen in ScoreConfig, source of the
+    /// half-life; required unless no decay realm is set
+    #[account(
+        seeds = [b"governance_config".as_ref(), score_config.decay_realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Option<Account<'info, GovernanceConfig>>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Correct a recorded v1 or v2 contribution in place; only the score and
+/// content hash change. The amended leaf keeps the original developer, task,
+/// timestamp and v2 details, so nullifiers and activity
+/// windows derived from it do not move. A credited leaf is re-credited at
+/// its amended score, and an attestation of the old leaf is closed; the
+/// amended leaf can be attested again.
+///
+/// `remaining_accounts` carries the Merkle proof of `previous`, leaf level first.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AmendContribution<'info>>,
+    previous: VersionedContributionLeaf,
+    amended: ContributionInput,
+    root: [u8; 32],
+    leaf_index: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    require!(!previous.is_revoked(), GsdHubError::ContributionRevoked);
+    require!(
+        amended.developer == previous.developer() && amended.task_ref == previous.task_ref(),
+        GsdHubError::ImmutableContributionKey
+    );
+    require!(
+        amended.verification_score <= 10_000,
+        GsdHubError::InvalidVerificationScore
+    );
+
+    let change = ContributionChange {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        leaf_index,
+        previous_leaf_hash: previous.to_leaf_hash(),
+        revoked: false,
+        leaf: previous.amended(&amended),
+    };
+    emit_contribution_change(&ctx.accounts.noop_program.to_account_info(), &change)?;
+
+    replace_contribution_leaf(
+        &ctx.accounts.tree_config,
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        &LeafReplacement {
+            root,
+            previous_leaf: change.previous_leaf_hash,
+            new_leaf: change.leaf.to_leaf_hash(),
+            index: leaf_index,
+        },
+    )?;
+    close_attestation(
+        &ctx.accounts.attestation.to_account_info(),
+        &ctx.accounts.attestation_payer,
+    )?;
+
+    let half_life_secs = ctx
+        .accounts
+        .score_config
+        .decay_half_life_secs(&ctx.accounts.governance_config)?;
+    settle_counted_contribution(
+        &ctx.accounts.nullifier.to_account_info(),
+        &mut ctx.accounts.developer_profile,
+        half_life_secs,
+        &CountedCredit {
+            leaf_hash: change.previous_leaf_hash,
+            credited_score: previous.credited_score(),
+            contributed_at: previous.timestamp(),
+        },
+        Some(&CountedCredit {
+            leaf_hash: change.leaf.to_leaf_hash(),
+            credited_score: change.leaf.credited_score(),
+            contributed_at: change.leaf.timestamp(),
+        }),
+    )
+}
+
+/// What a contribution leaf adds to its developer's profile when counted.
+pub struct CountedCredit {
+    pub leaf_hash: [u8; 32],
+    pub credited_score: u64,
+    pub contributed_at: i64,
+}
+
+/// Undo the credit of a rewritten leaf. Only applies when the developer's
+/// nullifier counted exactly `previous`; the decayed remainder of its credit
+/// is debited and, for an amendment, `replacement` is credited in its place
+/// with the nullifier moved over to the new leaf.
+pub fn settle_counted_contribution<'info>(
+    nullifier: &AccountInfo<'info>,
+    developer_profile: &mut Option<Account<'info, DeveloperProfile>>,
+    half_life_secs: u64,
+    previous: &CountedCredit,
+    replacement: Option<&CountedCredit>,
+) -> Result<()> {
+    if nullifier.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*nullifier.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let mut counted =
+        ContributionNullifier::try_deserialize(&mut &nullifier.try_borrow_data()?[..])?;
+    if counted.leaf_hash != previous.leaf_hash {
+        return Ok(());
+    }
+
+    let profile = developer_profile
+        .as_mut()
+        .ok_or(GsdHubError::CreditedProfileRequired)?;
+    let now = Clock::get()?.unix_timestamp;
+    profile.debit_contribution(previous.credited_score, counted.counted_at, now, half_life_secs)?;
+
+    if let Some(replacement) = replacement {
+        profile.credit_contribution(
+            replacement.credited_score,
+            replacement.contributed_at,
+            now,
+            half_life_secs,
+        )?;
+        counted.leaf_hash = replacement.leaf_hash;
+        counted.counted_at = now;
+        counted.try_serialize(&mut &mut nullifier.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
+
+/// Close the attestation of a rewritten leaf back to the wallet that paid
+/// for it, so the old leaf no longer reads as proven on-chain. Does nothing
+/// when the leaf was never attested.
+pub fn close_attestation<'info>(
+    attestation: &AccountInfo<'info>,
+    attestation_payer: &Option<UncheckedAccount<'info>>,
+) -> Result<()> {
+    if attestation.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*attestation.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let record =
+        ContributionAttestation::try_deserialize(&mut &attestation.try_borrow_data()?[..])?;
+    let payer = attestation_payer
+        .as_ref()
+        .ok_or(GsdHubError::InvalidAttestationPayer)?;
+    require_keys_eq!(payer.key(), record.payer, GsdHubError::InvalidAttestationPayer);
+    crate::cpi::close_account(attestation, &payer.to_account_info())
+}
+
+/// Emit a `ContributionChange` via noop for off-chain indexing.
+pub fn emit_contribution_change<'info>(
+    noop_program: &AccountInfo<'info>,
+    change: &ContributionChange,
+) -> Result<()> {
+    let change_data = AnchorSerialize::try_to_vec(change)
+        .map_err(|_| error!(GsdHubError::InvalidContributionData))?;
+    crate::cpi::noop::wrap_application_data(noop_program, change_data)
+}
+
+/// Swap a contribution leaf. The authority of a wallet-owned tree signs
+/// directly; PDA-owned trees are signed for by the tree_config PDA.
+pub fn replace_contribution_leaf<'info>(
+    tree_config: &Account<'info, ContributionTreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    replacement: &LeafReplacement,
+) -> Result<()> {
+    if !tree_config.pda_authority {
+        return crate::cpi::compression::replace_leaf(
+            merkle_tree,
+            authority,
+            noop_program,
+            compression_program,
+            proof,
+            replacement,
+            None, // authority signs directly
+        );
+    }
+
+    let merkle_key = merkle_tree.key();
+    let tree_config_seeds: &[&[u8]] = &[
+        b"contribution_tree",
+        merkle_key.as_ref(),
+        &[tree_config.bump],
+    ];
+    crate::cpi::compression::replace_leaf(
+        merkle_tree,
+        &tree_config.to_account_info(),
+        noop_program,
+        compression_program,
+        proof,
+        replacement,
+        Some(&[tree_config_seeds]),
+    )
+}
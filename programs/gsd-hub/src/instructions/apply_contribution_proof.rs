@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::cpi::compression::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use crate::errors::GsdHubError;
+use crate::state::{
+    ContributionLeaf, ContributionNullifier, ContributionTreeConfig, ContributionTreeRegistry,
+    DeveloperProfile, GovernanceConfig, PauseConfig, ScoreConfig,
+};
+
+#[derive(Accounts)]
+#[instruction(leaf: ContributionLeaf)]
+pub struct ApplyContributionProof<'info> {
+    /// Ties the tree to this program's contribution trees. Only PDA-owned
+    /// trees qualify: a wallet-owned tree's authority can append arbitrary
+    /// leaves straight through spl-account-compression.
+    #[account(
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.pda_authority @ GsdHubError::UntrustedContributionTree,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Registry that must list `merkle_tree`
+    #[account(
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump = tree_registry.bump,
+        constraint = tree_registry.is_registered(&merkle_tree.key())
+            @ GsdHubError::UntrustedContributionTree,
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"developer", leaf.developer.as_ref()],
+        bump = developer_profile.bump,
+    )]
+    pub developer_profile: Account<'info, DeveloperProfile>,
+
+    /// Created once per developer and task; a second proof of the same task fails here
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ContributionNullifier::INIT_SPACE,
+        seeds = [
+            b"contribution_nullifier".as_ref(),
+            leaf.developer.as_ref(),
+            leaf.task_ref.as_ref(),
+        ],
+        bump
+    )]
+    pub nullifier: Account<'info, ContributionNullifier>,
+
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Governance config of the realm chosen in ScoreConfig, source of the
+    /// half-life; required unless no decay realm is set
+    #[account(
+        seeds = [b"governance_config".as_ref(), score_config.decay_realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Option<Account<'info, GovernanceConfig>>,
+
+    /// The developer or any crank acting for them
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Credit a proven contribution leaf to its developer and recompute
+/// `contribution_score` on-chain, without trusting a score oracle.
+///
+/// `remaining_accounts` carries the Merkle proof nodes, leaf level first.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApplyContributionProof<'info>>,
+    leaf: ContributionLeaf,
+    root: [u8; 32],
+    leaf_index: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    require!(!leaf.is_revoked(), GsdHubError::ContributionRevoked);
+
+    let leaf_hash = leaf.to_leaf_hash();
+    crate::cpi::compression::verify_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        leaf_hash,
+        leaf_index,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let nullifier = &mut ctx.accounts.nullifier;
+    nullifier.developer = leaf.developer;
+    nullifier.task_ref = leaf.task_ref;
+    nullifier.leaf_hash = leaf_hash;
+    nullifier.counted_at = now;
+    nullifier.bump = ctx.bumps.nullifier;
+
+    let half_life_secs = ctx
+        .accounts
+        .score_config
+        .decay_half_life_secs(&ctx.accounts.governance_config)?;
+    ctx.accounts.developer_profile.credit_contribution(
+        leaf.verification_score as u64,
+        leaf.timestamp,
+        now,
+        half_life_secs,
+    )?;
+
+    Ok(())
+}
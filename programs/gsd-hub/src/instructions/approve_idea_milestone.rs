@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, Idea, IdeaMilestone, IdeaRound, PauseConfig};
+
+#[derive(Accounts)]
+pub struct ApproveIdeaMilestone<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_milestone".as_ref(), idea.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump
+    )]
+    pub milestone: Account<'info, IdeaMilestone>,
+
+    #[account(
+        constraint = approver.key() == round.authority
+            || approver.key() == governance_config.admin @ GovernanceError::UnauthorizedMilestoneApprover
+    )]
+    pub approver: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Round authority or governance admin accepts a milestone proposed by the
+/// idea author: its amount, task_ref, min score and deadline.
+pub fn handler(ctx: Context<ApproveIdeaMilestone>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(!milestone.reclaimed, GovernanceError::MilestoneReclaimed);
+    require!(
+        !milestone.approved,
+        GovernanceError::MilestoneAlreadyApproved
+    );
+
+    milestone.approved = true;
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GovernanceError;
+use crate::state::{
+    ArchiveTreeConfig, Idea, IdeaRound, IdeaStatus, IdeaSummaryLeaf, PauseConfig, QfIdeaTally,
+    RoundStatus, RoundType,
+};
+
+#[derive(Accounts)]
+pub struct ArchiveIdea<'info> {
+    #[account(
+        mut,
+        seeds = [b"archive_tree".as_ref(), round.realm.as_ref()],
+        bump = archive_tree.bump
+    )]
+    pub archive_tree: Account<'info, ArchiveTreeConfig>,
+
+    /// CHECK: Must be the archive tree, validated by spl-account-compression CPI
+    #[account(mut, address = archive_tree.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Closed
+            || round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        close = author,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    /// CHECK: ["qf_tally", idea] PDA; in QF rounds it must be matched (fully
+    /// refunded once cancelled) or never created, otherwise the match or the
+    /// refunds could not be paid after archiving
+    #[account(seeds = [b"qf_tally".as_ref(), idea.key().as_ref()], bump)]
+    pub qf_tally: UncheckedAccount<'info>,
+
+    /// CHECK: Rent recipient, must be the idea author who paid for the account
+    #[account(mut, address = idea.author)]
+    pub author: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: summarize a settled idea into the archive tree and
+/// close its account, refunding rent to the author.
+///
+/// Standard-round ideas must have been finalized, or voided once the round
+/// is cancelled; ideas in funding rounds may stay Submitted. Ideas still
+/// holding unreleased funds, conviction stake or unrefunded QF contributions
+/// cannot be archived, nor can ideas of a live round's unsettled budget:
+/// budget finalization and reclaiming read every idea of the round.
+pub fn handler(ctx: Context<ArchiveIdea>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let idea = &ctx.accounts.idea;
+
+    require!(
+        idea.status != IdeaStatus::Submitted
+            || ctx.accounts.round.round_type != RoundType::Standard,
+        GovernanceError::IdeaNotFinalized
+    );
+    require!(
+        idea.released_amount == idea.allocated_amount && idea.conviction_staked == 0,
+        GovernanceError::IdeaFundsOutstanding
+    );
+
+    let round = &ctx.accounts.round;
+    let cancelled = round.status == RoundStatus::Cancelled;
+    require!(
+        !round.has_budget() || round.is_budget_settled() || cancelled,
+        GovernanceError::BudgetNotFinalized
+    );
+    if round.round_type == RoundType::QuadraticFunding && !ctx.accounts.qf_tally.data_is_empty() {
+        let tally =
+            QfIdeaTally::try_deserialize(&mut &ctx.accounts.qf_tally.try_borrow_data()?[..])?;
+        if cancelled {
+            // refund_qf_contribution still needs the idea to sign for its escrow
+            require!(tally.total_contributed == 0, GovernanceError::QfRefundsPending);
+        } else {
+            require!(tally.matched, GovernanceError::QfMatchPending);
+        }
+    }
+
+    let summary = IdeaSummaryLeaf {
+        round: ctx.accounts.round.key(),
+        idea: idea.key(),
+        idea_index: idea.idea_index,
+        status: idea.status,
+        yes_weight: idea.yes_weight,
+        no_weight: idea.no_weight,
+        abstain_weight: idea.abstain_weight,
+        voter_count: idea.voter_count,
+        content_hash: idea.content_hash,
+        archived_at: Clock::get()?.unix_timestamp,
+    };
+
+    // Emit full summary via noop for off-chain indexing
+    let summary_data = AnchorSerialize::try_to_vec(&summary)
+        .map_err(|_| error!(GovernanceError::Overflow))?;
+    crate::cpi::noop::wrap_application_data(
+        &ctx.accounts.noop_program.to_account_info(),
+        summary_data,
+    )?;
+
+    let archive_seeds: &[&[u8]] = &[
+        b"archive_tree".as_ref(),
+        ctx.accounts.archive_tree.realm.as_ref(),
+        &[ctx.accounts.archive_tree.bump],
+    ];
+    crate::cpi::compression::append_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.archive_tree.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        summary.to_leaf_hash(),
+        Some(&[archive_seeds]),
+    )?;
+
+    let archive = &mut ctx.accounts.archive_tree;
+    archive.archived_ideas = archive
+        .archived_ideas
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let round = &mut ctx.accounts.round;
+    round.archived_idea_count = round
+        .archived_idea_count
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::errors::GovernanceError;
+use crate::state::{ArchiveTreeConfig, IdeaRound, PauseConfig, RoundStatus};
+
+#[derive(Accounts)]
+pub struct ArchiveRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"archive_tree".as_ref(), idea_round.realm.as_ref()],
+        bump = archive_tree.bump
+    )]
+    pub archive_tree: Account<'info, ArchiveTreeConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"idea_round".as_ref(), idea_round.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Closed
+            || idea_round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive,
+        constraint = idea_round.idea_count > 0 @ GovernanceError::EmptyRoundNotArchivable,
+        constraint = idea_round.archived_idea_count == idea_round.idea_count @ GovernanceError::IdeasNotArchived,
+        constraint = idea_round.open_vote_accounts == 0 @ GovernanceError::VotesStillOpen
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    /// CHECK: Rent recipient, must be the account that paid for the round
+    #[account(mut, address = idea_round.rent_recipient())]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    // --- Budget escrow, required only when the round has a budget ---
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), idea_round.key().as_ref()],
+        bump,
+    )]
+    pub round_budget_escrow: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: close a Closed or Cancelled round once every idea is archived
+/// and every vote account is closed, refunding rent to whoever paid for the round.
+/// close_vote_record and close_vote_nullifier let anyone close the remaining
+/// VoteRecords and VoteNullifiers first.
+///
+/// Rounds without ideas are never closed, so tally_delegate_round can tell
+/// them apart from archived rounds, which always had ideas.
+///
+/// A budgeted round's escrow must already be emptied by fund_idea_escrow and
+/// reclaim_round_budget; it is closed alongside the round.
+pub fn handler(ctx: Context<ArchiveRound>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let round = &ctx.accounts.idea_round;
+
+    if round.has_budget() {
+        let (Some(round_budget_escrow), Some(token_program)) = (
+            ctx.accounts.round_budget_escrow.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return Err(GovernanceError::BudgetAccountsMissing.into());
+        };
+        require!(
+            round_budget_escrow.amount == 0,
+            GovernanceError::BudgetNotReclaimed
+        );
+
+        let round_index_bytes = round.round_index.to_le_bytes();
+        let round_seeds: &[&[u8]] = &[
+            b"idea_round".as_ref(),
+            round.realm.as_ref(),
+            round_index_bytes.as_ref(),
+            &[round.bump],
+        ];
+        let signer_seeds = &[round_seeds];
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: round_budget_escrow.to_account_info(),
+                destination: ctx.accounts.rent_payer.to_account_info(),
+                authority: ctx.accounts.idea_round.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    let archive = &mut ctx.accounts.archive_tree;
+    archive.archived_rounds = archive
+        .archived_rounds
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, RoundStatus};
+
+#[derive(Accounts)]
+pub struct CancelRound<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Admin-only: cancel a live round.
+///
+/// A cancelled round accepts no further submissions or votes, voters may
+/// relinquish immediately, and its ideas can be voided via `void_idea`.
+/// Left out of the VOTING pause so a paused round can still be cancelled.
+pub fn handler(ctx: Context<CancelRound>) -> Result<()> {
+    let round = &mut ctx.accounts.idea_round;
+
+    match round.status {
+        RoundStatus::Open | RoundStatus::Voting => {
+            round.status = RoundStatus::Cancelled;
+        }
+        RoundStatus::Closed => {
+            return Err(GovernanceError::AlreadyClosed.into());
+        }
+        RoundStatus::Cancelled => {
+            return Err(GovernanceError::RoundCancelled.into());
+        }
+    }
+
+    Ok(())
+}
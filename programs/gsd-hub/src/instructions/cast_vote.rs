@@ -3,8 +3,10 @@ use sha2::{Digest, Sha256};
 use std::str::FromStr;
 
 use crate::errors::GovernanceError;
+use crate::events::VoteCast;
+use crate::math::isqrt;
 use crate::state::{
-    GovernanceConfig, Idea, IdeaRound, RoundStatus, VoteChoice, VoteDeposit, VoteRecord,
+    amount_at_snapshot, DelegateProfile, GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType, VoteChoice, VoteDeposit, VoteRecord,
 };
 
 /// Compute the 8-byte Anchor account discriminator for a given account name.
@@ -18,22 +20,46 @@ fn account_discriminator(account_name: &str) -> [u8; 8] {
     disc
 }
 
-/// Integer square root using Newton's method for u64 values.
-/// Used for quadratic voting weight calculation: weight = floor(sqrt(tokens)).
-pub fn isqrt(n: u64) -> u64 {
-    if n == 0 {
-        return 0;
-    }
-    if n == 1 {
-        return 1;
-    }
-    let mut x = n;
-    let mut y = (x + 1) / 2;
-    while y < x {
-        x = y;
-        y = (x + n / x) / 2;
+/// Verify a Civic Pass gateway token for `subject` on the configured gatekeeper network.
+/// Shared sybil gate for quadratic voting and quadratic funding contributions.
+pub fn verify_gateway_token(
+    gateway_token: Option<&AccountInfo>,
+    subject: &Pubkey,
+    gatekeeper_network: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let gt_info = gateway_token.ok_or(GovernanceError::HumanVerificationRequired)?;
+
+    let civic_gateway = Pubkey::from_str("gatbGF9DvLAw3kWyn1EmH5Nh1Sqp8sTukF7yaQpSc71").unwrap();
+    require!(
+        gt_info.owner == &civic_gateway,
+        GovernanceError::InvalidGatewayToken
+    );
+
+    let gt_data = gt_info.try_borrow_data()?;
+    require!(gt_data.len() >= 81, GovernanceError::InvalidGatewayToken);
+
+    // Gateway Token layout: version[1] | owner_pubkey[32] | gatekeeper_network[32] | state[8] | expiry[8]
+    let token_subject = Pubkey::try_from(&gt_data[1..33]).unwrap();
+    let network = Pubkey::try_from(&gt_data[33..65]).unwrap();
+    let state = u64::from_le_bytes(gt_data[65..73].try_into().unwrap());
+    let expiry = u64::from_le_bytes(gt_data[73..81].try_into().unwrap());
+
+    require!(
+        token_subject == *subject,
+        GovernanceError::GatewayTokenOwnerMismatch
+    );
+    require!(
+        network == *gatekeeper_network,
+        GovernanceError::GatewayNetworkMismatch
+    );
+    require!(state == 0, GovernanceError::GatewayTokenNotActive);
+
+    if expiry > 0 {
+        require!(expiry as i64 >= now, GovernanceError::GatewayTokenExpired);
     }
-    x
+
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -41,12 +67,19 @@ pub struct CastVote<'info> {
     #[account(
         mut,
         seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
-        bump = idea.bump
+        bump = idea.bump,
+        constraint = idea.status == IdeaStatus::Submitted @ GovernanceError::IdeaVotingClosed
     )]
     pub idea: Account<'info, Idea>,
 
     #[account(
-        constraint = round.status == RoundStatus::Voting @ GovernanceError::RoundNotInVotingState
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Voting @ GovernanceError::RoundNotInVotingState,
+        constraint = !round.paused @ GovernanceError::RoundPaused,
+        constraint = round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = !round.has_vote_tree() @ GovernanceError::CompressedVotingEnabled
     )]
     pub round: Account<'info, IdeaRound>,
 
@@ -61,14 +94,14 @@ pub struct CastVote<'info> {
 
     #[account(
         mut,
-        seeds = [b"vote_deposit".as_ref(), voter.key().as_ref()],
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), voter.key().as_ref()],
         bump = vote_deposit.bump,
         constraint = vote_deposit.deposited_amount > 0 @ GovernanceError::NoDeposit
     )]
     pub vote_deposit: Account<'info, VoteDeposit>,
 
     #[account(
-        seeds = [b"governance_config".as_ref()],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
@@ -77,81 +110,73 @@ pub struct CastVote<'info> {
     /// Only required when quadratic voting is enabled (Civic Pass sybil resistance)
     pub gateway_token: Option<UncheckedAccount<'info>>,
 
+    /// The voter's delegate profile, if registered, to record participation
+    #[account(
+        mut,
+        seeds = [b"delegate_profile".as_ref(), governance_config.realm.as_ref(), voter.key().as_ref()],
+        bump = delegate_profile.bump
+    )]
+    pub delegate_profile: Option<Account<'info, DelegateProfile>>,
+
     #[account(mut)]
     pub voter: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-}
 
-pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
-    let clock = Clock::get()?;
-    let now = clock.unix_timestamp;
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
 
+/// Voting weight of `voter`: own deposit plus the active delegations passed in
+/// `delegations`, after the deposit timelock and sybil checks, with the linear
+/// or quadratic formula applied. Shared by both vote paths.
+///
+/// Only tokens deposited before `snapshot_at` (when the round snapshotted its
+/// eligible weight) count: a deposit or delegation topped up since then votes
+/// without its pending top-ups, so cast weight never exceeds the snapshot.
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_vote_weight(
+    program_id: &Pubkey,
+    voter: &Pubkey,
+    vote_deposit: &VoteDeposit,
+    governance_config: &GovernanceConfig,
+    gateway_token: Option<&AccountInfo>,
+    delegations: &[AccountInfo],
+    now: i64,
+    snapshot_at: i64,
+) -> Result<u64> {
     // Validate timelock eligibility
     require!(
-        now >= ctx.accounts.vote_deposit.eligible_at,
+        now >= vote_deposit.eligible_at,
         GovernanceError::TokensNotYetEligible
     );
 
-    // Validate within voting period
-    require!(
-        now < ctx.accounts.round.voting_end,
-        GovernanceError::VotingPeriodEnded
-    );
-
     // Sybil gate: when quadratic voting is enabled, require valid Civic Pass gateway token
-    if ctx.accounts.governance_config.quadratic_voting_enabled {
-        let gt_info = ctx
-            .accounts
-            .gateway_token
-            .as_ref()
-            .ok_or(GovernanceError::HumanVerificationRequired)?;
-
-        let civic_gateway =
-            Pubkey::from_str("gatbGF9DvLAw3kWyn1EmH5Nh1Sqp8sTukF7yaQpSc71").unwrap();
-        require!(
-            gt_info.owner == &civic_gateway,
-            GovernanceError::InvalidGatewayToken
-        );
-
-        let gt_data = gt_info.try_borrow_data()?;
-        require!(gt_data.len() >= 81, GovernanceError::InvalidGatewayToken);
-
-        // Gateway Token layout: version[1] | owner_pubkey[32] | gatekeeper_network[32] | state[8] | expiry[8]
-        let subject = Pubkey::try_from(&gt_data[1..33]).unwrap();
-        let network = Pubkey::try_from(&gt_data[33..65]).unwrap();
-        let state = u64::from_le_bytes(gt_data[65..73].try_into().unwrap());
-        let expiry = u64::from_le_bytes(gt_data[73..81].try_into().unwrap());
-
-        require!(
-            subject == ctx.accounts.voter.key(),
-            GovernanceError::GatewayTokenOwnerMismatch
-        );
-        require!(
-            network == ctx.accounts.governance_config.civic_gatekeeper_network,
-            GovernanceError::GatewayNetworkMismatch
-        );
-        require!(state == 0, GovernanceError::GatewayTokenNotActive);
-
-        if expiry > 0 {
-            require!(
-                expiry as i64 >= clock.unix_timestamp,
-                GovernanceError::GatewayTokenExpired
-            );
-        }
+    if governance_config.quadratic_voting_enabled {
+        verify_gateway_token(
+            gateway_token,
+            voter,
+            &governance_config.civic_gatekeeper_network,
+            now,
+        )?;
     }
 
     // Check if voter has an active delegation -- prevent double-counting
-    let voter_key = ctx.accounts.voter.key();
-    let delegation_seeds: &[&[u8]] = &[b"delegation".as_ref(), voter_key.as_ref()];
+    let voter_key = *voter;
+    let delegation_seeds: &[&[u8]] = &[
+        b"delegation".as_ref(),
+        governance_config.realm.as_ref(),
+        voter_key.as_ref(),
+    ];
     let (delegation_pda, _) =
-        Pubkey::find_program_address(delegation_seeds, ctx.program_id);
+        Pubkey::find_program_address(delegation_seeds, program_id);
 
     // Base weight from voter's own deposit
-    let mut total_tokens: u64 = ctx.accounts.vote_deposit.deposited_amount;
+    let mut total_tokens: u64 = vote_deposit.amount_at(snapshot_at);
 
     // Iterate remaining_accounts for delegation checks and aggregation
-    for account_info in ctx.remaining_accounts.iter() {
+    for account_info in delegations.iter() {
         if account_info.key() == delegation_pda {
             // This is the voter's own delegation PDA -- check it's not active
             if account_info.data_len() > 0 {
@@ -169,8 +194,8 @@ pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
         } else {
             // Try to deserialize as a DelegationRecord for delegation aggregation
             let data = account_info.try_borrow_data()?;
-            // Check minimum size for DelegationRecord (8 disc + 86 data = 94)
-            if data.len() >= 94 {
+            // Check minimum size for DelegationRecord (8 disc + 86 data + 8 deposited_at + 8 pending_amount + 32 realm = 142)
+            if account_info.owner == program_id && data.len() >= 142 {
                 // Check discriminator matches DelegationRecord
                 let disc = &data[0..8];
                 let expected_disc = account_discriminator("DelegationRecord");
@@ -181,12 +206,24 @@ pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
                         delegate_key == voter_key,
                         GovernanceError::InvalidDelegation
                     );
+                    // Parse realm at offset 110..142 -- delegations only count in their realm
+                    let realm = Pubkey::try_from(&data[110..142]).unwrap();
+                    require!(
+                        realm == governance_config.realm,
+                        GovernanceError::RealmMismatch
+                    );
                     // Parse is_active at offset 89
                     let is_active = data[89] != 0;
                     require!(is_active, GovernanceError::DelegationInactive);
                     // Parse delegated_amount at offset 8 + 32 + 32 + 1 = 73..81
                     let delegated_amount =
                         u64::from_le_bytes(data[73..81].try_into().unwrap());
+                    // Parse deposited_at at 94..102 and pending_amount at 102..110 --
+                    // only the delegated tokens that predate the snapshot count
+                    let deposited_at = i64::from_le_bytes(data[94..102].try_into().unwrap());
+                    let pending_amount = u64::from_le_bytes(data[102..110].try_into().unwrap());
+                    let delegated_amount =
+                        amount_at_snapshot(delegated_amount, pending_amount, deposited_at, snapshot_at);
                     total_tokens = total_tokens
                         .checked_add(delegated_amount)
                         .ok_or(GovernanceError::Overflow)?;
@@ -196,25 +233,21 @@ pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
         }
     }
 
+    // Every token behind the vote was deposited after the snapshot
+    require!(total_tokens > 0, GovernanceError::DepositAfterSnapshot);
+
     // Apply quadratic or linear formula
-    let weight = if ctx.accounts.governance_config.quadratic_voting_enabled {
+    let weight = if governance_config.quadratic_voting_enabled {
         isqrt(total_tokens)
     } else {
         total_tokens
     };
 
-    // Set vote record fields
-    let record = &mut ctx.accounts.vote_record;
-    record.voter = ctx.accounts.voter.key();
-    record.idea = ctx.accounts.idea.key();
-    record.round = ctx.accounts.round.key();
-    record.bump = ctx.bumps.vote_record;
-    record.vote = vote;
-    record.weight = weight;
-    record.voted_at = now;
+    Ok(weight)
+}
 
-    // Update idea tallies
-    let idea = &mut ctx.accounts.idea;
+/// Add a vote of `weight` to the idea's tallies and voter count.
+pub fn tally_vote(idea: &mut Idea, vote: VoteChoice, weight: u64) -> Result<()> {
     match vote {
         VoteChoice::Yes => {
             idea.yes_weight = idea
@@ -241,6 +274,82 @@ pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
         .checked_add(1)
         .ok_or(GovernanceError::Overflow)?;
 
+    Ok(())
+}
+
+/// Remove a vote of `weight` from the idea's tallies and voter count,
+/// undoing `tally_vote`.
+pub fn untally_vote(idea: &mut Idea, vote: VoteChoice, weight: u64) -> Result<()> {
+    let tally = match vote {
+        VoteChoice::Yes => &mut idea.yes_weight,
+        VoteChoice::No => &mut idea.no_weight,
+        VoteChoice::Abstain => &mut idea.abstain_weight,
+    };
+    *tally = tally.checked_sub(weight).ok_or(GovernanceError::Overflow)?;
+
+    idea.voter_count = idea
+        .voter_count
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
+
+pub fn handler(
+    ctx: Context<CastVote>,
+    vote: VoteChoice,
+    reason_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    // Validate within voting period
+    require!(
+        now < ctx.accounts.round.voting_end,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    let weight = resolve_vote_weight(
+        ctx.program_id,
+        &ctx.accounts.voter.key(),
+        &ctx.accounts.vote_deposit,
+        &ctx.accounts.governance_config,
+        ctx.accounts.gateway_token.as_ref().map(|gt| gt.as_ref()),
+        ctx.remaining_accounts,
+        now,
+        ctx.accounts.round.vote_snapshot_at(),
+    )?;
+
+    // Set vote record fields
+    let record = &mut ctx.accounts.vote_record;
+    record.voter = ctx.accounts.voter.key();
+    record.idea = ctx.accounts.idea.key();
+    record.round = ctx.accounts.round.key();
+    record.bump = ctx.bumps.vote_record;
+    record.vote = vote;
+    record.weight = weight;
+    record.voted_at = now;
+    record.reason_hash = reason_hash;
+
+    // Update idea tallies
+    tally_vote(&mut ctx.accounts.idea, vote, weight)?;
+
+    if let Some(profile) = ctx.accounts.delegate_profile.as_mut() {
+        profile
+            .record_vote(ctx.accounts.round.round_index)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+
+    // Track the record so the round outlives it for relinquishing
+    ctx.accounts.round.open_vote_accounts = ctx
+        .accounts
+        .round
+        .open_vote_accounts
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
     // Increment active votes on deposit
     ctx.accounts.vote_deposit.active_votes = ctx
         .accounts
@@ -249,5 +358,15 @@ pub fn handler(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
         .checked_add(1)
         .ok_or(GovernanceError::Overflow)?;
 
+    emit!(VoteCast {
+        voter: ctx.accounts.voter.key(),
+        idea: ctx.accounts.idea.key(),
+        round: ctx.accounts.round.key(),
+        vote,
+        weight,
+        reason_hash,
+        voted_at: now,
+    });
+
     Ok(())
 }
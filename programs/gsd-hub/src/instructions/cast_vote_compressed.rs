@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GovernanceError;
+use crate::events::VoteCast;
+use crate::instructions::cast_vote::{resolve_vote_weight, tally_vote};
+use crate::state::{
+    DelegateProfile, GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType, VoteChoice,
+    VoteDeposit, VoteNullifier, VoteReceiptLeaf, NULLIFIER_CAPACITY,
+};
+
+#[derive(Accounts)]
+pub struct CastVoteCompressed<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump,
+        constraint = idea.status == IdeaStatus::Submitted @ GovernanceError::IdeaVotingClosed
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Voting @ GovernanceError::RoundNotInVotingState,
+        constraint = round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = !round.paused @ GovernanceError::RoundPaused,
+        constraint = round.has_vote_tree() @ GovernanceError::NoVoteTree
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoteNullifier::INIT_SPACE,
+        seeds = [b"vote_nullifier".as_ref(), round.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_nullifier: Account<'info, VoteNullifier>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), voter.key().as_ref()],
+        bump = vote_deposit.bump,
+        constraint = vote_deposit.deposited_amount > 0 @ GovernanceError::NoDeposit
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: Validated in verify_gateway_token
+    /// Only required when quadratic voting is enabled (Civic Pass sybil resistance)
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+
+    /// The voter's delegate profile, if registered, to record participation
+    #[account(
+        mut,
+        seeds = [b"delegate_profile".as_ref(), governance_config.realm.as_ref(), voter.key().as_ref()],
+        bump = delegate_profile.bump
+    )]
+    pub delegate_profile: Option<Account<'info, DelegateProfile>>,
+
+    /// CHECK: Must be the round's vote tree, validated by spl-account-compression CPI
+    #[account(mut, address = round.vote_tree @ GovernanceError::NoVoteTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Cast a vote in a round with a vote tree.
+///
+/// Instead of a VoteRecord PDA, the receipt is emitted via noop and appended
+/// to the round's Merkle tree; the voter's nullifier bitmap prevents voting
+/// twice on the same idea. Delegations are passed as remaining accounts,
+/// exactly as for cast_vote, and the optional rationale hash is kept in the
+/// receipt and the VoteCast event.
+pub fn handler(
+    ctx: Context<CastVoteCompressed>,
+    vote: VoteChoice,
+    reason_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let idea_index = ctx.accounts.idea.idea_index;
+
+    require!(
+        now < ctx.accounts.round.voting_end,
+        GovernanceError::VotingPeriodEnded
+    );
+    require!(
+        idea_index < NULLIFIER_CAPACITY,
+        GovernanceError::IdeaIndexOutOfRange
+    );
+
+    let nullifier = &mut ctx.accounts.vote_nullifier;
+    if nullifier.voter == Pubkey::default() {
+        nullifier.voter = ctx.accounts.voter.key();
+        nullifier.round = ctx.accounts.round.key();
+        nullifier.bump = ctx.bumps.vote_nullifier;
+        ctx.accounts.round.open_vote_accounts = ctx
+            .accounts
+            .round
+            .open_vote_accounts
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+    require!(
+        !nullifier.has_voted(idea_index),
+        GovernanceError::AlreadyVoted
+    );
+    nullifier.mark_voted(idea_index);
+    nullifier.vote_count = nullifier
+        .vote_count
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let weight = resolve_vote_weight(
+        ctx.program_id,
+        &ctx.accounts.voter.key(),
+        &ctx.accounts.vote_deposit,
+        &ctx.accounts.governance_config,
+        ctx.accounts.gateway_token.as_ref().map(|gt| gt.as_ref()),
+        ctx.remaining_accounts,
+        now,
+        ctx.accounts.round.vote_snapshot_at(),
+    )?;
+
+    let receipt = VoteReceiptLeaf {
+        voter: ctx.accounts.voter.key(),
+        idea: ctx.accounts.idea.key(),
+        round: ctx.accounts.round.key(),
+        vote,
+        weight,
+        voted_at: now,
+        reason_hash,
+    };
+
+    // Emit full receipt via noop for off-chain indexing
+    let receipt_data = AnchorSerialize::try_to_vec(&receipt)
+        .map_err(|_| error!(GovernanceError::Overflow))?;
+    crate::cpi::noop::wrap_application_data(
+        &ctx.accounts.noop_program.to_account_info(),
+        receipt_data,
+    )?;
+
+    // Round PDA is the tree authority
+    let round = &ctx.accounts.round;
+    let round_index_bytes = round.round_index.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[
+        b"idea_round".as_ref(),
+        round.realm.as_ref(),
+        round_index_bytes.as_ref(),
+        &[round.bump],
+    ];
+    crate::cpi::compression::append_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.round.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        receipt.to_leaf_hash(),
+        Some(&[round_seeds]),
+    )?;
+
+    tally_vote(&mut ctx.accounts.idea, vote, weight)?;
+
+    if let Some(profile) = ctx.accounts.delegate_profile.as_mut() {
+        profile
+            .record_vote(ctx.accounts.round.round_index)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+
+    let round = &mut ctx.accounts.round;
+    round.vote_receipt_count = round
+        .vote_receipt_count
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    ctx.accounts.vote_deposit.active_votes = ctx
+        .accounts
+        .vote_deposit
+        .active_votes
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    emit!(VoteCast {
+        voter: ctx.accounts.voter.key(),
+        idea: ctx.accounts.idea.key(),
+        round: ctx.accounts.round.key(),
+        vote,
+        weight,
+        reason_hash,
+        voted_at: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, IdeaStatus, PauseConfig, RoundType};
+
+#[derive(Accounts)]
+pub struct ClaimIdeaPayout<'info> {
+    #[account(
+        constraint = round.round_type == RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump,
+        has_one = author @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = author_token_account.owner == author.key()
+            && author_token_account.mint == round.budget_mint
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+
+    pub author: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Author claims the contributions and match of a QF idea.
+///
+/// QF funding comes from contributors who chose the idea directly, so it is
+/// paid out in full; budget-funded ideas release through milestones instead.
+pub fn handler(ctx: Context<ClaimIdeaPayout>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let idea = &ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Approved,
+        GovernanceError::IdeaNotApproved
+    );
+    require!(idea.escrow_funded, GovernanceError::EscrowNotFunded);
+    require!(
+        idea.released_amount == 0,
+        GovernanceError::PayoutAlreadyClaimed
+    );
+
+    let amount = idea.allocated_amount;
+
+    // Idea PDA signs for its payout escrow
+    let round_key = ctx.accounts.round.key();
+    let idea_index_bytes = idea.idea_index.to_le_bytes();
+    let idea_seeds: &[&[u8]] = &[
+        b"idea".as_ref(),
+        round_key.as_ref(),
+        idea_index_bytes.as_ref(),
+        &[idea.bump],
+    ];
+    let signer_seeds = &[idea_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.idea_escrow.to_account_info(),
+            to: ctx.accounts.author_token_account.to_account_info(),
+            authority: ctx.accounts.idea.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    ctx.accounts.idea.released_amount = amount;
+
+    Ok(())
+}
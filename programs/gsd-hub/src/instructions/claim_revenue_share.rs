@@ -2,13 +2,16 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::RevenueError;
-use crate::state::{DeveloperProfile, RevenueClaim, RevenueEvent, RevenueStatus};
+use crate::state::{
+    DeveloperProfile, GovernanceConfig, PauseConfig, RevenueClaim, RevenueEvent, RevenueStatus,
+    ScoreConfig,
+};
 
 #[derive(Accounts)]
 pub struct ClaimRevenueShare<'info> {
     #[account(
         mut,
-        seeds = [b"revenue_event".as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
+        seeds = [b"revenue_event".as_ref(), revenue_event.realm.as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
         bump = revenue_event.bump
     )]
     pub revenue_event: Account<'info, RevenueEvent>,
@@ -30,6 +33,17 @@ pub struct ClaimRevenueShare<'info> {
     )]
     pub developer_profile: Account<'info, DeveloperProfile>,
 
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Governance config of the realm chosen in ScoreConfig, the same
+    /// half-life source the decay crank uses; required unless no decay realm is set
+    #[account(
+        seeds = [b"governance_config".as_ref(), score_config.decay_realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Option<Account<'info, GovernanceConfig>>,
+
     #[account(mut)]
     pub claimant: Signer<'info>,
 
@@ -37,15 +51,21 @@ pub struct ClaimRevenueShare<'info> {
     /// CHECK: Validated by seeds constraint
     #[account(
         mut,
-        seeds = [b"revenue_vault".as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
+        seeds = [b"revenue_vault".as_ref(), revenue_event.realm.as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
         bump
     )]
     pub developer_pool_vault: SystemAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<ClaimRevenueShare>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
     let event = &ctx.accounts.revenue_event;
 
     // Validate event status
@@ -54,8 +74,19 @@ pub fn handler(ctx: Context<ClaimRevenueShare>) -> Result<()> {
         RevenueError::EventAlreadyCompleted
     );
 
-    // Validate contributor has score
-    let contribution_score = ctx.accounts.developer_profile.contribution_score;
+    let clock = Clock::get()?;
+
+    // Score as it stood at the recording time, the moment the event's total
+    // was snapshotted, read from the profile's checkpoint history so neither
+    // decay cranks nor later credits and debits move it
+    let half_life_secs = ctx
+        .accounts
+        .score_config
+        .decay_half_life_secs(&ctx.accounts.governance_config)?;
+    let contribution_score = ctx
+        .accounts
+        .developer_profile
+        .score_at(event.recorded_at, half_life_secs);
     require!(contribution_score > 0, RevenueError::NoContributionScore);
 
     // Validate total contribution score
@@ -64,12 +95,14 @@ pub fn handler(ctx: Context<ClaimRevenueShare>) -> Result<()> {
         RevenueError::DivisionByZero
     );
 
-    // Calculate share using u128 intermediary to prevent overflow
+    // Calculate share using u128 intermediary to prevent overflow; never
+    // pay out more than the pool has left, whatever the snapshot said
     let amount = (event.developer_pool as u128)
         .checked_mul(contribution_score as u128)
         .ok_or(RevenueError::ClaimOverflow)?
         .checked_div(event.total_contribution_score as u128)
         .ok_or(RevenueError::DivisionByZero)? as u64;
+    let amount = amount.min(event.unclaimed_amount());
 
     // Validate vault has sufficient lamports
     require!(
@@ -80,7 +113,12 @@ pub fn handler(ctx: Context<ClaimRevenueShare>) -> Result<()> {
     // Transfer SOL from vault PDA to claimant
     let event_index_bytes = event.event_index.to_le_bytes();
     let vault_bump = ctx.bumps.developer_pool_vault;
-    let vault_seeds: &[&[u8]] = &[b"revenue_vault", event_index_bytes.as_ref(), &[vault_bump]];
+    let vault_seeds: &[&[u8]] = &[
+        b"revenue_vault",
+        event.realm.as_ref(),
+        event_index_bytes.as_ref(),
+        &[vault_bump],
+    ];
     let signer_seeds = &[vault_seeds];
 
     system_program::transfer(
@@ -95,8 +133,6 @@ pub fn handler(ctx: Context<ClaimRevenueShare>) -> Result<()> {
         amount,
     )?;
 
-    let clock = Clock::get()?;
-
     // Set claim fields
     let claim = &mut ctx.accounts.revenue_claim;
     claim.claimant = ctx.accounts.claimant.key();
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct CloseConvictionRound<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.round_type == RoundType::Conviction @ GovernanceError::WrongRoundType
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: stop a conviction pool from approving further ideas.
+///
+/// Conviction pools run without a voting deadline, so this replaces the
+/// Voting -> Closed transition. Stakes can still be withdrawn afterwards, and
+/// the unallocated budget becomes reclaimable.
+pub fn handler(ctx: Context<CloseConvictionRound>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let round = &mut ctx.accounts.idea_round;
+
+    match round.status {
+        RoundStatus::Open | RoundStatus::Voting => {
+            round.status = RoundStatus::Closed;
+        }
+        RoundStatus::Closed => {
+            return Err(GovernanceError::AlreadyClosed.into());
+        }
+        RoundStatus::Cancelled => {
+            return Err(GovernanceError::RoundCancelled.into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct CloseIdeaEarly<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Voting @ GovernanceError::RoundNotInVotingState,
+        constraint = round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: decide an idea before `voting_end` once the uncast
+/// eligible weight (snapshot minus cast) can no longer change the outcome.
+///
+/// Approved when quorum is already met and No plus all remaining weight
+/// cannot reach Yes; Rejected when Yes plus all remaining weight cannot
+/// exceed No.
+pub fn handler(ctx: Context<CloseIdeaEarly>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let round = &ctx.accounts.round;
+    let idea = &mut ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Submitted,
+        GovernanceError::IdeaVotingClosed
+    );
+    require!(now < round.voting_end, GovernanceError::VotingPeriodEnded);
+
+    let cast = idea.total_cast_weight();
+    let remaining = round.eligible_weight_snapshot.saturating_sub(cast) as u128;
+    let yes = idea.yes_weight as u128;
+    let no = idea.no_weight as u128;
+
+    let quorum_met = round.quorum_type.is_met(cast, round.eligible_weight_snapshot);
+
+    if quorum_met && yes > no + remaining {
+        idea.status = IdeaStatus::Approved;
+        idea.execution_eligible_at = now
+            .checked_add(ctx.accounts.governance_config.execution_timelock)
+            .ok_or(GovernanceError::Overflow)?;
+    } else if yes + remaining <= no {
+        idea.status = IdeaStatus::Rejected;
+    } else {
+        return err!(GovernanceError::OutcomeNotDecided);
+    }
+
+    Ok(())
+}
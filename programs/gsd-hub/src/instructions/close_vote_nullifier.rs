@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{IdeaRound, PauseConfig, RoundStatus, VoteDeposit, VoteNullifier};
+
+#[derive(Accounts)]
+pub struct CloseVoteNullifier<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Closed
+            || round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote_nullifier".as_ref(), round.key().as_ref(), vote_nullifier.voter.as_ref()],
+        bump = vote_nullifier.bump
+    )]
+    pub vote_nullifier: Account<'info, VoteNullifier>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), round.realm.as_ref(), vote_nullifier.voter.as_ref()],
+        bump = vote_deposit.bump
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    /// CHECK: Rent recipient, must be the voter who paid for the nullifier
+    #[account(mut, address = vote_nullifier.voter)]
+    pub voter: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: relinquish a closed or cancelled round's compressed
+/// votes on the voter's behalf and close the VoteNullifier, refunding rent to
+/// the voter.
+pub fn handler(ctx: Context<CloseVoteNullifier>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let deposit = &mut ctx.accounts.vote_deposit;
+    deposit.active_votes = deposit
+        .active_votes
+        .checked_sub(ctx.accounts.vote_nullifier.vote_count as u32)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let round = &mut ctx.accounts.round;
+    round.open_vote_accounts = round
+        .open_vote_accounts
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
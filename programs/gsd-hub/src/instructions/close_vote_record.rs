@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::instructions::relinquish_vote::release_vote_record;
+use crate::state::{IdeaRound, PauseConfig, RoundStatus, VoteDeposit, VoteRecord};
+
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Closed
+            || round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        close = voter,
+        constraint = vote_record.round == round.key() @ GovernanceError::VoteRecordRoundMismatch
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), round.realm.as_ref(), vote_record.voter.as_ref()],
+        bump = vote_deposit.bump
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    /// CHECK: Rent recipient, must be the voter who paid for the record
+    #[account(mut, address = vote_record.voter)]
+    pub voter: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: relinquish a closed or cancelled round's vote on the
+/// voter's behalf and close the VoteRecord, refunding rent to the voter.
+pub fn handler(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    release_vote_record(&mut ctx.accounts.vote_deposit, &mut ctx.accounts.round)
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, PauseConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureConviction<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = governance_config.admin == admin.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: set conviction voting parameters.
+///
+/// `half_life` is in seconds; `rho_bps` and `max_share_bps` shape the pass
+/// threshold rho / (max_share - requested_share)^2 of total deposits.
+pub fn handler(
+    ctx: Context<ConfigureConviction>,
+    half_life: i64,
+    rho_bps: u16,
+    max_share_bps: u16,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    require!(
+        half_life > 0 && rho_bps <= 10000 && max_share_bps > 0 && max_share_bps <= 10000,
+        GovernanceError::InvalidConvictionParams
+    );
+
+    let config = &mut ctx.accounts.governance_config;
+    config.conviction_half_life = half_life;
+    config.conviction_rho_bps = rho_bps;
+    config.conviction_max_share_bps = max_share_bps;
+
+    Ok(())
+}
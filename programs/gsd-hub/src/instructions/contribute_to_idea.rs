@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::instructions::cast_vote::verify_gateway_token;
+use crate::math::isqrt;
+use crate::state::{
+    GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, QfContribution, QfIdeaTally, RoundStatus,
+    RoundType,
+};
+
+#[derive(Accounts)]
+pub struct ContributeToIdea<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Voting @ GovernanceError::RoundNotInVotingState,
+        constraint = idea_round.round_type == RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        seeds = [b"idea".as_ref(), idea_round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + QfIdeaTally::INIT_SPACE,
+        seeds = [b"qf_tally".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub qf_tally: Account<'info, QfIdeaTally>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + QfContribution::INIT_SPACE,
+        seeds = [b"qf_contribution".as_ref(), idea.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub qf_contribution: Account<'info, QfContribution>,
+
+    #[account(address = idea_round.budget_mint)]
+    pub budget_mint: Account<'info, Mint>,
+
+    /// Idea-owned escrow holding contributions and, after close, the match
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump,
+        token::mint = budget_mint,
+        token::authority = idea,
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key(),
+        constraint = contributor_token_account.mint == idea_round.budget_mint
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: Validated in verify_gateway_token
+    /// Only required when the round requires Civic Pass sybil resistance
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Contribute tokens directly to an idea in a quadratic funding round.
+///
+/// Each contributor counts once per idea with isqrt of their cumulative
+/// contribution; the idea's match weight is (sum of roots)^2 minus the sum of
+/// squared roots, and the round keeps the running total across ideas.
+pub fn handler(ctx: Context<ContributeToIdea>, amount: u64) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::DEPOSITS)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, GovernanceError::ZeroContribution);
+    require!(
+        now < ctx.accounts.idea_round.voting_end,
+        GovernanceError::VotingPeriodEnded
+    );
+    require!(
+        ctx.accounts.idea.status == IdeaStatus::Submitted,
+        GovernanceError::IdeaAlreadyFinalized
+    );
+
+    if ctx.accounts.idea_round.qf_require_gateway {
+        verify_gateway_token(
+            ctx.accounts.gateway_token.as_ref().map(|gt| gt.as_ref()),
+            &ctx.accounts.contributor.key(),
+            &ctx.accounts.governance_config.civic_gatekeeper_network,
+            now,
+        )?;
+    }
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.contributor_token_account.to_account_info(),
+            to: ctx.accounts.idea_escrow.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let round_key = ctx.accounts.idea_round.key();
+    let idea_key = ctx.accounts.idea.key();
+
+    // First contribution from this wallet to this idea
+    let contribution = &mut ctx.accounts.qf_contribution;
+    let is_new_contributor = contribution.contributor == Pubkey::default();
+    if is_new_contributor {
+        contribution.contributor = ctx.accounts.contributor.key();
+        contribution.idea = idea_key;
+        contribution.bump = ctx.bumps.qf_contribution;
+        contribution.amount = 0;
+        contribution.refunded = false;
+    }
+
+    let old_root = isqrt(contribution.amount);
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(GovernanceError::Overflow)?;
+    let new_root = isqrt(contribution.amount);
+
+    // First contribution to this idea from anyone
+    let tally = &mut ctx.accounts.qf_tally;
+    let round = &mut ctx.accounts.idea_round;
+    if tally.idea == Pubkey::default() {
+        tally.round = round_key;
+        tally.idea = idea_key;
+        tally.bump = ctx.bumps.qf_tally;
+        round.qf_idea_count = round
+            .qf_idea_count
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+
+    tally.total_contributed = tally
+        .total_contributed
+        .checked_add(amount)
+        .ok_or(GovernanceError::Overflow)?;
+    if is_new_contributor {
+        tally.contributor_count = tally
+            .contributor_count
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+    tally.sqrt_sum = tally
+        .sqrt_sum
+        .checked_sub(old_root)
+        .and_then(|v| v.checked_add(new_root))
+        .ok_or(GovernanceError::Overflow)?;
+    tally.sqrt_square_sum = tally
+        .sqrt_square_sum
+        .checked_sub(old_root * old_root)
+        .and_then(|v| v.checked_add(new_root * new_root))
+        .ok_or(GovernanceError::Overflow)?;
+
+    // Keep the round-wide denominator in step with this idea's weight
+    let old_weight = tally.match_weight;
+    tally.match_weight = tally.compute_match_weight();
+    round.qf_total_match_weight = round
+        .qf_total_match_weight
+        .checked_sub(old_weight)
+        .and_then(|v| v.checked_add(tally.match_weight))
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
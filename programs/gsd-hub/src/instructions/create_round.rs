@@ -1,15 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::GovernanceError;
-use crate::state::{GovernanceConfig, IdeaRound, QuorumType, RoundStatus};
+use crate::state::{GovernanceConfig, IdeaRound, OperatorRole, PauseConfig, QuorumType, RevenueConfig};
 
 #[derive(Accounts)]
 pub struct CreateRound<'info> {
     #[account(
         mut,
-        seeds = [b"governance_config"],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump,
-        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+        constraint = OperatorRole::permits(
+            admin.key(),
+            governance_config.admin,
+            &operator_role,
+            OperatorRole::ROUND_CREATOR
+        ) @ GovernanceError::UnauthorizedAdmin
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
@@ -17,7 +23,7 @@ pub struct CreateRound<'info> {
         init,
         payer = admin,
         space = 8 + IdeaRound::INIT_SPACE,
-        seeds = [b"idea_round".as_ref(), governance_config.round_count.to_le_bytes().as_ref()],
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), governance_config.round_count.to_le_bytes().as_ref()],
         bump
     )]
     pub idea_round: Account<'info, IdeaRound>,
@@ -26,6 +32,48 @@ pub struct CreateRound<'info> {
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    // --- Optional funding budget accounts (all required when budget_amount > 0) ---
+
+    /// Source of treasury_address for the budget transfer
+    #[account(
+        seeds = [b"revenue_config".as_ref(), governance_config.realm.as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Option<Account<'info, RevenueConfig>>,
+
+    /// Mint of the funding budget (e.g. GSD or USDC)
+    pub budget_mint: Option<Account<'info, Mint>>,
+
+    /// Treasury wallet co-signing the budget transfer; checked against revenue_config
+    pub treasury: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Round-owned escrow holding the budget until allocations are paid out
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"round_budget".as_ref(), idea_round.key().as_ref()],
+        bump,
+        token::mint = budget_mint,
+        token::authority = idea_round,
+    )]
+    pub round_budget_escrow: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Signer's operator roles, required when it is a ROUND_CREATOR rather than the admin
+    #[account(
+        seeds = [b"operator_role".as_ref(), governance_config.realm.as_ref(), admin.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -35,7 +83,10 @@ pub fn handler(
     voting_end: i64,
     quorum_type: QuorumType,
     content_hash: [u8; 32],
+    budget_amount: u64,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     // Validate timestamps: submission_start < submission_end < voting_end, all > 0
     require!(
         submission_start > 0 && submission_end > 0 && voting_end > 0,
@@ -46,17 +97,68 @@ pub fn handler(
         GovernanceError::InvalidTimestamps
     );
 
-    let round = &mut ctx.accounts.idea_round;
-    round.authority = ctx.accounts.admin.key();
-    round.round_index = ctx.accounts.governance_config.round_count;
-    round.bump = ctx.bumps.idea_round;
-    round.status = RoundStatus::Open;
-    round.submission_start = submission_start;
-    round.submission_end = submission_end;
-    round.voting_end = voting_end;
-    round.idea_count = 0;
-    round.quorum_type = quorum_type;
-    round.content_hash = content_hash;
+    // Escrow the funding budget from the treasury, if any
+    let mut budget_mint = Pubkey::default();
+    if budget_amount > 0 {
+        let accounts = &ctx.accounts;
+        let (
+            Some(revenue_config),
+            Some(mint),
+            Some(treasury),
+            Some(treasury_token_account),
+            Some(round_budget_escrow),
+            Some(token_program),
+        ) = (
+            accounts.revenue_config.as_ref(),
+            accounts.budget_mint.as_ref(),
+            accounts.treasury.as_ref(),
+            accounts.treasury_token_account.as_ref(),
+            accounts.round_budget_escrow.as_ref(),
+            accounts.token_program.as_ref(),
+        )
+        else {
+            return Err(GovernanceError::BudgetAccountsMissing.into());
+        };
+
+        require_keys_eq!(
+            treasury.key(),
+            revenue_config.treasury_address,
+            GovernanceError::InvalidTreasury
+        );
+        require!(
+            treasury_token_account.owner == treasury.key()
+                && treasury_token_account.mint == mint.key(),
+            GovernanceError::InvalidTreasury
+        );
+
+        let transfer_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: treasury_token_account.to_account_info(),
+                to: round_budget_escrow.to_account_info(),
+                authority: treasury.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, budget_amount)?;
+
+        budget_mint = mint.key();
+    }
+
+    let round_index = ctx.accounts.governance_config.round_count;
+    ctx.accounts.idea_round.init(
+        ctx.accounts.admin.key(),
+        ctx.accounts.governance_config.realm,
+        round_index,
+        ctx.bumps.idea_round,
+        submission_start,
+        submission_end,
+        voting_end,
+        quorum_type,
+        content_hash,
+        budget_mint,
+        budget_amount,
+        ctx.accounts.admin.key(),
+    );
 
     // Increment round count on governance config
     ctx.accounts.governance_config.round_count += 1;
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RoundSchedule, RoundStatus};
+
+#[derive(Accounts)]
+pub struct CreateScheduledRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"round_schedule".as_ref(), governance_config.realm.as_ref()],
+        bump = round_schedule.bump,
+        constraint = round_schedule.is_active @ GovernanceError::ScheduleInactive
+    )]
+    pub round_schedule: Account<'info, RoundSchedule>,
+
+    /// The round most recently created by this schedule.
+    /// Required once the schedule has created at least one round; validated in the handler.
+    pub previous_round: Option<Account<'info, IdeaRound>>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + IdeaRound::INIT_SPACE,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), governance_config.round_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: create the next round of a schedule once the previous
+/// scheduled round has stopped accepting submissions.
+pub fn handler(ctx: Context<CreateScheduledRound>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = &ctx.accounts.round_schedule;
+
+    // The previous round gates the next one: its submission window must have
+    // closed, which honors any extend_round changes without waiting for a
+    // transition_round crank, or it must have been cancelled.
+    if schedule.rounds_created > 0 {
+        let previous = ctx
+            .accounts
+            .previous_round
+            .as_ref()
+            .ok_or(GovernanceError::InvalidPreviousRound)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[
+                b"idea_round".as_ref(),
+                ctx.accounts.governance_config.realm.as_ref(),
+                schedule.last_round_index.to_le_bytes().as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            previous.key(),
+            expected,
+            GovernanceError::InvalidPreviousRound
+        );
+        require!(
+            previous.submission_end <= now || previous.status == RoundStatus::Cancelled,
+            GovernanceError::PreviousRoundStillOpen
+        );
+    }
+
+    // Skip calendar slots whose submission window has already passed so a late
+    // crank never creates a round that is closed on arrival.
+    let mut submission_start = schedule.next_submission_start;
+    let slot_end = submission_start
+        .checked_add(schedule.submission_length)
+        .ok_or(GovernanceError::Overflow)?;
+    if now >= slot_end {
+        let missed = (now - slot_end) / schedule.interval + 1;
+        submission_start = missed
+            .checked_mul(schedule.interval)
+            .and_then(|offset| submission_start.checked_add(offset))
+            .ok_or(GovernanceError::Overflow)?;
+    }
+    let submission_end = submission_start
+        .checked_add(schedule.submission_length)
+        .ok_or(GovernanceError::Overflow)?;
+    let voting_end = submission_end
+        .checked_add(schedule.voting_length)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let round_index = ctx.accounts.governance_config.round_count;
+
+    // Stamp the current admin, not whoever created the schedule, so rounds
+    // created after an admin handover belong to the new admin
+    let authority = ctx.accounts.governance_config.admin;
+    let quorum_type = schedule.quorum_type;
+    let content_hash = schedule.content_hash;
+    ctx.accounts.idea_round.init(
+        authority,
+        ctx.accounts.governance_config.realm,
+        round_index,
+        ctx.bumps.idea_round,
+        submission_start,
+        submission_end,
+        voting_end,
+        quorum_type,
+        content_hash,
+        Pubkey::default(),
+        0,
+        ctx.accounts.cranker.key(),
+    );
+
+    // Increment round count on governance config, as create_round does
+    let config = &mut ctx.accounts.governance_config;
+    config.round_count = config
+        .round_count
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let schedule = &mut ctx.accounts.round_schedule;
+    schedule.last_round_index = round_index;
+    schedule.rounds_created = schedule
+        .rounds_created
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+    schedule.next_submission_start = submission_start
+        .checked_add(schedule.interval)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
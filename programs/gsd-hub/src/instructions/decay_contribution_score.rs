@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::errors::GsdHubError;
+use crate::state::{DeveloperProfile, GovernanceConfig, PauseConfig, ScoreConfig};
+
+#[derive(Accounts)]
+pub struct DecayContributionScore<'info> {
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Governance config of the realm chosen in ScoreConfig, source of the half-life
+    #[account(
+        seeds = [b"governance_config".as_ref(), score_config.decay_realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"developer", developer_wallet.key().as_ref()],
+        bump = developer_profile.bump,
+    )]
+    pub developer_profile: Account<'info, DeveloperProfile>,
+
+    /// CHECK: Used only for PDA seed derivation
+    pub developer_wallet: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Apply 2^(-elapsed / half_life) to a developer's stored score since the
+/// last crank. Profiles that have never been scored only start the clock.
+pub fn handler(ctx: Context<DecayContributionScore>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let profile = &mut ctx.accounts.developer_profile;
+
+    require!(
+        profile.score_decayed_at == 0
+            || now.saturating_sub(profile.score_decayed_at) >= DeveloperProfile::MIN_DECAY_INTERVAL,
+        GsdHubError::DecayTooSoon
+    );
+
+    profile.apply_score_decay(now, ctx.accounts.governance_config.decay_half_life_secs());
+    profile.updated_at = now;
+
+    Ok(())
+}
@@ -1,18 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::{DelegationRecord, GovernanceConfig, VoteDeposit};
+use crate::state::{DelegateProfile, DelegationRecord, GovernanceConfig, PauseConfig, VoteDeposit};
 
 #[derive(Accounts)]
 pub struct DelegateVote<'info> {
     #[account(
-        seeds = [b"governance_config".as_ref()],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
     #[account(
-        seeds = [b"vote_deposit".as_ref(), delegator.key().as_ref()],
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), delegator.key().as_ref()],
         bump = vote_deposit.bump,
         constraint = vote_deposit.deposited_amount > 0 @ GovernanceError::NoDeposit,
         constraint = vote_deposit.active_votes == 0 @ GovernanceError::CannotDelegateWithActiveVotes
@@ -23,7 +23,7 @@ pub struct DelegateVote<'info> {
         init,
         payer = delegator,
         space = 8 + DelegationRecord::INIT_SPACE,
-        seeds = [b"delegation".as_ref(), delegator.key().as_ref()],
+        seeds = [b"delegation".as_ref(), governance_config.realm.as_ref(), delegator.key().as_ref()],
         bump
     )]
     pub delegation_record: Account<'info, DelegationRecord>,
@@ -34,17 +34,44 @@ pub struct DelegateVote<'info> {
     /// CHECK: Just stored as a pubkey -- the delegate receiving voting power
     pub delegate: UncheckedAccount<'info>,
 
+    /// Required when GovernanceConfig.require_delegate_profile is set
+    #[account(
+        seeds = [b"delegate_profile".as_ref(), governance_config.realm.as_ref(), delegate.key().as_ref()],
+        bump = delegate_profile.bump
+    )]
+    pub delegate_profile: Option<Account<'info, DelegateProfile>>,
+
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<DelegateVote>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     let clock = Clock::get()?;
 
+    match ctx.accounts.delegate_profile.as_ref() {
+        Some(profile) => require!(
+            profile.accepting_delegations,
+            GovernanceError::DelegateNotAccepting
+        ),
+        None => require!(
+            !ctx.accounts.governance_config.require_delegate_profile,
+            GovernanceError::DelegateProfileRequired
+        ),
+    }
+
     let record = &mut ctx.accounts.delegation_record;
     record.delegator = ctx.accounts.delegator.key();
     record.delegate = ctx.accounts.delegate.key();
     record.bump = ctx.bumps.delegation_record;
+    record.realm = ctx.accounts.governance_config.realm;
     record.delegated_amount = ctx.accounts.vote_deposit.deposited_amount;
+    record.deposited_at = ctx.accounts.vote_deposit.deposit_timestamp;
+    record.pending_amount = ctx.accounts.vote_deposit.pending_amount;
     record.delegated_at = clock.unix_timestamp;
     record.is_active = true;
     record.effective_from_round = ctx.accounts.governance_config.round_count;
@@ -2,14 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::GovernanceError;
-use crate::state::{GovernanceConfig, VoteDeposit};
+use crate::state::{GovernanceConfig, PauseConfig, VoteDeposit};
 
 #[derive(Accounts)]
 pub struct DepositTokens<'info> {
     #[account(
         mut,
-        seeds = [b"governance_config".as_ref()],
-        bump = governance_config.bump
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
@@ -17,7 +17,7 @@ pub struct DepositTokens<'info> {
         init_if_needed,
         payer = depositor,
         space = 8 + VoteDeposit::INIT_SPACE,
-        seeds = [b"vote_deposit".as_ref(), depositor.key().as_ref()],
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), depositor.key().as_ref()],
         bump
     )]
     pub vote_deposit: Account<'info, VoteDeposit>,
@@ -40,9 +40,15 @@ pub struct DepositTokens<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::DEPOSITS)?;
+
     require!(amount > 0, GovernanceError::InsufficientDeposit);
 
     let clock = Clock::get()?;
@@ -61,28 +67,26 @@ pub fn handler(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
 
     // Update vote deposit
     let deposit = &mut ctx.accounts.vote_deposit;
-    deposit.deposited_amount = deposit
-        .deposited_amount
-        .checked_add(amount)
-        .ok_or(GovernanceError::Overflow)?;
+    let old_amount = deposit.deposited_amount;
 
-    // Set timestamp fields on first deposit
+    // Eligibility runs from the first deposit; rounds snapshotted before a
+    // top-up still count the rest of the deposit, just not the top-up
     if deposit.deposit_timestamp == 0 {
-        deposit.deposit_timestamp = now;
         deposit.eligible_at = now
             .checked_add(ctx.accounts.governance_config.deposit_timelock)
             .ok_or(GovernanceError::Overflow)?;
     }
+    deposit.record_top_up(amount, now, ctx.accounts.governance_config.votes_open_until)?;
 
     deposit.bump = ctx.bumps.vote_deposit;
     deposit.authority = ctx.accounts.depositor.key();
+    deposit.realm = ctx.accounts.governance_config.realm;
 
     // Increment global total deposited
-    let config = &mut ctx.accounts.governance_config;
-    config.total_deposited = config
-        .total_deposited
-        .checked_add(amount)
-        .ok_or(GovernanceError::Overflow)?;
+    let new_amount = deposit.deposited_amount;
+    ctx.accounts
+        .governance_config
+        .record_deposit_change(old_amount, new_amount)?;
 
     Ok(())
 }
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::instructions::cast_vote::untally_vote;
+use crate::state::{
+    ConvictionStake, GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, VoteDeposit,
+    VoteRecord,
+};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        seeds = [b"pause_config".as_ref()],
+        bump = pause_config.bump,
+        constraint = pause_config.is_paused(PauseConfig::VOTING | PauseConfig::DEPOSITS)
+            @ GovernanceError::ProtocolNotPaused
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), depositor.key().as_ref()],
+        bump = vote_deposit.bump,
+        constraint = vote_deposit.deposited_amount > 0 @ GovernanceError::NoDeposit
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == depositor.key()
+            && user_token_account.mint == governance_config.governance_token_mint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == governance_config.key()
+            && escrow_token_account.mint == governance_config.governance_token_mint
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Return a depositor's entire escrowed balance while voting or deposits are
+/// paused.
+///
+/// Skips the active-vote checks of `withdraw_tokens`, but the withdrawn
+/// tokens must not keep voting, so every active vote of the deposit is passed
+/// in `remaining_accounts`:
+/// - (idea, conviction_stake) pairs, zeroed so they stop accruing
+/// - (round, idea, vote_record) triples; the weight comes off ideas that are
+///   still undecided and the record is left for relinquishing later
+///
+/// Compressed votes carry no on-chain record, so they are removed beforehand
+/// with `strip_compressed_vote`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EmergencyWithdraw<'info>>,
+) -> Result<()> {
+    release_votes(
+        &mut ctx.accounts.vote_deposit,
+        &ctx.accounts.governance_config,
+        ctx.accounts.depositor.key(),
+        ctx.remaining_accounts,
+    )?;
+
+    let amount = ctx.accounts.vote_deposit.deposited_amount;
+
+    let governance_config_seeds: &[&[u8]] = &[
+        b"governance_config".as_ref(),
+        ctx.accounts.governance_config.realm.as_ref(),
+        &[ctx.accounts.governance_config.bump],
+    ];
+    let signer_seeds = &[governance_config_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.governance_config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let deposit = &mut ctx.accounts.vote_deposit;
+    deposit.deposited_amount = 0;
+    deposit.deposit_timestamp = 0;
+    deposit.eligible_at = 0;
+    deposit.pending_amount = 0;
+
+    ctx.accounts
+        .governance_config
+        .record_deposit_change(amount, 0)?;
+
+    Ok(())
+}
+
+/// Walk the stake pairs and vote record triples of `depositor`, then require
+/// the deposit to end up unstaked with every remaining active vote passed.
+fn release_votes<'info>(
+    deposit: &mut VoteDeposit,
+    governance_config: &GovernanceConfig,
+    depositor: Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vote_records: Vec<Pubkey> = Vec::new();
+
+    let mut i = 0;
+    while i < accounts.len() {
+        let is_round = accounts[i]
+            .try_borrow_data()?
+            .starts_with(IdeaRound::DISCRIMINATOR);
+        if is_round {
+            require!(i + 3 <= accounts.len(), GovernanceError::InvalidVoteAccounts);
+            // Each record counts once towards the deposit's active votes
+            require!(
+                !vote_records.contains(accounts[i + 2].key),
+                GovernanceError::InvalidVoteAccounts
+            );
+            strip_vote_record(governance_config, depositor, &accounts[i..i + 3])?;
+            vote_records.push(*accounts[i + 2].key);
+            i += 3;
+        } else {
+            require!(i + 2 <= accounts.len(), GovernanceError::InvalidStakeAccounts);
+            release_conviction_stake(deposit, governance_config, depositor, now, &accounts[i..i + 2])?;
+            i += 2;
+        }
+    }
+
+    require!(deposit.conviction_staked == 0, GovernanceError::ConvictionStakesOutstanding);
+    require!(
+        vote_records.len() as u32 == deposit.active_votes,
+        GovernanceError::VotesOutstanding
+    );
+    Ok(())
+}
+
+/// Take a (round, idea, vote_record) triple's weight off the idea if it is
+/// still undecided, zeroing the record's weight so it is only taken once.
+fn strip_vote_record<'info>(
+    governance_config: &GovernanceConfig,
+    depositor: Pubkey,
+    triple: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let round = Account::<IdeaRound>::try_from(&triple[0])?;
+    let mut idea = Account::<Idea>::try_from(&triple[1])?;
+    let mut record = Account::<VoteRecord>::try_from(&triple[2])?;
+    require!(
+        round.realm == governance_config.realm
+            && idea.round == round.key()
+            && record.round == round.key()
+            && record.idea == idea.key()
+            && record.voter == depositor,
+        GovernanceError::InvalidVoteAccounts
+    );
+
+    if idea.status == IdeaStatus::Submitted && record.weight > 0 {
+        untally_vote(&mut idea, record.vote, record.weight)?;
+        record.weight = 0;
+        idea.exit(&crate::ID)?;
+        record.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+/// Zero an (idea, conviction_stake) pair of `depositor`, folding in the
+/// conviction earned so far.
+fn release_conviction_stake<'info>(
+    deposit: &mut VoteDeposit,
+    governance_config: &GovernanceConfig,
+    depositor: Pubkey,
+    now: i64,
+    pair: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let mut idea = Account::<Idea>::try_from(&pair[0])?;
+    let mut stake = Account::<ConvictionStake>::try_from(&pair[1])?;
+    let (expected_stake, _) = Pubkey::find_program_address(
+        &[b"conviction_stake", idea.key().as_ref(), depositor.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(stake.key(), expected_stake, GovernanceError::InvalidStakeAccounts);
+    if stake.amount == 0 {
+        return Ok(());
+    }
+
+    if idea.status == IdeaStatus::Submitted {
+        idea.accrue_conviction(now, governance_config.conviction_half_life);
+    }
+    idea.conviction_staked = idea
+        .conviction_staked
+        .checked_sub(stake.amount)
+        .ok_or(GovernanceError::Overflow)?;
+    deposit.conviction_staked = deposit
+        .conviction_staked
+        .checked_sub(stake.amount)
+        .ok_or(GovernanceError::Overflow)?;
+    deposit.active_votes = deposit.active_votes.saturating_sub(1);
+
+    stake.amount = 0;
+    stake.updated_at = now;
+    idea.exit(&crate::ID)?;
+    stake.exit(&crate::ID)?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct EnableConvictionVoting<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin,
+        constraint = governance_config.conviction_half_life > 0 @ GovernanceError::ConvictionDisabled
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Open @ GovernanceError::RoundNotOpen,
+        constraint = idea_round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = idea_round.has_budget() @ GovernanceError::NoBudget
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: turn a budgeted round into a continuous conviction pool.
+///
+/// Ideas can be submitted and are funded from the round budget as soon as
+/// their conviction passes the threshold, ignoring `submission_end` and
+/// `voting_end`, until the admin closes the pool with `close_conviction_round`.
+pub fn handler(ctx: Context<EnableConvictionVoting>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    ctx.accounts.idea_round.round_type = RoundType::Conviction;
+    Ok(())
+}
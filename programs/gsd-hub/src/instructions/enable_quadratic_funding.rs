@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RevenueConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct EnableQuadraticFunding<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [b"revenue_config".as_ref(), governance_config.realm.as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Open @ GovernanceError::RoundNotOpen,
+        constraint = idea_round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = idea_round.has_budget() @ GovernanceError::NoBudget
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: turn a budgeted round into a quadratic funding round.
+///
+/// The round budget becomes the matching pool and contributions are made in
+/// the same mint, which must be the GSD or USDC mint. Must happen before voting
+/// opens, since contributions take the place of votes.
+pub fn handler(ctx: Context<EnableQuadraticFunding>, require_gateway: bool) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let revenue_config = &ctx.accounts.revenue_config;
+    let round = &mut ctx.accounts.idea_round;
+
+    require!(
+        round.budget_mint == revenue_config.gsd_mint || round.budget_mint == revenue_config.usdc_mint,
+        GovernanceError::InvalidContributionMint
+    );
+
+    round.round_type = RoundType::QuadraticFunding;
+    round.qf_require_gateway = require_gateway;
+
+    Ok(())
+}
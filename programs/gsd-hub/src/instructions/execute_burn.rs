@@ -2,24 +2,29 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
 
 use crate::errors::RevenueError;
-use crate::state::{RevenueConfig, RevenueEvent, RevenueStatus};
+use crate::state::{OperatorRole, PauseConfig, RevenueConfig, RevenueEvent, RevenueStatus};
 
 #[derive(Accounts)]
 pub struct ExecuteBurn<'info> {
     #[account(
         mut,
-        seeds = [b"revenue_event".as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
+        seeds = [b"revenue_event".as_ref(), revenue_config.realm.as_ref(), revenue_event.event_index.to_le_bytes().as_ref()],
         bump = revenue_event.bump
     )]
     pub revenue_event: Account<'info, RevenueEvent>,
 
     #[account(
-        constraint = burn_authority.key() == revenue_config.admin @ RevenueError::UnauthorizedBurnAuthority
+        constraint = OperatorRole::permits(
+            burn_authority.key(),
+            revenue_config.admin,
+            &operator_role,
+            OperatorRole::BURN_EXECUTOR
+        ) @ RevenueError::UnauthorizedBurnAuthority
     )]
     pub burn_authority: Signer<'info>,
 
     #[account(
-        seeds = [b"revenue_config"],
+        seeds = [b"revenue_config".as_ref(), revenue_config.realm.as_ref()],
         bump = revenue_config.bump
     )]
     pub revenue_config: Account<'info, RevenueConfig>,
@@ -31,6 +36,17 @@ pub struct ExecuteBurn<'info> {
     pub gsd_mint: Account<'info, Mint>,
 
     pub token_program: Program<'info, Token>,
+
+    /// Signer's operator roles, required when it is a BURN_EXECUTOR rather than the admin
+    #[account(
+        seeds = [b"operator_role".as_ref(), revenue_config.realm.as_ref(), burn_authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -38,6 +54,8 @@ pub fn handler(
     gsd_amount: u64,
     burn_tx_signature: [u8; 64],
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
     let event = &ctx.accounts.revenue_event;
 
     // Validate burn hasn't already been executed
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct ExtendRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Admin-only: push back `submission_end` and/or `voting_end` of a live round.
+///
+/// Deadlines can only move later, and only while they have not yet passed.
+/// `submission_end` can only be extended while the round is still Open.
+/// Works while voting is paused, so deadlines can be pushed past the pause.
+pub fn handler(
+    ctx: Context<ExtendRound>,
+    new_submission_end: Option<i64>,
+    new_voting_end: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &mut ctx.accounts.idea_round;
+
+    require!(
+        round.status == RoundStatus::Open || round.status == RoundStatus::Voting,
+        GovernanceError::RoundNotLive
+    );
+
+    if let Some(submission_end) = new_submission_end {
+        require!(
+            round.status == RoundStatus::Open,
+            GovernanceError::RoundNotOpen
+        );
+        require!(
+            now < round.submission_end,
+            GovernanceError::SubmissionPeriodEnded
+        );
+        require!(
+            submission_end >= round.submission_end,
+            GovernanceError::DeadlineNotExtended
+        );
+        round.submission_end = submission_end;
+    }
+
+    if let Some(voting_end) = new_voting_end {
+        require!(
+            now < round.voting_end,
+            GovernanceError::VotingPeriodEnded
+        );
+        require!(
+            voting_end >= round.voting_end,
+            GovernanceError::DeadlineNotExtended
+        );
+        round.voting_end = voting_end;
+        if round.status == RoundStatus::Voting && round.round_type == RoundType::Standard {
+            ctx.accounts.governance_config.hold_deposits_until(voting_end);
+        }
+    }
+
+    // Keep the schedule ordered: submission_end < voting_end
+    require!(
+        round.submission_end < round.voting_end,
+        GovernanceError::InvalidTimestamps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct FinalizeIdea<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Closed @ GovernanceError::RoundNotClosed,
+        constraint = round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: decide a submitted idea once its round has closed.
+///
+/// An idea is Approved when total cast weight meets the round's quorum against
+/// the eligible weight snapshotted at voting start and Yes outweighs No;
+/// otherwise it is Rejected.
+pub fn handler(ctx: Context<FinalizeIdea>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let round = &ctx.accounts.round;
+    let idea = &mut ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Submitted,
+        GovernanceError::IdeaAlreadyFinalized
+    );
+
+    let quorum_met = round
+        .quorum_type
+        .is_met(idea.total_cast_weight(), round.eligible_weight_snapshot);
+
+    if quorum_met && idea.yes_weight > idea.no_weight {
+        idea.status = IdeaStatus::Approved;
+        idea.execution_eligible_at = now
+            .checked_add(ctx.accounts.governance_config.execution_timelock)
+            .ok_or(GovernanceError::Overflow)?;
+    } else {
+        idea.status = IdeaStatus::Rejected;
+    }
+
+    Ok(())
+}
@@ -1,25 +1,50 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::VerificationError;
-use crate::state::{VerificationConfig, VerificationReport, VerificationStatus, VerificationType};
+use crate::errors::{GovernanceError, VerificationError};
+use crate::state::{OperatorRole, PauseConfig, VerificationConfig, VerificationReport, VerificationStatus, VerificationType};
 
 #[derive(Accounts)]
 pub struct FinalizePeerVerification<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = OperatorRole::permits(
+            authority.key(),
+            verification_config.admin,
+            &operator_role,
+            OperatorRole::VERIFICATION_ORACLE
+        ) @ GovernanceError::MissingOperatorRole
+    )]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"verification", verification_report.developer.as_ref(), verification_report.task_ref.as_ref()],
+        seeds = [
+            b"verification".as_ref(),
+            verification_report.realm.as_ref(),
+            verification_report.developer.as_ref(),
+            verification_report.task_ref.as_ref(),
+        ],
         bump = verification_report.bump
     )]
     pub verification_report: Account<'info, VerificationReport>,
 
+    /// Config of the realm that issued the report
     #[account(
-        seeds = [b"verification_config"],
+        seeds = [b"verification_config".as_ref(), verification_report.realm.as_ref()],
         bump = verification_config.bump
     )]
     pub verification_config: Account<'info, VerificationConfig>,
+
+    /// Signer's operator roles, required when it is a VERIFICATION_ORACLE rather than the admin
+    #[account(
+        seeds = [b"operator_role".as_ref(), verification_report.realm.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -28,6 +53,8 @@ pub fn handler(
     final_confidence: u16,
     peer_report_hash: [u8; 32],
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VERIFICATION)?;
+
     let report = &ctx.accounts.verification_report;
     let config = &ctx.accounts.verification_config;
 
@@ -44,6 +71,7 @@ pub fn handler(
     report.status = VerificationStatus::Completed;
     report.verification_type = VerificationType::Peer;
     report.report_hash = peer_report_hash;
+    report.verified_by = ctx.accounts.authority.key();
 
     Ok(())
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, IdeaStatus, PauseConfig, QfIdeaTally, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct FinalizeQfMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), idea_round.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Closed @ GovernanceError::RoundNotClosed,
+        constraint = idea_round.round_type == RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), idea_round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"qf_tally".as_ref(), idea.key().as_ref()],
+        bump = qf_tally.bump
+    )]
+    pub qf_tally: Account<'info, QfIdeaTally>,
+
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), idea_round.key().as_ref()],
+        bump
+    )]
+    pub round_budget_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: pay a contributed idea its share of the matching pool.
+///
+/// match = budget_amount * match_weight / qf_total_match_weight. The idea's
+/// contributions plus its match become its allocation, claimable by the author
+/// through claim_idea_payout.
+pub fn handler(ctx: Context<FinalizeQfMatch>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    require!(
+        ctx.accounts.idea.status == IdeaStatus::Submitted && !ctx.accounts.qf_tally.matched,
+        GovernanceError::IdeaAlreadyFinalized
+    );
+
+    let round = &ctx.accounts.idea_round;
+    let tally = &ctx.accounts.qf_tally;
+    let match_amount = if round.qf_total_match_weight == 0 {
+        0
+    } else {
+        let share = (round.budget_amount as u128)
+            .checked_mul(tally.match_weight)
+            .ok_or(GovernanceError::Overflow)?
+            / round.qf_total_match_weight;
+        u64::try_from(share).map_err(|_| GovernanceError::Overflow)?
+    };
+
+    if match_amount > 0 {
+        // Round PDA signs for its budget escrow
+        let round_index_bytes = round.round_index.to_le_bytes();
+        let round_seeds: &[&[u8]] = &[
+            b"idea_round".as_ref(),
+            round.realm.as_ref(),
+            round_index_bytes.as_ref(),
+            &[round.bump],
+        ];
+        let signer_seeds = &[round_seeds];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.round_budget_escrow.to_account_info(),
+                to: ctx.accounts.idea_escrow.to_account_info(),
+                authority: ctx.accounts.idea_round.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, match_amount)?;
+    }
+
+    let total_contributed = ctx.accounts.qf_tally.total_contributed;
+
+    let tally = &mut ctx.accounts.qf_tally;
+    tally.matched_amount = match_amount;
+    tally.matched = true;
+
+    let idea = &mut ctx.accounts.idea;
+    idea.status = IdeaStatus::Approved;
+    idea.allocated_amount = total_contributed
+        .checked_add(match_amount)
+        .ok_or(GovernanceError::Overflow)?;
+    idea.escrow_funded = true;
+    idea.execution_eligible_at = Clock::get()?.unix_timestamp;
+
+    let round = &mut ctx.accounts.idea_round;
+    round.budget_allocated = round
+        .budget_allocated
+        .checked_add(match_amount)
+        .ok_or(GovernanceError::Overflow)?;
+    round.budget_disbursed = round
+        .budget_disbursed
+        .checked_add(match_amount)
+        .ok_or(GovernanceError::Overflow)?;
+    round.qf_ideas_matched = round
+        .qf_ideas_matched
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
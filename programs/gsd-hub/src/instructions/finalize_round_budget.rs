@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{BudgetRankEntry, BudgetRanking, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct FinalizeRoundBudget<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), idea_round.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Closed @ GovernanceError::RoundNotClosed,
+        constraint = idea_round.has_budget() @ GovernanceError::NoBudget,
+        constraint = idea_round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = !idea_round.budget_finalized @ GovernanceError::BudgetAlreadyFinalized
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    /// Ranking carried between calls until the budget is finalized
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + BudgetRanking::INIT_SPACE,
+        seeds = [b"budget_ranking".as_ref(), idea_round.key().as_ref()],
+        bump
+    )]
+    pub budget_ranking: Account<'info, BudgetRanking>,
+
+    /// CHECK: Must be `budget_ranking.payer`; receives the ranking rent when it closes
+    #[account(mut)]
+    pub ranking_payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: allocate a closed round's budget to its approved ideas.
+///
+/// Approved ideas are ranked by net Yes weight (ties broken by lower index)
+/// and funded their full request in rank order until the next request no
+/// longer fits the remaining budget. The work can be split across calls:
+/// `remaining_accounts` (writable Ideas) first continue the ranking in index
+/// order from `budget_ranking.next_idea_index`, each of which must already be
+/// finalized; once every idea is ranked, any funded Ideas passed receive
+/// their allocation. The budget is finalized when the last one does.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeRoundBudget<'info>>,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let round_key = ctx.accounts.idea_round.key();
+    let idea_count = ctx.accounts.idea_round.idea_count;
+    let idea_infos = ctx.remaining_accounts;
+
+    let ranking = &mut ctx.accounts.budget_ranking;
+    if ranking.round == Pubkey::default() {
+        ranking.round = round_key;
+        ranking.payer = ctx.accounts.cranker.key();
+        ranking.bump = ctx.bumps.budget_ranking;
+    }
+    require_keys_eq!(
+        ctx.accounts.ranking_payer.key(),
+        ranking.payer,
+        GovernanceError::InvalidRankingPayer
+    );
+
+    // Continue ranking in index order
+    let mut ranked = 0;
+    while !ranking.ranking_complete
+        && ranking.next_idea_index < idea_count
+        && ranked < idea_infos.len()
+    {
+        let idea = load_round_idea(&idea_infos[ranked], &round_key, ctx.program_id)?;
+        require!(
+            idea.idea_index == ranking.next_idea_index,
+            GovernanceError::InvalidIdeaAccounts
+        );
+        require!(
+            idea.status != IdeaStatus::Submitted,
+            GovernanceError::IdeaNotFinalized
+        );
+
+        if idea.status == IdeaStatus::Approved && idea.requested_amount > 0 {
+            require!(
+                ranking.entries.len() < BudgetRanking::MAX_ENTRIES,
+                GovernanceError::BudgetRankingFull
+            );
+            ranking.insert(BudgetRankEntry {
+                idea_index: idea.idea_index,
+                net_yes_weight: idea.net_yes_weight(),
+                requested_amount: idea.requested_amount,
+                allocated: false,
+            });
+        }
+        ranking.next_idea_index += 1;
+        ranked += 1;
+    }
+
+    if !ranking.ranking_complete && ranking.next_idea_index == idea_count {
+        let allocated = ranking.settle_funded_prefix(ctx.accounts.idea_round.budget_amount);
+        ctx.accounts.idea_round.budget_allocated = allocated;
+    }
+    if !ranking.ranking_complete {
+        return Ok(());
+    }
+
+    // Write allocations to the funded ideas passed in, in any order
+    for info in idea_infos.iter() {
+        let mut idea = load_round_idea(info, &round_key, ctx.program_id)?;
+        let Some(position) = ranking.funded_position(idea.idea_index) else {
+            continue;
+        };
+        if ranking.entries[position].allocated {
+            continue;
+        }
+        ranking.entries[position].allocated = true;
+        ranking.allocated_count += 1;
+        let request = ranking.entries[position].requested_amount;
+
+        if idea.status == IdeaStatus::Approved {
+            idea.allocated_amount = request;
+            idea.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        } else {
+            // Vetoed after ranking: its share stays unallocated and reclaimable
+            let round = &mut ctx.accounts.idea_round;
+            round.budget_allocated = round
+                .budget_allocated
+                .checked_sub(request)
+                .ok_or(GovernanceError::Overflow)?;
+        }
+    }
+
+    if ranking.allocated_count == ranking.funded_count {
+        ctx.accounts.idea_round.budget_finalized = true;
+        ctx.accounts
+            .budget_ranking
+            .close(ctx.accounts.ranking_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Deserialize a writable Idea of `round_key`, checking its PDA.
+fn load_round_idea(info: &AccountInfo, round_key: &Pubkey, program_id: &Pubkey) -> Result<Idea> {
+    require!(
+        info.owner == program_id && info.is_writable,
+        GovernanceError::InvalidIdeaAccounts
+    );
+    let idea = Idea::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    let expected = Pubkey::create_program_address(
+        &[
+            b"idea".as_ref(),
+            round_key.as_ref(),
+            idea.idea_index.to_le_bytes().as_ref(),
+            &[idea.bump],
+        ],
+        program_id,
+    )
+    .map_err(|_| GovernanceError::InvalidIdeaAccounts)?;
+    require_keys_eq!(info.key(), expected, GovernanceError::InvalidIdeaAccounts);
+
+    Ok(idea)
+}
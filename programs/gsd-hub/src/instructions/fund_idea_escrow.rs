@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, IdeaStatus, PauseConfig, RoundType};
+
+#[derive(Accounts)]
+pub struct FundIdeaEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), idea_round.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.budget_finalized
+            || idea_round.round_type == RoundType::Conviction @ GovernanceError::BudgetNotFinalized
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), idea_round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(address = idea_round.budget_mint)]
+    pub budget_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), idea_round.key().as_ref()],
+        bump
+    )]
+    pub round_budget_escrow: Account<'info, TokenAccount>,
+
+    /// Idea-owned escrow holding the allocation until it is paid out
+    #[account(
+        init,
+        payer = cranker,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump,
+        token::mint = budget_mint,
+        token::authority = idea,
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: move an approved idea's allocation into its payout escrow.
+///
+/// Only possible once the execution timelock has elapsed, so the veto authority
+/// has had the full window to veto (which releases the allocation instead).
+pub fn handler(ctx: Context<FundIdeaEscrow>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let idea = &ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Approved,
+        GovernanceError::IdeaNotApproved
+    );
+    require!(idea.allocated_amount > 0, GovernanceError::NoAllocation);
+    require!(!idea.escrow_funded, GovernanceError::EscrowAlreadyFunded);
+    require!(
+        now >= idea.execution_eligible_at,
+        GovernanceError::ExecutionTimelockActive
+    );
+
+    let amount = idea.allocated_amount;
+
+    // Round PDA signs for its budget escrow
+    let round = &ctx.accounts.idea_round;
+    let round_index_bytes = round.round_index.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[
+        b"idea_round".as_ref(),
+        round.realm.as_ref(),
+        round_index_bytes.as_ref(),
+        &[round.bump],
+    ];
+    let signer_seeds = &[round_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.round_budget_escrow.to_account_info(),
+            to: ctx.accounts.idea_escrow.to_account_info(),
+            authority: ctx.accounts.idea_round.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    ctx.accounts.idea.escrow_funded = true;
+
+    let round = &mut ctx.accounts.idea_round;
+    round.budget_disbursed = round
+        .budget_disbursed
+        .checked_add(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GovernanceError;
+use crate::state::{ArchiveTreeConfig, GovernanceConfig, PauseConfig};
+
+#[derive(Accounts)]
+pub struct InitArchiveTree<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ArchiveTreeConfig::INIT_SPACE,
+        seeds = [b"archive_tree".as_ref(), governance_config.realm.as_ref()],
+        bump
+    )]
+    pub archive_tree: Account<'info, ArchiveTreeConfig>,
+
+    /// CHECK: Pre-allocated Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: create the archive tree that closed rounds are summarized into.
+pub fn handler(ctx: Context<InitArchiveTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let bump = ctx.bumps.archive_tree;
+    let realm = ctx.accounts.governance_config.realm;
+    let archive_seeds: &[&[u8]] = &[b"archive_tree".as_ref(), realm.as_ref(), &[bump]];
+
+    crate::cpi::compression::init_empty_merkle_tree(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.archive_tree.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        max_depth,
+        max_buffer_size,
+        Some(&[archive_seeds]),
+    )?;
+
+    let archive = &mut ctx.accounts.archive_tree;
+    archive.merkle_tree = ctx.accounts.merkle_tree.key();
+    archive.archived_ideas = 0;
+    archive.archived_rounds = 0;
+    archive.bump = bump;
+    archive.realm = realm;
+    archive.created_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
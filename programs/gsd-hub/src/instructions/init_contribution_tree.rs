@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
-use crate::state::ContributionTreeConfig;
+use crate::state::{ContributionTreeConfig, PauseConfig};
 
 #[derive(Accounts)]
 pub struct InitContributionTree<'info> {
@@ -29,23 +29,51 @@ pub struct InitContributionTree<'info> {
     pub noop_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
+/// Create a contribution tree. With `pda_authority` the tree_config PDA
+/// becomes the compression authority, so appends never depend on the key
+/// that created the tree; otherwise that key owns the tree directly.
 pub fn handler(
     ctx: Context<InitContributionTree>,
     max_depth: u32,
     max_buffer_size: u32,
+    pda_authority: bool,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
     // Initialize the Merkle tree via CPI
-    crate::cpi::compression::init_empty_merkle_tree(
-        &ctx.accounts.merkle_tree.to_account_info(),
-        &ctx.accounts.authority.to_account_info(),
-        &ctx.accounts.noop_program.to_account_info(),
-        &ctx.accounts.compression_program.to_account_info(),
-        max_depth,
-        max_buffer_size,
-        None, // authority signs directly, not a PDA
-    )?;
+    if pda_authority {
+        let merkle_key = ctx.accounts.merkle_tree.key();
+        let tree_config_seeds: &[&[u8]] = &[
+            b"contribution_tree",
+            merkle_key.as_ref(),
+            &[ctx.bumps.tree_config],
+        ];
+        crate::cpi::compression::init_empty_merkle_tree(
+            &ctx.accounts.merkle_tree.to_account_info(),
+            &ctx.accounts.tree_config.to_account_info(),
+            &ctx.accounts.noop_program.to_account_info(),
+            &ctx.accounts.compression_program.to_account_info(),
+            max_depth,
+            max_buffer_size,
+            Some(&[tree_config_seeds]),
+        )?;
+    } else {
+        crate::cpi::compression::init_empty_merkle_tree(
+            &ctx.accounts.merkle_tree.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.noop_program.to_account_info(),
+            &ctx.accounts.compression_program.to_account_info(),
+            max_depth,
+            max_buffer_size,
+            None, // authority signs directly, not a PDA
+        )?;
+    }
 
     // Populate the tree config PDA
     let config = &mut ctx.accounts.tree_config;
@@ -54,6 +82,8 @@ pub fn handler(
     config.total_contributions = 0;
     config.bump = ctx.bumps.tree_config;
     config.created_at = Clock::get()?.unix_timestamp;
+    config.pda_authority = pda_authority;
+    config.writers = Vec::new();
 
     Ok(())
 }
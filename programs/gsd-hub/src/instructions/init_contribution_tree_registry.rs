@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::GovernanceError;
+use crate::state::ContributionTreeRegistry;
+
+#[derive(Accounts)]
+pub struct InitContributionTreeRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ContributionTreeRegistry::INIT_SPACE,
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
+    /// Program data account of this program; only its upgrade authority may
+    /// install the registry authority
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ GovernanceError::UnauthorizedUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitContributionTreeRegistry>, registry_authority: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.tree_registry;
+    registry.authority = registry_authority;
+    registry.active_tree = Pubkey::default();
+    registry.bump = ctx.bumps.tree_registry;
+    registry.created_at = Clock::get()?.unix_timestamp;
+    registry.trees = Vec::new();
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::state::GovernanceConfig;
+use crate::state::{GovernanceConfig, PauseConfig};
 
 #[derive(Accounts)]
 pub struct InitGovernanceConfig<'info> {
@@ -9,11 +9,15 @@ pub struct InitGovernanceConfig<'info> {
         init,
         payer = admin,
         space = 8 + GovernanceConfig::INIT_SPACE,
-        seeds = [b"governance_config"],
+        seeds = [b"governance_config".as_ref(), realm.key().as_ref()],
         bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
+    /// Keypair whose public key identifies the realm; signing proves the
+    /// creator controls it, so realms cannot be squatted
+    pub realm: Signer<'info>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -25,6 +29,10 @@ pub struct InitGovernanceConfig<'info> {
     pub veto_authority: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -32,11 +40,14 @@ pub fn handler(
     deposit_timelock: i64,
     execution_timelock: i64,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     let config = &mut ctx.accounts.governance_config;
     config.admin = ctx.accounts.admin.key();
     config.veto_authority = ctx.accounts.veto_authority.key();
     config.governance_token_mint = ctx.accounts.governance_token_mint.key();
     config.bump = ctx.bumps.governance_config;
+    config.realm = ctx.accounts.realm.key();
     config.round_count = 0;
     config.total_deposited = 0;
 
@@ -59,5 +70,11 @@ pub fn handler(
     config.civic_gatekeeper_network = Pubkey::default();
     config.decay_half_life_days = 180;
 
+    // Conviction voting -- disabled until configured
+    config.conviction_half_life = 0;
+    config.conviction_rho_bps = 0;
+    config.conviction_max_share_bps = 0;
+    config.require_delegate_profile = false;
+
     Ok(())
 }
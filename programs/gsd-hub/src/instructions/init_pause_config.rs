@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::GovernanceError;
+use crate::state::PauseConfig;
+
+#[derive(Accounts)]
+pub struct InitPauseConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PauseConfig::INIT_SPACE,
+        seeds = [b"pause_config".as_ref()],
+        bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+
+    /// Program data account of this program; only its upgrade authority may
+    /// install the guardian, so the singleton cannot be squatted
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ GovernanceError::UnauthorizedUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitPauseConfig>, guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.pause_config;
+    config.guardian = guardian;
+    config.paused = 0;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.pause_config;
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::RevenueError;
-use crate::state::RevenueConfig;
+use crate::errors::{GovernanceError, RevenueError};
+use crate::state::{GovernanceConfig, PauseConfig, RevenueConfig};
 
 #[derive(Accounts)]
 pub struct InitRevenueConfig<'info> {
@@ -9,15 +9,27 @@ pub struct InitRevenueConfig<'info> {
         init,
         payer = admin,
         space = 8 + RevenueConfig::INIT_SPACE,
-        seeds = [b"revenue_config"],
+        seeds = [b"revenue_config".as_ref(), governance_config.realm.as_ref()],
         bump
     )]
     pub revenue_config: Account<'info, RevenueConfig>,
 
+    /// Realm whose governance admin sets up this config
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -28,6 +40,8 @@ pub fn handler(
     usdc_mint: Pubkey,
     min_revenue_threshold: u64,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
     let config = &mut ctx.accounts.revenue_config;
 
     // Default split: 60/20/10/10
@@ -45,6 +59,7 @@ pub fn handler(
 
     config.admin = ctx.accounts.admin.key();
     config.bump = ctx.bumps.revenue_config;
+    config.realm = ctx.accounts.governance_config.realm;
     config.developer_bps = developer_bps;
     config.treasury_bps = treasury_bps;
     config.burn_bps = burn_bps;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::GovernanceError;
+use crate::state::RoleRegistry;
+
+#[derive(Accounts)]
+pub struct InitRoleRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleRegistry::INIT_SPACE,
+        seeds = [b"role_registry".as_ref()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Program data account of this program; only its upgrade authority may
+    /// install the registry admin
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ GovernanceError::UnauthorizedUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitRoleRegistry>, admin: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.role_registry;
+    registry.admin = admin;
+    registry.bump = ctx.bumps.role_registry;
+    registry.created_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, PauseConfig, QuorumType, RoundSchedule};
+
+#[derive(Accounts)]
+pub struct InitRoundSchedule<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RoundSchedule::INIT_SPACE,
+        seeds = [b"round_schedule".as_ref(), governance_config.realm.as_ref()],
+        bump
+    )]
+    pub round_schedule: Account<'info, RoundSchedule>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<InitRoundSchedule>,
+    first_submission_start: i64,
+    interval: i64,
+    submission_length: i64,
+    voting_length: i64,
+    quorum_type: QuorumType,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    require!(first_submission_start > 0, GovernanceError::InvalidTimestamps);
+    require!(
+        RoundSchedule::is_valid_cadence(interval, submission_length, voting_length),
+        GovernanceError::InvalidSchedule
+    );
+
+    let schedule = &mut ctx.accounts.round_schedule;
+    schedule.authority = ctx.accounts.admin.key();
+    schedule.bump = ctx.bumps.round_schedule;
+    schedule.realm = ctx.accounts.governance_config.realm;
+    schedule.is_active = true;
+    schedule.interval = interval;
+    schedule.submission_length = submission_length;
+    schedule.voting_length = voting_length;
+    schedule.quorum_type = quorum_type;
+    schedule.content_hash = content_hash;
+    schedule.next_submission_start = first_submission_start;
+    schedule.rounds_created = 0;
+    schedule.last_round_index = 0;
+
+    Ok(())
+}
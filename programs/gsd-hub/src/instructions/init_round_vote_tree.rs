@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GovernanceError;
+use crate::state::{
+    GovernanceConfig, IdeaRound, PauseConfig, RoundStatus, RoundType, NULLIFIER_CAPACITY,
+};
+
+#[derive(Accounts)]
+pub struct InitRoundVoteTree<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Open @ GovernanceError::RoundNotOpen,
+        constraint = idea_round.round_type == RoundType::Standard @ GovernanceError::WrongRoundType,
+        constraint = !idea_round.has_vote_tree() @ GovernanceError::VoteTreeAlreadySet,
+        constraint = idea_round.idea_count <= NULLIFIER_CAPACITY @ GovernanceError::RoundFull
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    /// CHECK: Pre-allocated Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: switch a round to compressed vote receipts.
+///
+/// Initializes a concurrent Merkle tree whose authority is the round PDA, so
+/// only this program can append receipts to it. The round must still fit in
+/// a VoteNullifier bitmap; later submissions are capped to it.
+pub fn handler(
+    ctx: Context<InitRoundVoteTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let round = &ctx.accounts.idea_round;
+    let round_index_bytes = round.round_index.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[
+        b"idea_round".as_ref(),
+        round.realm.as_ref(),
+        round_index_bytes.as_ref(),
+        &[round.bump],
+    ];
+
+    crate::cpi::compression::init_empty_merkle_tree(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.idea_round.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        max_depth,
+        max_buffer_size,
+        Some(&[round_seeds]),
+    )?;
+
+    let round = &mut ctx.accounts.idea_round;
+    round.vote_tree = ctx.accounts.merkle_tree.key();
+    round.vote_receipt_count = 0;
+
+    Ok(())
+}
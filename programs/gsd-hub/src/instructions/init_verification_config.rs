@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::VerificationError;
-use crate::state::VerificationConfig;
+use crate::errors::{GovernanceError, VerificationError};
+use crate::state::{GovernanceConfig, PauseConfig, VerificationConfig};
 
 #[derive(Accounts)]
 pub struct InitVerificationConfig<'info> {
@@ -9,15 +9,27 @@ pub struct InitVerificationConfig<'info> {
         init,
         payer = admin,
         space = 8 + VerificationConfig::INIT_SPACE,
-        seeds = [b"verification_config"],
+        seeds = [b"verification_config".as_ref(), governance_config.realm.as_ref()],
         bump
     )]
     pub verification_config: Account<'info, VerificationConfig>,
 
+    /// Realm whose governance admin sets up this config
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -31,7 +43,10 @@ pub fn handler(
     min_reviewers: u8,
     consensus_threshold_bps: u16,
     review_timeout_days: u8,
+    min_milestone_score: u16,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VERIFICATION)?;
+
     // Validate weights sum to 10000 bps
     let total_weights = code_quality_weight as u32
         + task_fulfillment_weight as u32
@@ -43,9 +58,15 @@ pub fn handler(
         VerificationError::WeightsMustSumTo10000
     );
 
+    require!(
+        min_milestone_score > 0 && min_milestone_score <= 10000,
+        VerificationError::InvalidMilestoneScoreFloor
+    );
+
     let config = &mut ctx.accounts.verification_config;
     config.admin = ctx.accounts.admin.key();
     config.bump = ctx.bumps.verification_config;
+    config.realm = ctx.accounts.governance_config.realm;
     config.version = 1;
     config.confidence_threshold = confidence_threshold;
     config.code_quality_weight = code_quality_weight;
@@ -56,6 +77,7 @@ pub fn handler(
     config.min_reviewers = min_reviewers;
     config.consensus_threshold_bps = consensus_threshold_bps;
     config.review_timeout_days = review_timeout_days;
+    config.min_milestone_score = min_milestone_score;
 
     Ok(())
 }
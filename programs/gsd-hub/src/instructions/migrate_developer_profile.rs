@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::cpi::grow_account;
+use crate::errors::GsdHubError;
+use crate::state::DeveloperProfile;
+
+#[derive(Accounts)]
+pub struct MigrateDeveloperProfile<'info> {
+    /// CHECK: Profile in an older, shorter layout that `Account` cannot
+    /// deserialize; owner and seeds are checked here, the discriminator in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"developer", developer_wallet.key().as_ref()],
+        bump
+    )]
+    pub developer_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Used only for PDA seed derivation
+    pub developer_wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: grow a profile created before fields were appended to
+/// DeveloperProfile, so instructions taking `Account<DeveloperProfile>` can
+/// load it again.
+pub fn handler(ctx: Context<MigrateDeveloperProfile>) -> Result<()> {
+    let profile = ctx.accounts.developer_profile.to_account_info();
+    let new_len = 8 + DeveloperProfile::INIT_SPACE;
+
+    require!(
+        profile.try_borrow_data()?.starts_with(DeveloperProfile::DISCRIMINATOR),
+        GsdHubError::InvalidMigrationAccount
+    );
+    require!(
+        profile.data_len() < new_len,
+        GsdHubError::AccountAlreadyMigrated
+    );
+
+    grow_account(
+        &profile,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )
+}
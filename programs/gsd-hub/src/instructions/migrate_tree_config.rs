@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::cpi::grow_account;
+use crate::errors::GsdHubError;
+use crate::state::ContributionTreeConfig;
+
+#[derive(Accounts)]
+pub struct MigrateTreeConfig<'info> {
+    /// CHECK: Tree config in an older, shorter layout that `Account` cannot
+    /// deserialize; owner and seeds are checked here, the discriminator in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump
+    )]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the tree_config PDA
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: grow a tree config created before fields were appended
+/// to ContributionTreeConfig. The tree keeps its signer authority and an
+/// empty writer allowlist.
+pub fn handler(ctx: Context<MigrateTreeConfig>) -> Result<()> {
+    let tree_config = ctx.accounts.tree_config.to_account_info();
+    let new_len = 8 + ContributionTreeConfig::INIT_SPACE;
+
+    require!(
+        tree_config
+            .try_borrow_data()?
+            .starts_with(ContributionTreeConfig::DISCRIMINATOR),
+        GsdHubError::InvalidMigrationAccount
+    );
+    require!(
+        tree_config.data_len() < new_len,
+        GsdHubError::AccountAlreadyMigrated
+    );
+
+    grow_account(
+        &tree_config,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )
+}
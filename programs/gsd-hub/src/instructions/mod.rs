@@ -1,51 +1,187 @@
+pub mod accept_governance_admin;
+pub mod accept_revenue_admin;
+pub mod accept_role_admin;
+pub mod accept_tree_authority;
+pub mod accept_verification_admin;
+pub mod add_idea_milestone;
+pub mod amend_contribution;
+pub mod apply_contribution_proof;
+pub mod apply_contribution_proof_v2;
+pub mod approve_idea_milestone;
+pub mod archive_idea;
+pub mod archive_round;
+pub mod cancel_round;
 pub mod cast_vote;
+pub mod cast_vote_compressed;
+pub mod claim_idea_payout;
 pub mod claim_revenue_share;
+pub mod close_conviction_round;
+pub mod close_idea_early;
+pub mod close_vote_nullifier;
+pub mod close_vote_record;
+pub mod configure_conviction;
+pub mod contribute_to_idea;
 pub mod create_round;
+pub mod create_scheduled_round;
+pub mod decay_contribution_score;
 pub mod delegate_vote;
 pub mod deposit_tokens;
+pub mod emergency_withdraw;
+pub mod enable_conviction_voting;
+pub mod enable_quadratic_funding;
 pub mod execute_burn;
+pub mod extend_round;
+pub mod finalize_idea;
 pub mod finalize_peer_verification;
+pub mod finalize_qf_match;
+pub mod finalize_round_budget;
+pub mod fund_idea_escrow;
+pub mod init_archive_tree;
 pub mod init_contribution_tree;
+pub mod init_contribution_tree_registry;
 pub mod init_governance_config;
+pub mod init_pause_config;
 pub mod init_revenue_config;
+pub mod init_role_registry;
+pub mod init_round_schedule;
+pub mod init_round_vote_tree;
 pub mod init_verification_config;
+pub mod migrate_developer_profile;
+pub mod migrate_tree_config;
+pub mod propose_governance_admin;
+pub mod propose_revenue_admin;
+pub mod propose_role_admin;
+pub mod propose_tree_authority;
+pub mod propose_verification_admin;
+pub mod reclaim_milestone;
+pub mod reclaim_round_budget;
+pub mod reclaim_unplanned_allocation;
 pub mod record_contribution;
+pub mod record_contributions_batch;
 pub mod record_revenue_event;
+pub mod refund_qf_contribution;
 pub mod register;
+pub mod register_delegate;
+pub mod release_milestone;
+pub mod relinquish_compressed_votes;
 pub mod relinquish_vote;
+pub mod reschedule_round;
+pub mod revoke_contribution;
 pub mod revoke_delegation;
+pub mod rollover_contribution_tree;
+pub mod set_conviction_stake;
+pub mod set_operator_roles;
+pub mod set_pause_flags;
+pub mod set_round_paused;
+pub mod set_score_limits;
+pub mod set_tree_writers;
+pub mod strip_compressed_vote;
 pub mod submit_idea;
 pub mod submit_peer_review;
 pub mod submit_verification;
+pub mod tally_delegate_round;
 pub mod transition_round;
+pub mod update_conviction;
+pub mod update_delegate_profile;
 pub mod update_governance_config;
 pub mod update_hash;
+pub mod update_round_schedule;
 pub mod update_score;
+pub mod verify_contribution;
 pub mod veto_idea;
+pub mod void_idea;
 pub mod withdraw_tokens;
 
+pub use accept_governance_admin::*;
+pub use accept_revenue_admin::*;
+pub use accept_role_admin::*;
+pub use accept_tree_authority::*;
+pub use accept_verification_admin::*;
+pub use add_idea_milestone::*;
+pub use amend_contribution::*;
+pub use apply_contribution_proof::*;
+pub use apply_contribution_proof_v2::*;
+pub use approve_idea_milestone::*;
+pub use archive_idea::*;
+pub use archive_round::*;
+pub use cancel_round::*;
 pub use cast_vote::*;
+pub use cast_vote_compressed::*;
+pub use claim_idea_payout::*;
 pub use claim_revenue_share::*;
+pub use close_conviction_round::*;
+pub use close_idea_early::*;
+pub use close_vote_nullifier::*;
+pub use close_vote_record::*;
+pub use configure_conviction::*;
+pub use contribute_to_idea::*;
 pub use create_round::*;
+pub use create_scheduled_round::*;
+pub use decay_contribution_score::*;
 pub use delegate_vote::*;
 pub use deposit_tokens::*;
+pub use emergency_withdraw::*;
+pub use enable_conviction_voting::*;
+pub use enable_quadratic_funding::*;
 pub use execute_burn::*;
+pub use extend_round::*;
+pub use finalize_idea::*;
 pub use finalize_peer_verification::*;
+pub use finalize_qf_match::*;
+pub use finalize_round_budget::*;
+pub use fund_idea_escrow::*;
+pub use init_archive_tree::*;
 pub use init_contribution_tree::*;
+pub use init_contribution_tree_registry::*;
 pub use init_governance_config::*;
+pub use init_pause_config::*;
 pub use init_revenue_config::*;
+pub use init_role_registry::*;
+pub use init_round_schedule::*;
+pub use init_round_vote_tree::*;
 pub use init_verification_config::*;
+pub use migrate_developer_profile::*;
+pub use migrate_tree_config::*;
+pub use propose_governance_admin::*;
+pub use propose_revenue_admin::*;
+pub use propose_role_admin::*;
+pub use propose_tree_authority::*;
+pub use propose_verification_admin::*;
+pub use reclaim_milestone::*;
+pub use reclaim_round_budget::*;
+pub use reclaim_unplanned_allocation::*;
 pub use record_contribution::*;
+pub use record_contributions_batch::*;
 pub use record_revenue_event::*;
+pub use refund_qf_contribution::*;
 pub use register::*;
+pub use register_delegate::*;
+pub use release_milestone::*;
+pub use relinquish_compressed_votes::*;
 pub use relinquish_vote::*;
+pub use reschedule_round::*;
+pub use revoke_contribution::*;
 pub use revoke_delegation::*;
+pub use rollover_contribution_tree::*;
+pub use set_conviction_stake::*;
+pub use set_operator_roles::*;
+pub use set_pause_flags::*;
+pub use set_round_paused::*;
+pub use set_score_limits::*;
+pub use set_tree_writers::*;
+pub use strip_compressed_vote::*;
 pub use submit_idea::*;
 pub use submit_peer_review::*;
 pub use submit_verification::*;
+pub use tally_delegate_round::*;
 pub use transition_round::*;
+pub use update_conviction::*;
+pub use update_delegate_profile::*;
 pub use update_governance_config::*;
 pub use update_hash::*;
+pub use update_round_schedule::*;
 pub use update_score::*;
+pub use verify_contribution::*;
 pub use veto_idea::*;
+pub use void_idea::*;
 pub use withdraw_tokens::*;
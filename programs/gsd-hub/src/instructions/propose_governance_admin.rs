@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::GovernanceConfig;
+
+#[derive(Accounts)]
+pub struct ProposeGovernanceAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = governance_config.admin == admin.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeGovernanceAdmin>,
+    new_admin: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .governance_config
+        .pending_admin
+        .propose(new_admin, expires_at, now)
+}
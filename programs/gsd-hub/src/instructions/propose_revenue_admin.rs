@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RevenueError;
+use crate::state::RevenueConfig;
+
+#[derive(Accounts)]
+pub struct ProposeRevenueAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"revenue_config".as_ref(), revenue_config.realm.as_ref()],
+        bump = revenue_config.bump,
+        constraint = revenue_config.admin == admin.key() @ RevenueError::UnauthorizedRevenueAdmin
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeRevenueAdmin>,
+    new_admin: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .revenue_config
+        .pending_admin
+        .propose(new_admin, expires_at, now)
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::RoleRegistry;
+
+#[derive(Accounts)]
+pub struct ProposeRoleAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"role_registry".as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.admin == admin.key() @ GovernanceError::UnauthorizedRoleAdmin
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeRoleAdmin>,
+    new_admin: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .role_registry
+        .pending_admin
+        .propose(new_admin, expires_at, now)
+}
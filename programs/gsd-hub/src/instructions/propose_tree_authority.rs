@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use crate::errors::GsdHubError;
+use crate::state::ContributionTreeConfig;
+
+#[derive(Accounts)]
+pub struct ProposeTreeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// Propose (or, with `Pubkey::default()`, cancel) a new tree authority.
+///
+/// For a signer-owned Merkle tree, the compression authority is parked on the
+/// tree_config PDA while a proposal is pending; the proposed key receives it
+/// on accept, and cancelling hands it back. Appends are blocked until the
+/// handover resolves. PDA-owned trees never move, so only the recorded
+/// authority changes.
+pub fn handler(
+    ctx: Context<ProposeTreeAuthority>,
+    new_authority: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.tree_config;
+    let was_pending = config.pending_authority.admin != Pubkey::default();
+    config.pending_authority.propose(new_authority, expires_at, now)?;
+    let is_pending = config.pending_authority.admin != Pubkey::default();
+
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let compression_program = ctx.accounts.compression_program.to_account_info();
+
+    if ctx.accounts.tree_config.pda_authority {
+        return Ok(());
+    }
+
+    if !was_pending && is_pending {
+        // Park the tree on the config PDA until the new authority accepts
+        crate::cpi::compression::transfer_authority(
+            &merkle_tree,
+            &ctx.accounts.authority.to_account_info(),
+            &compression_program,
+            ctx.accounts.tree_config.key(),
+            None,
+        )?;
+    } else if was_pending && !is_pending {
+        // Cancelled -- return the tree to the current authority
+        let merkle_key = merkle_tree.key();
+        let tree_config_seeds: &[&[u8]] = &[
+            b"contribution_tree",
+            merkle_key.as_ref(),
+            &[ctx.accounts.tree_config.bump],
+        ];
+        crate::cpi::compression::transfer_authority(
+            &merkle_tree,
+            &ctx.accounts.tree_config.to_account_info(),
+            &compression_program,
+            ctx.accounts.authority.key(),
+            Some(&[tree_config_seeds]),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VerificationError;
+use crate::state::VerificationConfig;
+
+#[derive(Accounts)]
+pub struct ProposeVerificationAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"verification_config".as_ref(), verification_config.realm.as_ref()],
+        bump = verification_config.bump,
+        constraint = verification_config.admin == admin.key() @ VerificationError::UnauthorizedVerificationAdmin
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeVerificationAdmin>,
+    new_admin: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .verification_config
+        .pending_admin
+        .propose(new_admin, expires_at, now)
+}
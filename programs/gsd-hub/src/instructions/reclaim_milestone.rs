@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaMilestone, IdeaRound, PauseConfig};
+
+#[derive(Accounts)]
+pub struct ReclaimMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_milestone".as_ref(), idea.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump
+    )]
+    pub milestone: Account<'info, IdeaMilestone>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), round.key().as_ref()],
+        bump
+    )]
+    pub round_budget_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: return the funds of a milestone that was not
+/// released by its deadline from the idea escrow to the round budget.
+///
+/// The amount leaves the idea's allocation and milestone plan, so the
+/// remaining milestones can still be released and the round budget can be
+/// reclaimed to the treasury once settled.
+pub fn handler(ctx: Context<ReclaimMilestone>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let milestone = &ctx.accounts.milestone;
+
+    require!(
+        !milestone.released,
+        GovernanceError::MilestoneAlreadyReleased
+    );
+    require!(!milestone.reclaimed, GovernanceError::MilestoneReclaimed);
+    require!(
+        Clock::get()?.unix_timestamp > milestone.deadline,
+        GovernanceError::MilestoneDeadlineNotReached
+    );
+
+    let amount = milestone.amount;
+
+    let idea = &mut ctx.accounts.idea;
+    idea.milestone_total = idea
+        .milestone_total
+        .checked_sub(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    return_to_round_budget(
+        &mut ctx.accounts.round,
+        &mut ctx.accounts.idea,
+        &ctx.accounts.idea_escrow,
+        &ctx.accounts.round_budget_escrow,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    ctx.accounts.milestone.reclaimed = true;
+
+    Ok(())
+}
+
+/// Move `amount` of an idea's escrowed allocation back into the round budget
+/// escrow and un-allocate it from both the idea and the round.
+pub(crate) fn return_to_round_budget<'info>(
+    round: &mut Account<'info, IdeaRound>,
+    idea: &mut Account<'info, Idea>,
+    idea_escrow: &Account<'info, TokenAccount>,
+    round_budget_escrow: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    // Idea PDA signs for its payout escrow
+    let round_key = round.key();
+    let idea_index_bytes = idea.idea_index.to_le_bytes();
+    let idea_seeds: &[&[u8]] = &[
+        b"idea".as_ref(),
+        round_key.as_ref(),
+        idea_index_bytes.as_ref(),
+        &[idea.bump],
+    ];
+    let signer_seeds = &[idea_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Transfer {
+            from: idea_escrow.to_account_info(),
+            to: round_budget_escrow.to_account_info(),
+            authority: idea.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    idea.allocated_amount = idea
+        .allocated_amount
+        .checked_sub(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    round.budget_allocated = round
+        .budget_allocated
+        .checked_sub(amount)
+        .ok_or(GovernanceError::Overflow)?;
+    round.budget_disbursed = round
+        .budget_disbursed
+        .checked_sub(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
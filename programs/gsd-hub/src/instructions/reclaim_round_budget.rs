@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RevenueConfig, RoundStatus};
+
+#[derive(Accounts)]
+pub struct ReclaimRoundBudget<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [b"revenue_config".as_ref(), governance_config.realm.as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.has_budget() @ GovernanceError::NoBudget
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), idea_round.key().as_ref()],
+        bump
+    )]
+    pub round_budget_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == revenue_config.treasury_address
+            && treasury_token_account.mint == idea_round.budget_mint @ GovernanceError::InvalidTreasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: return the unallocated part of a round budget to the treasury.
+///
+/// Allowed once allocations are final (or every QF match is paid), or at any
+/// time after cancellation.
+/// Allocations not yet moved into idea escrows stay in the round escrow.
+pub fn handler(ctx: Context<ReclaimRoundBudget>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let round = &ctx.accounts.idea_round;
+
+    require!(
+        round.is_budget_settled() || round.status == RoundStatus::Cancelled,
+        GovernanceError::BudgetNotFinalized
+    );
+
+    let amount = ctx
+        .accounts
+        .round_budget_escrow
+        .amount
+        .saturating_sub(round.outstanding_allocations());
+    require!(amount > 0, GovernanceError::NoAllocation);
+
+    let round_index_bytes = round.round_index.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[
+        b"idea_round".as_ref(),
+        round.realm.as_ref(),
+        round_index_bytes.as_ref(),
+        &[round.bump],
+    ];
+    let signer_seeds = &[round_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.round_budget_escrow.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.idea_round.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    Ok(())
+}
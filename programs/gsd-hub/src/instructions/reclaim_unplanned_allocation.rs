@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::reclaim_milestone::return_to_round_budget;
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, PauseConfig, RoundType, MILESTONE_PLANNING_PERIOD};
+
+#[derive(Accounts)]
+pub struct ReclaimUnplannedAllocation<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.round_type != RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"round_budget".as_ref(), round.key().as_ref()],
+        bump
+    )]
+    pub round_budget_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: once the milestone planning period is over, return
+/// the part of an escrowed allocation no milestone covers to the round budget.
+///
+/// Afterwards the milestone plan covers the whole allocation, so the
+/// registered milestones can be released.
+pub fn handler(ctx: Context<ReclaimUnplannedAllocation>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let idea = &ctx.accounts.idea;
+
+    require!(idea.escrow_funded, GovernanceError::EscrowNotFunded);
+    require!(
+        Clock::get()?.unix_timestamp
+            >= idea.execution_eligible_at.saturating_add(MILESTONE_PLANNING_PERIOD),
+        GovernanceError::MilestoneDeadlineNotReached
+    );
+
+    let amount = idea.allocated_amount.saturating_sub(idea.milestone_total);
+    require!(amount > 0, GovernanceError::NoUnplannedAllocation);
+
+    return_to_round_budget(
+        &mut ctx.accounts.round,
+        &mut ctx.accounts.idea,
+        &ctx.accounts.idea_escrow,
+        &ctx.accounts.round_budget_escrow,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
 use crate::errors::GsdHubError;
-use crate::state::{ContributionLeaf, ContributionTreeConfig};
+use crate::state::{
+    ContributionDetails, ContributionLeaf, ContributionLeafV2, ContributionTreeConfig,
+    ContributionTreeRegistry, OperatorRole, PauseConfig,
+};
 
 #[derive(Accounts)]
 pub struct RecordContribution<'info> {
@@ -9,7 +12,8 @@ pub struct RecordContribution<'info> {
         mut,
         seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
         bump = tree_config.bump,
-        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+        constraint = tree_config.may_write(authority.key(), &operator_role)
+            @ GsdHubError::InvalidTreeAuthority,
     )]
     pub tree_config: Account<'info, ContributionTreeConfig>,
 
@@ -17,6 +21,15 @@ pub struct RecordContribution<'info> {
     #[account(mut)]
     pub merkle_tree: UncheckedAccount<'info>,
 
+    /// Registry whose active tree must be `merkle_tree`
+    #[account(
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump = tree_registry.bump,
+        constraint = tree_registry.active_tree == merkle_tree.key()
+            @ GsdHubError::InactiveContributionTree,
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
     pub authority: Signer<'info>,
 
     /// CHECK: Validated against known program ID
@@ -26,6 +39,17 @@ pub struct RecordContribution<'info> {
     /// CHECK: Validated against known program ID
     #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
     pub compression_program: UncheckedAccount<'info>,
+
+    /// Signer's operator roles, required when it is a TREE_WRITER rather than the tree authority or an allowlisted writer
+    #[account(
+        seeds = [b"operator_role".as_ref(), OperatorRole::HUB_REALM.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -34,7 +58,10 @@ pub fn handler(
     task_ref: [u8; 32],
     verification_score: u16,
     content_hash: [u8; 32],
+    details: Option<ContributionDetails>,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
     // Validate verification score range
     require!(
         verification_score <= 10_000,
@@ -52,33 +79,96 @@ pub fn handler(
         content_hash,
     };
 
-    // Serialize leaf data for noop emission (indexers read this)
-    let leaf_data = AnchorSerialize::try_to_vec(&leaf)
-        .map_err(|_| error!(GsdHubError::InvalidContributionData))?;
+    // Serialize leaf data for noop emission (indexers read this); with
+    // details the contribution is recorded in the versioned v2 encoding,
+    // one leaf per author carrying that author's share
+    let leaves = match &details {
+        None => vec![(AnchorSerialize::try_to_vec(&leaf), leaf.to_leaf_hash())],
+        Some(details) => details
+            .author_shares(developer)?
+            .iter()
+            .map(|share| {
+                let leaf = ContributionLeafV2::for_author(&leaf, details, share);
+                (AnchorSerialize::try_to_vec(&leaf), leaf.to_leaf_hash())
+            })
+            .collect(),
+    };
 
-    // Emit full leaf data via noop program for off-chain indexing
-    crate::cpi::noop::wrap_application_data(
-        &ctx.accounts.noop_program.to_account_info(),
-        leaf_data,
-    )?;
+    // Refuse once the active tree is full; the registry authority rolls over
+    let remaining = ctx
+        .accounts
+        .tree_registry
+        .active_capacity()
+        .saturating_sub(ctx.accounts.tree_config.total_contributions);
+    require!(leaves.len() as u64 <= remaining, GsdHubError::TreeFull);
 
-    // Compute leaf hash and append to Merkle tree
-    let leaf_hash = leaf.to_leaf_hash();
-    crate::cpi::compression::append_leaf(
-        &ctx.accounts.merkle_tree.to_account_info(),
-        &ctx.accounts.authority.to_account_info(),
-        &ctx.accounts.noop_program.to_account_info(),
-        &ctx.accounts.compression_program.to_account_info(),
-        leaf_hash,
-        None, // authority signs directly
-    )?;
+    for (leaf_data, leaf_hash) in &leaves {
+        let leaf_data = leaf_data
+            .as_ref()
+            .map_err(|_| error!(GsdHubError::InvalidContributionData))?;
+
+        // Emit full leaf data via noop program for off-chain indexing
+        crate::cpi::noop::wrap_application_data(
+            &ctx.accounts.noop_program.to_account_info(),
+            leaf_data.clone(),
+        )?;
+
+        // Append the leaf hash to the Merkle tree
+        append_contribution_leaf(
+            &ctx.accounts.tree_config,
+            &ctx.accounts.merkle_tree.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.noop_program.to_account_info(),
+            &ctx.accounts.compression_program.to_account_info(),
+            *leaf_hash,
+        )?;
+    }
 
     // Increment contribution count
     let config = &mut ctx.accounts.tree_config;
     config.total_contributions = config
         .total_contributions
-        .checked_add(1)
+        .checked_add(leaves.len() as u64)
         .ok_or(GsdHubError::ScoreOverflow)?;
 
     Ok(())
 }
+
+/// Append a leaf hash to a contribution tree. The authority of a
+/// wallet-owned tree signs directly; everyone else appends through the
+/// tree_config PDA, which only works for trees whose compression authority
+/// is that PDA (`pda_authority`).
+pub fn append_contribution_leaf<'info>(
+    tree_config: &Account<'info, ContributionTreeConfig>,
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    leaf_hash: [u8; 32],
+) -> Result<()> {
+    if !tree_config.pda_authority && authority.key() == tree_config.authority {
+        return crate::cpi::compression::append_leaf(
+            merkle_tree,
+            authority,
+            noop_program,
+            compression_program,
+            leaf_hash,
+            None, // authority signs directly
+        );
+    }
+
+    let merkle_key = merkle_tree.key();
+    let tree_config_seeds: &[&[u8]] = &[
+        b"contribution_tree",
+        merkle_key.as_ref(),
+        &[tree_config.bump],
+    ];
+    crate::cpi::compression::append_leaf(
+        merkle_tree,
+        &tree_config.to_account_info(),
+        noop_program,
+        compression_program,
+        leaf_hash,
+        Some(&[tree_config_seeds]),
+    )
+}
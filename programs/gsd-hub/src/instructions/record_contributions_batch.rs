@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::cpi::compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GsdHubError;
+use crate::instructions::record_contribution::append_contribution_leaf;
+use crate::state::{ContributionInput, ContributionLeaf, ContributionTreeConfig, ContributionTreeRegistry, OperatorRole, PauseConfig};
+
+#[derive(Accounts)]
+pub struct RecordContributionsBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.may_write(authority.key(), &operator_role)
+            @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Registry whose active tree must be `merkle_tree`
+    #[account(
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump = tree_registry.bump,
+        constraint = tree_registry.active_tree == merkle_tree.key()
+            @ GsdHubError::InactiveContributionTree,
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// Signer's operator roles, required when it is a TREE_WRITER rather than the tree authority or an allowlisted writer
+    #[account(
+        seeds = [b"operator_role".as_ref(), OperatorRole::HUB_REALM.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Record up to `ContributionLeaf::MAX_BATCH` contributions at once.
+///
+/// All leaves share one timestamp and are emitted in a single noop payload,
+/// a Borsh `Vec<ContributionLeaf>` (u32 LE count prefix, then 106 bytes per
+/// leaf), before each leaf hash is appended in order.
+pub fn handler(ctx: Context<RecordContributionsBatch>, entries: Vec<ContributionInput>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    require!(
+        !entries.is_empty() && entries.len() <= ContributionLeaf::MAX_BATCH,
+        GsdHubError::InvalidBatchSize
+    );
+
+    // The whole batch must fit in the active tree
+    let remaining = ctx
+        .accounts
+        .tree_registry
+        .active_capacity()
+        .saturating_sub(ctx.accounts.tree_config.total_contributions);
+    require!(entries.len() as u64 <= remaining, GsdHubError::TreeFull);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let leaves = entries
+        .into_iter()
+        .map(|entry| {
+            require!(
+                entry.verification_score <= 10_000,
+                GsdHubError::InvalidVerificationScore
+            );
+            Ok(ContributionLeaf {
+                developer: entry.developer,
+                task_ref: entry.task_ref,
+                verification_score: entry.verification_score,
+                timestamp,
+                content_hash: entry.content_hash,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Emit every leaf in one noop payload for off-chain indexing
+    let batch_data = AnchorSerialize::try_to_vec(&leaves)
+        .map_err(|_| error!(GsdHubError::InvalidContributionData))?;
+    crate::cpi::noop::wrap_application_data(
+        &ctx.accounts.noop_program.to_account_info(),
+        batch_data,
+    )?;
+
+    for leaf in leaves.iter() {
+        append_contribution_leaf(
+            &ctx.accounts.tree_config,
+            &ctx.accounts.merkle_tree.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.noop_program.to_account_info(),
+            &ctx.accounts.compression_program.to_account_info(),
+            leaf.to_leaf_hash(),
+        )?;
+    }
+
+    // Single count update for the whole batch
+    let config = &mut ctx.accounts.tree_config;
+    config.total_contributions = config
+        .total_contributions
+        .checked_add(leaves.len() as u64)
+        .ok_or(GsdHubError::ScoreOverflow)?;
+
+    Ok(())
+}
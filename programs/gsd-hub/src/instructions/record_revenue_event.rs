@@ -2,13 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::RevenueError;
-use crate::state::{RevenueConfig, RevenueEvent, RevenueStatus, RevenueToken};
+use crate::state::{OperatorRole, PauseConfig, RevenueConfig, RevenueEvent, RevenueStatus, RevenueToken};
 
 #[derive(Accounts)]
 pub struct RecordRevenueEvent<'info> {
     #[account(
         mut,
-        seeds = [b"revenue_config"],
+        seeds = [b"revenue_config".as_ref(), revenue_config.realm.as_ref()],
         bump = revenue_config.bump
     )]
     pub revenue_config: Account<'info, RevenueConfig>,
@@ -17,7 +17,7 @@ pub struct RecordRevenueEvent<'info> {
         init,
         payer = authority,
         space = 8 + RevenueEvent::INIT_SPACE,
-        seeds = [b"revenue_event".as_ref(), revenue_config.event_count.to_le_bytes().as_ref()],
+        seeds = [b"revenue_event".as_ref(), revenue_config.realm.as_ref(), revenue_config.event_count.to_le_bytes().as_ref()],
         bump
     )]
     pub revenue_event: Account<'info, RevenueEvent>,
@@ -26,18 +26,34 @@ pub struct RecordRevenueEvent<'info> {
     /// CHECK: This is a PDA system account used as a SOL vault; validated by seeds
     #[account(
         mut,
-        seeds = [b"revenue_vault".as_ref(), revenue_config.event_count.to_le_bytes().as_ref()],
+        seeds = [b"revenue_vault".as_ref(), revenue_config.realm.as_ref(), revenue_config.event_count.to_le_bytes().as_ref()],
         bump
     )]
     pub developer_pool_vault: SystemAccount<'info>,
 
     #[account(
         mut,
-        constraint = authority.key() == revenue_config.admin @ RevenueError::UnauthorizedBurnAuthority
+        constraint = OperatorRole::permits(
+            authority.key(),
+            revenue_config.admin,
+            &operator_role,
+            OperatorRole::REVENUE_RECORDER
+        ) @ RevenueError::UnauthorizedBurnAuthority
     )]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Signer's operator roles, required when it is a REVENUE_RECORDER rather than the admin
+    #[account(
+        seeds = [b"operator_role".as_ref(), revenue_config.realm.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -47,6 +63,8 @@ pub fn handler(
     origin_signature: [u8; 64],
     total_contribution_score: u64,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
     let config = &ctx.accounts.revenue_config;
 
     // Validate minimum threshold
@@ -94,6 +112,7 @@ pub fn handler(
     // Set all fields on revenue_event
     let event = &mut ctx.accounts.revenue_event;
     event.event_index = ctx.accounts.revenue_config.event_count;
+    event.realm = ctx.accounts.revenue_config.realm;
     event.bump = ctx.bumps.revenue_event;
     event.vault_bump = ctx.bumps.developer_pool_vault;
     event.token = token;
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, PauseConfig, QfContribution, QfIdeaTally, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct RefundQfContribution<'info> {
+    #[account(
+        constraint = round.status == RoundStatus::Cancelled @ GovernanceError::RoundNotCancelled,
+        constraint = round.round_type == RoundType::QuadraticFunding @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"qf_contribution".as_ref(), idea.key().as_ref(), contributor.key().as_ref()],
+        bump = qf_contribution.bump
+    )]
+    pub qf_contribution: Account<'info, QfContribution>,
+
+    #[account(
+        mut,
+        seeds = [b"qf_tally".as_ref(), idea.key().as_ref()],
+        bump = qf_tally.bump
+    )]
+    pub qf_tally: Account<'info, QfIdeaTally>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key(),
+        constraint = contributor_token_account.mint == round.budget_mint
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Contributor withdraws their QF contribution to an idea of a cancelled round.
+pub fn handler(ctx: Context<RefundQfContribution>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::DEPOSITS)?;
+
+    require!(
+        !ctx.accounts.qf_contribution.refunded,
+        GovernanceError::ContributionAlreadyRefunded
+    );
+
+    let amount = ctx.accounts.qf_contribution.amount;
+
+    // Idea PDA signs for its escrow
+    let round_key = ctx.accounts.round.key();
+    let idea = &ctx.accounts.idea;
+    let idea_index_bytes = idea.idea_index.to_le_bytes();
+    let idea_seeds: &[&[u8]] = &[
+        b"idea".as_ref(),
+        round_key.as_ref(),
+        idea_index_bytes.as_ref(),
+        &[idea.bump],
+    ];
+    let signer_seeds = &[idea_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.idea_escrow.to_account_info(),
+            to: ctx.accounts.contributor_token_account.to_account_info(),
+            authority: ctx.accounts.idea.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    ctx.accounts.qf_contribution.refunded = true;
+    // archive_idea waits for every contribution to be refunded
+    ctx.accounts.qf_tally.total_contributed = ctx
+        .accounts
+        .qf_tally
+        .total_contributed
+        .checked_sub(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
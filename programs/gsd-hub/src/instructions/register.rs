@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::DeveloperProfile;
+use crate::state::{DeveloperProfile, PauseConfig};
 
 #[derive(Accounts)]
 pub struct RegisterDeveloper<'info> {
@@ -16,9 +16,15 @@ pub struct RegisterDeveloper<'info> {
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<RegisterDeveloper>, profile_hash: [u8; 32]) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
     let profile = &mut ctx.accounts.developer_profile;
     profile.authority = ctx.accounts.authority.key();
     profile.bump = ctx.bumps.developer_profile;
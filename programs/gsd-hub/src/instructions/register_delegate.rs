@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{DelegateProfile, GovernanceConfig, PauseConfig};
+
+#[derive(Accounts)]
+pub struct RegisterDelegate<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = delegate,
+        space = 8 + DelegateProfile::INIT_SPACE,
+        seeds = [b"delegate_profile".as_ref(), governance_config.realm.as_ref(), delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_profile: Account<'info, DelegateProfile>,
+
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Advertise the signer as a delegate. Participation is tracked from the
+/// next round created onwards.
+pub fn handler(
+    ctx: Context<RegisterDelegate>,
+    statement_hash: [u8; 32],
+    topics: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let profile = &mut ctx.accounts.delegate_profile;
+    profile.delegate = ctx.accounts.delegate.key();
+    profile.bump = ctx.bumps.delegate_profile;
+    profile.realm = ctx.accounts.governance_config.realm;
+    profile.statement_hash = statement_hash;
+    profile.topics = topics;
+    profile.accepting_delegations = true;
+    profile.ideas_voted = 0;
+    profile.rounds_voted = 0;
+    profile.rounds_missed = 0;
+    profile.next_round = ctx.accounts.governance_config.round_count;
+    profile.voted_rounds = [0u8; 32];
+    profile.registered_at = now;
+    profile.updated_at = now;
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::GovernanceError;
+use crate::state::{
+    Idea, IdeaMilestone, IdeaRound, IdeaStatus, OperatorRole, PauseConfig, VerificationReport,
+    VerificationStatus,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_milestone".as_ref(), idea.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump
+    )]
+    pub milestone: Account<'info, IdeaMilestone>,
+
+    /// Verification of the milestone's task, issued in the round's realm for the idea author
+    #[account(
+        seeds = [
+            b"verification".as_ref(),
+            round.realm.as_ref(),
+            idea.author.as_ref(),
+            milestone.task_ref.as_ref(),
+        ],
+        bump = verification_report.bump
+    )]
+    pub verification_report: Account<'info, VerificationReport>,
+
+    /// Operator roles of the report's writer, which must be a
+    /// VERIFICATION_ORACLE of the round's realm
+    #[account(
+        seeds = [b"operator_role".as_ref(), round.realm.as_ref(), verification_report.verified_by.as_ref()],
+        bump = verifier_role.bump,
+        constraint = verifier_role.has(OperatorRole::VERIFICATION_ORACLE)
+            @ GovernanceError::MissingOperatorRole
+    )]
+    pub verifier_role: Account<'info, OperatorRole>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_escrow".as_ref(), idea.key().as_ref()],
+        bump
+    )]
+    pub idea_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = author_token_account.owner == idea.author
+            && author_token_account.mint == round.budget_mint
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: release an approved milestone's funds to the idea
+/// author once its task is verified as Completed with at least the
+/// milestone's min score, by a verification submitted after the milestone
+/// was registered and no later than its deadline.
+///
+/// Only reports written in the round's own realm by one of its
+/// VERIFICATION_ORACLEs count; a config admin alone cannot release funds, so
+/// an author cannot verify their own task through a realm they created.
+pub fn handler(ctx: Context<ReleaseMilestone>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::REVENUE)?;
+
+    let idea = &ctx.accounts.idea;
+    let milestone = &ctx.accounts.milestone;
+    let report = &ctx.accounts.verification_report;
+
+    require!(
+        idea.status == IdeaStatus::Approved,
+        GovernanceError::IdeaNotApproved
+    );
+    require!(
+        idea.milestone_total == idea.allocated_amount,
+        GovernanceError::MilestonesIncomplete
+    );
+    require!(
+        !milestone.released,
+        GovernanceError::MilestoneAlreadyReleased
+    );
+    require!(!milestone.reclaimed, GovernanceError::MilestoneReclaimed);
+    require!(milestone.approved, GovernanceError::MilestoneNotApproved);
+    require!(
+        report.status == VerificationStatus::Completed,
+        GovernanceError::VerificationNotCompleted
+    );
+    require!(
+        report.score >= milestone.min_score,
+        GovernanceError::MilestoneScoreTooLow
+    );
+    require!(
+        report.verified_at > milestone.created_at,
+        GovernanceError::VerificationPredatesMilestone
+    );
+    require!(
+        report.verified_at <= milestone.deadline,
+        GovernanceError::VerificationAfterDeadline
+    );
+
+    let amount = milestone.amount;
+
+    // Idea PDA signs for its payout escrow
+    let round_key = ctx.accounts.round.key();
+    let idea_index_bytes = idea.idea_index.to_le_bytes();
+    let idea_seeds: &[&[u8]] = &[
+        b"idea".as_ref(),
+        round_key.as_ref(),
+        idea_index_bytes.as_ref(),
+        &[idea.bump],
+    ];
+    let signer_seeds = &[idea_seeds];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.idea_escrow.to_account_info(),
+            to: ctx.accounts.author_token_account.to_account_info(),
+            authority: ctx.accounts.idea.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let milestone = &mut ctx.accounts.milestone;
+    milestone.released = true;
+    milestone.released_at = Clock::get()?.unix_timestamp;
+
+    let idea = &mut ctx.accounts.idea;
+    idea.released_amount = idea
+        .released_amount
+        .checked_add(amount)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{IdeaRound, PauseConfig, RoundStatus, VoteDeposit, VoteNullifier};
+
+#[derive(Accounts)]
+pub struct RelinquishCompressedVotes<'info> {
+    #[account(
+        mut,
+        constraint = round.status == RoundStatus::Closed
+            || round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote_nullifier".as_ref(), round.key().as_ref(), voter.key().as_ref()],
+        bump = vote_nullifier.bump
+    )]
+    pub vote_nullifier: Account<'info, VoteNullifier>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), round.realm.as_ref(), voter.key().as_ref()],
+        bump = vote_deposit.bump
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Release every active vote a voter cast in a compressed round at once and
+/// close their nullifier, returning its rent.
+pub fn handler(ctx: Context<RelinquishCompressedVotes>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let deposit = &mut ctx.accounts.vote_deposit;
+    deposit.active_votes = deposit
+        .active_votes
+        .checked_sub(ctx.accounts.vote_nullifier.vote_count as u32)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let round = &mut ctx.accounts.round;
+    round.open_vote_accounts = round
+        .open_vote_accounts
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
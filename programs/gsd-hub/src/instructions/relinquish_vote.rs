@@ -1,36 +1,58 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::{IdeaRound, RoundStatus, VoteDeposit, VoteRecord};
+use crate::state::{IdeaRound, PauseConfig, RoundStatus, VoteDeposit, VoteRecord};
 
 #[derive(Accounts)]
 pub struct RelinquishVote<'info> {
     #[account(
-        constraint = round.status == RoundStatus::Closed @ GovernanceError::RoundStillActive
+        mut,
+        constraint = round.status == RoundStatus::Closed
+            || round.status == RoundStatus::Cancelled @ GovernanceError::RoundStillActive
     )]
     pub round: Account<'info, IdeaRound>,
 
     #[account(
-        constraint = vote_record.voter == voter.key()
+        mut,
+        close = voter,
+        constraint = vote_record.voter == voter.key(),
+        constraint = vote_record.round == round.key() @ GovernanceError::VoteRecordRoundMismatch
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
     #[account(
         mut,
-        seeds = [b"vote_deposit".as_ref(), voter.key().as_ref()],
+        seeds = [b"vote_deposit".as_ref(), round.realm.as_ref(), voter.key().as_ref()],
         bump = vote_deposit.bump
     )]
     pub vote_deposit: Account<'info, VoteDeposit>,
 
+    #[account(mut)]
     pub voter: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
+/// Release the vote's hold on the deposit and close the VoteRecord,
+/// returning its rent to the voter.
 pub fn handler(ctx: Context<RelinquishVote>) -> Result<()> {
-    let deposit = &mut ctx.accounts.vote_deposit;
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    release_vote_record(&mut ctx.accounts.vote_deposit, &mut ctx.accounts.round)
+}
+
+/// Shared bookkeeping for closing a VoteRecord once its round has ended.
+pub fn release_vote_record(deposit: &mut VoteDeposit, round: &mut IdeaRound) -> Result<()> {
     deposit.active_votes = deposit
         .active_votes
         .checked_sub(1)
         .ok_or(GovernanceError::Overflow)?;
+    round.open_vote_accounts = round
+        .open_vote_accounts
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
 
     Ok(())
 }
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, RoundStatus};
+
+#[derive(Accounts)]
+pub struct RescheduleRound<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.status == RoundStatus::Open @ GovernanceError::RoundNotOpen
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Admin-only: replace all three timestamps of a round that has not started yet.
+/// Unaffected by the VOTING pause flag.
+pub fn handler(
+    ctx: Context<RescheduleRound>,
+    submission_start: i64,
+    submission_end: i64,
+    voting_end: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &mut ctx.accounts.idea_round;
+
+    // Once submissions have opened, only extend_round may move deadlines
+    require!(
+        now < round.submission_start && round.idea_count == 0,
+        GovernanceError::RoundAlreadyStarted
+    );
+
+    // Same validation as create_round
+    require!(
+        submission_start > 0 && submission_end > 0 && voting_end > 0,
+        GovernanceError::InvalidTimestamps
+    );
+    require!(
+        submission_start < submission_end && submission_end < voting_end,
+        GovernanceError::InvalidTimestamps
+    );
+
+    round.submission_start = submission_start;
+    round.submission_end = submission_end;
+    round.voting_end = voting_end;
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use crate::cpi::compression::{LeafReplacement, ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GsdHubError;
+use crate::instructions::amend_contribution::{
+    close_attestation, emit_contribution_change, replace_contribution_leaf,
+    settle_counted_contribution, CountedCredit,
+};
+use crate::state::{
+    ContributionChange, ContributionTreeConfig, DeveloperProfile, GovernanceConfig, PauseConfig,
+    ScoreConfig, VersionedContributionLeaf,
+};
+
+#[derive(Accounts)]
+#[instruction(previous: VersionedContributionLeaf)]
+pub struct RevokeContribution<'info> {
+    #[account(
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: ["contribution_nullifier", developer, task_ref] PDA, read by
+    /// `settle_counted_contribution`; empty when the leaf was never credited
+    #[account(
+        mut,
+        seeds = [
+            b"contribution_nullifier".as_ref(),
+            previous.developer().as_ref(),
+            previous.task_ref().as_ref(),
+        ],
+        bump
+    )]
+    pub nullifier: UncheckedAccount<'info>,
+
+    /// Profile of the developer, required when the nullifier counted `previous`
+    #[account(
+        mut,
+        seeds = [b"developer", previous.developer().as_ref()],
+        bump = developer_profile.bump,
+    )]
+    pub developer_profile: Option<Account<'info, DeveloperProfile>>,
+
+    /// CHECK: ["contribution_attestation", merkle_tree, developer, task_ref]
+    /// PDA of `previous`, closed by `close_attestation`; empty when the leaf
+    /// was never attested
+    #[account(
+        mut,
+        seeds = [
+            b"contribution_attestation".as_ref(),
+            merkle_tree.key().as_ref(),
+            previous.developer().as_ref(),
+            previous.task_ref().as_ref(),
+        ],
+        bump
+    )]
+    pub attestation: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the attestation's payer; receives its rent when it
+    /// closes. Required only when `attestation` exists
+    #[account(mut)]
+    pub attestation_payer: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Governance config of the realm chosen in ScoreConfig, source of the
+    /// half-life; required unless no decay realm is set
+    #[account(
+        seeds = [b"governance_config".as_ref(), score_config.decay_realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Option<Account<'info, GovernanceConfig>>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Replace a recorded v1 or v2 contribution with its tombstone of the same
+/// version (zero score, `REVOKED_CONTENT_HASH`), which `verify_contribution`
+/// and both apply-proof instructions refuse. A credited leaf is debited from its
+/// developer; the nullifier stays so the task cannot be counted again. An
+/// attestation of the leaf is closed so it no longer reads as proven.
+///
+/// `remaining_accounts` carries the Merkle proof of `previous`, leaf level first.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevokeContribution<'info>>,
+    previous: VersionedContributionLeaf,
+    root: [u8; 32],
+    leaf_index: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    require!(!previous.is_revoked(), GsdHubError::ContributionRevoked);
+
+    let change = ContributionChange {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        leaf_index,
+        previous_leaf_hash: previous.to_leaf_hash(),
+        revoked: true,
+        leaf: previous.tombstone(),
+    };
+    emit_contribution_change(&ctx.accounts.noop_program.to_account_info(), &change)?;
+
+    replace_contribution_leaf(
+        &ctx.accounts.tree_config,
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        &LeafReplacement {
+            root,
+            previous_leaf: change.previous_leaf_hash,
+            new_leaf: change.leaf.to_leaf_hash(),
+            index: leaf_index,
+        },
+    )?;
+    close_attestation(
+        &ctx.accounts.attestation.to_account_info(),
+        &ctx.accounts.attestation_payer,
+    )?;
+
+    let half_life_secs = ctx
+        .accounts
+        .score_config
+        .decay_half_life_secs(&ctx.accounts.governance_config)?;
+    settle_counted_contribution(
+        &ctx.accounts.nullifier.to_account_info(),
+        &mut ctx.accounts.developer_profile,
+        half_life_secs,
+        &CountedCredit {
+            leaf_hash: change.previous_leaf_hash,
+            credited_score: previous.credited_score(),
+            contributed_at: previous.timestamp(),
+        },
+        None,
+    )
+}
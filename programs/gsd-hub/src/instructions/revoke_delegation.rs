@@ -1,13 +1,13 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::DelegationRecord;
+use crate::state::{DelegationRecord, PauseConfig};
 
 #[derive(Accounts)]
 pub struct RevokeDelegation<'info> {
     #[account(
         mut,
-        seeds = [b"delegation".as_ref(), delegator.key().as_ref()],
+        seeds = [b"delegation".as_ref(), delegation_record.realm.as_ref(), delegator.key().as_ref()],
         bump = delegation_record.bump,
         constraint = delegation_record.is_active @ GovernanceError::NoDelegation,
         close = delegator
@@ -16,9 +16,15 @@ pub struct RevokeDelegation<'info> {
 
     #[account(mut)]
     pub delegator: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<RevokeDelegation>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     // Account will be closed by the `close = delegator` constraint,
     // returning rent to the delegator. A new delegation can be created later.
     // Set is_active = false before close for safety (though close zeroes the account)
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::errors::GsdHubError;
+use crate::state::{ContributionTreeConfig, ContributionTreeRegistry, PauseConfig, RegisteredTree};
+
+#[derive(Accounts)]
+pub struct RolloverContributionTree<'info> {
+    #[account(
+        mut,
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump = tree_registry.bump,
+        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
+    /// Config of the pre-allocated tree being promoted, created by `init_contribution_tree`
+    #[account(
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Header parsed by `tree_max_depth`, which checks the owner and account type
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Register a pre-allocated contribution tree and make it the active tree.
+///
+/// The previous active tree stays listed so indexers can still find its
+/// leaves; `record_contribution` only appends to the new one from here on.
+pub fn handler(ctx: Context<RolloverContributionTree>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    let max_depth = crate::cpi::compression::tree_max_depth(&ctx.accounts.merkle_tree)
+        .ok_or(GsdHubError::InvalidMerkleTreeAccount)?;
+    let capacity = 1u64
+        .checked_shl(max_depth)
+        .ok_or(GsdHubError::InvalidMerkleTreeAccount)?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let registry = &mut ctx.accounts.tree_registry;
+    require!(
+        registry.trees.iter().all(|tree| tree.merkle_tree != merkle_tree),
+        GsdHubError::TreeAlreadyRegistered
+    );
+    require!(
+        registry.trees.len() < ContributionTreeRegistry::MAX_TREES,
+        GsdHubError::TreeRegistryFull
+    );
+
+    registry.trees.push(RegisteredTree {
+        merkle_tree,
+        capacity,
+        activated_at: Clock::get()?.unix_timestamp,
+    });
+    registry.active_tree = merkle_tree;
+
+    Ok(())
+}
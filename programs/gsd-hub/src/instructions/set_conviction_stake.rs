@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{
+    ConvictionStake, GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType,
+    VoteDeposit,
+};
+
+#[derive(Accounts)]
+pub struct SetConvictionStake<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.round_type == RoundType::Conviction @ GovernanceError::WrongRoundType
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), voter.key().as_ref()],
+        bump = vote_deposit.bump,
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + ConvictionStake::INIT_SPACE,
+        seeds = [b"conviction_stake".as_ref(), idea.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub conviction_stake: Account<'info, ConvictionStake>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Set how much of the voter's deposit is staked on a conviction idea.
+///
+/// Increasing a stake requires a live round, an undecided idea and an
+/// eligible deposit; decreasing is always allowed so deposits can be freed.
+/// Conviction pools have no voting deadline: they stay live until the admin
+/// closes them with `close_conviction_round`.
+/// A non-zero stake counts as an active vote, which locks the deposit.
+pub fn handler(ctx: Context<SetConvictionStake>, amount: u64) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let round = &ctx.accounts.round;
+    let old_amount = ctx.accounts.conviction_stake.amount;
+
+    if amount > old_amount {
+        require!(
+            round.status == RoundStatus::Open || round.status == RoundStatus::Voting,
+            GovernanceError::RoundNotLive
+        );
+        require!(
+            ctx.accounts.idea.status == IdeaStatus::Submitted,
+            GovernanceError::IdeaVotingClosed
+        );
+        require!(
+            now >= ctx.accounts.vote_deposit.eligible_at,
+            GovernanceError::TokensNotYetEligible
+        );
+    }
+
+    // Fold in conviction earned under the old stake before changing it
+    let idea = &mut ctx.accounts.idea;
+    if idea.status == IdeaStatus::Submitted {
+        idea.accrue_conviction(now, ctx.accounts.governance_config.conviction_half_life);
+    }
+    idea.conviction_staked = idea
+        .conviction_staked
+        .checked_sub(old_amount)
+        .and_then(|v| v.checked_add(amount))
+        .ok_or(GovernanceError::Overflow)?;
+
+    let deposit = &mut ctx.accounts.vote_deposit;
+    deposit.conviction_staked = deposit
+        .conviction_staked
+        .checked_sub(old_amount)
+        .and_then(|v| v.checked_add(amount))
+        .ok_or(GovernanceError::Overflow)?;
+    require!(
+        deposit.conviction_staked <= deposit.deposited_amount,
+        GovernanceError::InsufficientDeposit
+    );
+
+    if old_amount == 0 && amount > 0 {
+        deposit.active_votes = deposit
+            .active_votes
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    } else if old_amount > 0 && amount == 0 {
+        deposit.active_votes = deposit.active_votes.saturating_sub(1);
+    }
+
+    let stake = &mut ctx.accounts.conviction_stake;
+    stake.voter = ctx.accounts.voter.key();
+    stake.idea = ctx.accounts.idea.key();
+    stake.bump = ctx.bumps.conviction_stake;
+    stake.amount = amount;
+    stake.updated_at = now;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, OperatorRole, RoleRegistry};
+
+#[derive(Accounts)]
+#[instruction(realm: Pubkey)]
+pub struct SetOperatorRoles<'info> {
+    /// Grants hub-wide roles under `OperatorRole::HUB_REALM`
+    #[account(seeds = [b"role_registry".as_ref()], bump = role_registry.bump)]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Grants the realm's own roles; required unless `realm` is `HUB_REALM`
+    #[account(
+        seeds = [b"governance_config".as_ref(), realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Option<Account<'info, GovernanceConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + OperatorRole::INIT_SPACE,
+        seeds = [b"operator_role".as_ref(), realm.as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    /// CHECK: Operator key receiving the roles; only used for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the holder's role bitmask in `realm`; 0 revokes everything. Each
+/// realm's admin manages its own grants, while the role registry admin keeps
+/// the hub-wide roles. Not gated by the pause so a leaked operator key can be
+/// cut off mid-incident.
+pub fn handler(ctx: Context<SetOperatorRoles>, realm: Pubkey, roles: u8) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    let grantable = if realm == OperatorRole::HUB_REALM {
+        require_keys_eq!(
+            ctx.accounts.role_registry.admin,
+            admin,
+            GovernanceError::UnauthorizedRoleAdmin
+        );
+        OperatorRole::HUB_ROLES
+    } else {
+        let config = ctx
+            .accounts
+            .governance_config
+            .as_ref()
+            .ok_or(GovernanceError::UnauthorizedAdmin)?;
+        require_keys_eq!(config.admin, admin, GovernanceError::UnauthorizedAdmin);
+        OperatorRole::REALM_ROLES
+    };
+    require!(roles & !grantable == 0, GovernanceError::InvalidOperatorRoles);
+
+    let operator_role = &mut ctx.accounts.operator_role;
+    operator_role.holder = ctx.accounts.holder.key();
+    operator_role.roles = roles;
+    operator_role.granted_by = admin;
+    operator_role.updated_at = Clock::get()?.unix_timestamp;
+    operator_role.bump = ctx.bumps.operator_role;
+    operator_role.realm = realm;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::PauseConfig;
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    #[account(
+        mut,
+        seeds = [b"pause_config".as_ref()],
+        bump = pause_config.bump,
+        constraint = guardian.key() == pause_config.guardian @ GovernanceError::UnauthorizedGuardian
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Replace the paused-subsystem bitmask; 0 resumes everything.
+pub fn handler(ctx: Context<SetPauseFlags>, paused: u8) -> Result<()> {
+    require!(
+        paused & !PauseConfig::ALL == 0,
+        GovernanceError::InvalidPauseFlags
+    );
+
+    let config = &mut ctx.accounts.pause_config;
+    config.paused = paused;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, IdeaRound, RoundStatus};
+
+#[derive(Accounts)]
+pub struct SetRoundPaused<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Admin-only: pause or resume a single live round.
+///
+/// A paused round accepts no submissions or votes; every other round of the
+/// realm keeps running. Deadlines keep running too, so pair a long pause
+/// with `extend_round`.
+pub fn handler(ctx: Context<SetRoundPaused>, paused: bool) -> Result<()> {
+    let round = &mut ctx.accounts.idea_round;
+
+    require!(
+        round.status == RoundStatus::Open || round.status == RoundStatus::Voting,
+        GovernanceError::RoundNotLive
+    );
+    require!(round.paused != paused, GovernanceError::RoundPauseUnchanged);
+
+    round.paused = paused;
+
+    Ok(())
+}
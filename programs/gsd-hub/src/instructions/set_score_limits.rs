@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{RoleRegistry, ScoreConfig};
+
+#[derive(Accounts)]
+pub struct SetScoreLimits<'info> {
+    #[account(
+        seeds = [b"role_registry".as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.admin == admin.key() @ GovernanceError::UnauthorizedRoleAdmin
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ScoreConfig::INIT_SPACE,
+        seeds = [b"score_config".as_ref()],
+        bump
+    )]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set how far a single score oracle update may move a developer's score,
+/// and which realm's half-life the decay crank applies to stored scores.
+/// Owned by the role registry admin, who also decides who holds SCORE_ORACLE.
+pub fn handler(
+    ctx: Context<SetScoreLimits>,
+    max_score_increase: u64,
+    max_tasks_increase: u32,
+    decay_realm: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.score_config;
+    config.max_score_increase = max_score_increase;
+    config.max_tasks_increase = max_tasks_increase;
+    config.decay_realm = decay_realm;
+    config.updated_by = ctx.accounts.admin.key();
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.score_config;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GsdHubError;
+use crate::state::ContributionTreeConfig;
+
+#[derive(Accounts)]
+pub struct SetTreeWriters<'info> {
+    #[account(
+        mut,
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        has_one = authority @ GsdHubError::InvalidTreeAuthority,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Only used to derive the tree_config PDA
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Replace the tree's writer allowlist. Writers append through the
+/// tree_config PDA, so the list only takes effect on PDA-owned trees.
+pub fn handler(ctx: Context<SetTreeWriters>, writers: Vec<Pubkey>) -> Result<()> {
+    require!(
+        writers.len() <= ContributionTreeConfig::MAX_WRITERS,
+        GsdHubError::TooManyTreeWriters
+    );
+
+    ctx.accounts.tree_config.writers = writers;
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::cpi::compression::{LeafReplacement, ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use crate::errors::GovernanceError;
+use crate::instructions::cast_vote::untally_vote;
+use crate::state::{
+    Idea, IdeaRound, IdeaStatus, PauseConfig, VoteDeposit, VoteNullifier, VoteReceiptLeaf,
+};
+
+#[derive(Accounts)]
+pub struct StripCompressedVote<'info> {
+    #[account(
+        seeds = [b"pause_config".as_ref()],
+        bump = pause_config.bump,
+        constraint = pause_config.is_paused(PauseConfig::VOTING | PauseConfig::DEPOSITS)
+            @ GovernanceError::ProtocolNotPaused
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+
+    #[account(
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_nullifier".as_ref(), round.key().as_ref(), voter.key().as_ref()],
+        bump = vote_nullifier.bump
+    )]
+    pub vote_nullifier: Account<'info, VoteNullifier>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_deposit".as_ref(), round.realm.as_ref(), voter.key().as_ref()],
+        bump = vote_deposit.bump
+    )]
+    pub vote_deposit: Account<'info, VoteDeposit>,
+
+    /// CHECK: The round's vote receipt tree, validated by spl-account-compression CPI
+    #[account(mut, address = round.vote_tree @ GovernanceError::NoVoteTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub voter: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+/// Take one compressed vote's weight back off its idea while the protocol is
+/// paused, so the voter can then leave through `emergency_withdraw`.
+///
+/// The receipt is proven against the round's tree and replaced with a
+/// zero-weight copy, which also stops it being stripped twice. The nullifier
+/// bit stays set, so the idea cannot be voted on again. Decided ideas keep
+/// their tallies; only the deposit's hold is released.
+///
+/// `remaining_accounts` carries the Merkle proof nodes, leaf level first.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StripCompressedVote<'info>>,
+    receipt: VoteReceiptLeaf,
+    root: [u8; 32],
+    leaf_index: u32,
+) -> Result<()> {
+    require!(
+        receipt.voter == ctx.accounts.voter.key()
+            && receipt.idea == ctx.accounts.idea.key()
+            && receipt.round == ctx.accounts.round.key(),
+        GovernanceError::InvalidVoteAccounts
+    );
+    require!(receipt.weight > 0, GovernanceError::ReceiptAlreadyStripped);
+    require!(
+        ctx.accounts.vote_nullifier.has_voted(ctx.accounts.idea.idea_index),
+        GovernanceError::InvalidVoteAccounts
+    );
+
+    let stripped = VoteReceiptLeaf {
+        weight: 0,
+        ..receipt.clone()
+    };
+
+    // Emit the zero-weight receipt so indexers see the change
+    let stripped_data = AnchorSerialize::try_to_vec(&stripped)
+        .map_err(|_| error!(GovernanceError::Overflow))?;
+    crate::cpi::noop::wrap_application_data(
+        &ctx.accounts.noop_program.to_account_info(),
+        stripped_data,
+    )?;
+
+    // Round PDA is the tree authority
+    let round = &ctx.accounts.round;
+    let round_index_bytes = round.round_index.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[
+        b"idea_round".as_ref(),
+        round.realm.as_ref(),
+        round_index_bytes.as_ref(),
+        &[round.bump],
+    ];
+    crate::cpi::compression::replace_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.round.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        &LeafReplacement {
+            root,
+            previous_leaf: receipt.to_leaf_hash(),
+            new_leaf: stripped.to_leaf_hash(),
+            index: leaf_index,
+        },
+        Some(&[round_seeds]),
+    )?;
+
+    if ctx.accounts.idea.status == IdeaStatus::Submitted {
+        untally_vote(&mut ctx.accounts.idea, receipt.vote, receipt.weight)?;
+    }
+
+    let nullifier = &mut ctx.accounts.vote_nullifier;
+    nullifier.vote_count = nullifier
+        .vote_count
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    let deposit = &mut ctx.accounts.vote_deposit;
+    deposit.active_votes = deposit
+        .active_votes
+        .checked_sub(1)
+        .ok_or(GovernanceError::Overflow)?;
+
+    Ok(())
+}
@@ -1,15 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::{Idea, IdeaRound, IdeaStatus, RoundStatus};
+use crate::state::{Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType};
 
 #[derive(Accounts)]
 pub struct SubmitIdea<'info> {
     #[account(
         mut,
-        seeds = [b"idea_round".as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        seeds = [b"idea_round".as_ref(), idea_round.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
         bump = idea_round.bump,
-        constraint = idea_round.status == RoundStatus::Open @ GovernanceError::RoundNotOpen
+        constraint = idea_round.status == RoundStatus::Open
+            || (idea_round.status == RoundStatus::Voting && idea_round.round_type == RoundType::Conviction)
+            @ GovernanceError::RoundNotOpen,
+        constraint = !idea_round.paused @ GovernanceError::RoundPaused
     )]
     pub idea_round: Account<'info, IdeaRound>,
 
@@ -26,19 +29,50 @@ pub struct SubmitIdea<'info> {
     pub author: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<SubmitIdea>, content_hash: [u8; 32]) -> Result<()> {
+/// Submit an idea to a round during its submission window.
+///
+/// Conviction pools accept ideas continuously: from submission_start until
+/// the admin closes the pool, including after it has moved to Voting.
+pub fn handler(
+    ctx: Context<SubmitIdea>,
+    content_hash: [u8; 32],
+    requested_amount: u64,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
     let round = &ctx.accounts.idea_round;
 
     // Validate submission period
+    let continuous = round.round_type == RoundType::Conviction;
     require!(
-        now >= round.submission_start && now < round.submission_end,
+        now >= round.submission_start && (continuous || now < round.submission_end),
         GovernanceError::SubmissionPeriodEnded
     );
 
+    // Budgeted rounds: a request can never exceed the whole budget
+    if round.has_budget() {
+        require!(
+            requested_amount <= round.budget_amount,
+            GovernanceError::RequestExceedsBudget
+        );
+    } else {
+        require!(requested_amount == 0, GovernanceError::NoBudget);
+    }
+
+    // Every idea must remain rankable, or finalization could never complete
+    require!(
+        round.idea_count < round.idea_capacity(),
+        GovernanceError::RoundFull
+    );
+
     let idea = &mut ctx.accounts.idea;
     idea.author = ctx.accounts.author.key();
     idea.round = ctx.accounts.idea_round.key();
@@ -52,6 +86,15 @@ pub fn handler(ctx: Context<SubmitIdea>, content_hash: [u8; 32]) -> Result<()> {
     idea.abstain_weight = 0;
     idea.voter_count = 0;
     idea.execution_eligible_at = 0;
+    idea.requested_amount = requested_amount;
+    idea.allocated_amount = 0;
+    idea.escrow_funded = false;
+    idea.released_amount = 0;
+    idea.milestone_count = 0;
+    idea.milestone_total = 0;
+    idea.conviction_staked = 0;
+    idea.conviction = 0;
+    idea.conviction_updated_at = now;
 
     // Increment idea count on the round
     ctx.accounts.idea_round.idea_count += 1;
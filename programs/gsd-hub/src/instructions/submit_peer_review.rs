@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::VerificationError;
-use crate::state::{PeerReview, VerificationReport, VerificationStatus};
+use crate::state::{PauseConfig, PeerReview, VerificationReport, VerificationStatus};
 
 #[derive(Accounts)]
 pub struct SubmitPeerReview<'info> {
@@ -10,7 +10,12 @@ pub struct SubmitPeerReview<'info> {
 
     #[account(
         mut,
-        seeds = [b"verification", verification_report.developer.as_ref(), verification_report.task_ref.as_ref()],
+        seeds = [
+            b"verification".as_ref(),
+            verification_report.realm.as_ref(),
+            verification_report.developer.as_ref(),
+            verification_report.task_ref.as_ref(),
+        ],
         bump = verification_report.bump
     )]
     pub verification_report: Account<'info, VerificationReport>,
@@ -25,6 +30,10 @@ pub struct SubmitPeerReview<'info> {
     pub peer_review: Account<'info, PeerReview>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -35,6 +44,8 @@ pub fn handler(
     passed: bool,
     review_hash: [u8; 32],
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VERIFICATION)?;
+
     let report = &ctx.accounts.verification_report;
 
     // Prevent self-review: reviewer cannot be the developer
@@ -1,19 +1,27 @@
 use anchor_lang::prelude::*;
 
-use crate::errors::VerificationError;
-use crate::state::{VerificationConfig, VerificationReport, VerificationStatus, VerificationType};
+use crate::errors::{GovernanceError, VerificationError};
+use crate::state::{OperatorRole, PauseConfig, VerificationConfig, VerificationReport, VerificationStatus, VerificationType};
 
 #[derive(Accounts)]
 #[instruction(task_ref: [u8; 32])]
 pub struct SubmitVerification<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = OperatorRole::permits(
+            authority.key(),
+            verification_config.admin,
+            &operator_role,
+            OperatorRole::VERIFICATION_ORACLE
+        ) @ GovernanceError::MissingOperatorRole
+    )]
     pub authority: Signer<'info>,
 
     /// CHECK: Developer's public key -- just used for PDA derivation, not validated as signer
     pub developer: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [b"verification_config"],
+        seeds = [b"verification_config".as_ref(), verification_config.realm.as_ref()],
         bump = verification_config.bump
     )]
     pub verification_config: Account<'info, VerificationConfig>,
@@ -22,12 +30,28 @@ pub struct SubmitVerification<'info> {
         init,
         payer = authority,
         space = 8 + VerificationReport::INIT_SPACE,
-        seeds = [b"verification", developer.key().as_ref(), task_ref.as_ref()],
+        seeds = [
+            b"verification".as_ref(),
+            verification_config.realm.as_ref(),
+            developer.key().as_ref(),
+            task_ref.as_ref(),
+        ],
         bump
     )]
     pub verification_report: Account<'info, VerificationReport>,
 
     pub system_program: Program<'info, System>,
+
+    /// Signer's operator roles, required when it is a VERIFICATION_ORACLE rather than the admin
+    #[account(
+        seeds = [b"operator_role".as_ref(), verification_config.realm.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -38,6 +62,8 @@ pub fn handler(
     confidence: u16,
     report_hash: [u8; 32],
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VERIFICATION)?;
+
     // Validate score
     require!(score <= 10000, VerificationError::InvalidVerificationScore);
 
@@ -78,6 +104,8 @@ pub fn handler(
     report.verified_at = clock.unix_timestamp;
     report.reviewer_count = 0;
     report.config_version = config.version;
+    report.realm = config.realm;
+    report.verified_by = ctx.accounts.authority.key();
 
     Ok(())
 }
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{
+    DelegateProfile, GovernanceConfig, IdeaRound, PauseConfig, RoundStatus, RoundType,
+};
+
+#[derive(Accounts)]
+pub struct TallyDelegateRound<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = delegate_profile.next_round < governance_config.round_count @ GovernanceError::RoundStillActive
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"delegate_profile".as_ref(), governance_config.realm.as_ref(), delegate_profile.delegate.as_ref()],
+        bump = delegate_profile.bump
+    )]
+    pub delegate_profile: Account<'info, DelegateProfile>,
+
+    /// CHECK: The IdeaRound at the profile's next_round, deserialized below.
+    /// Empty once the round has been archived.
+    #[account(
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), delegate_profile.next_round.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub idea_round: UncheckedAccount<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: count the delegate's participation in their next
+/// untallied round.
+///
+/// Only Standard rounds take votes, so only a Closed Standard round with at
+/// least one idea counts as missed. Quadratic funding and conviction rounds
+/// are skipped whatever their status, so a live conviction pool never stalls
+/// the walk; only an unfinished Standard round does. An archived round no
+/// longer records its type, so it counts only if the delegate voted in it.
+pub fn handler(ctx: Context<TallyDelegateRound>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let round_info = ctx.accounts.idea_round.to_account_info();
+    let counts_as_missed = if round_info.data_is_empty() {
+        false
+    } else {
+        let round = IdeaRound::try_deserialize(&mut &round_info.try_borrow_data()?[..])?;
+        if round.round_type == RoundType::Standard {
+            require!(
+                round.status == RoundStatus::Closed || round.status == RoundStatus::Cancelled,
+                GovernanceError::RoundStillActive
+            );
+        }
+        round.round_type == RoundType::Standard
+            && round.status == RoundStatus::Closed
+            && round.idea_count > 0
+    };
+
+    let profile = &mut ctx.accounts.delegate_profile;
+    let voted = profile
+        .take_next_round()
+        .ok_or(GovernanceError::Overflow)?;
+    if voted {
+        profile.rounds_voted = profile
+            .rounds_voted
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    } else if counts_as_missed {
+        profile.rounds_missed = profile
+            .rounds_missed
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+
+    Ok(())
+}
@@ -1,20 +1,33 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::{IdeaRound, RoundStatus};
+use crate::state::{GovernanceConfig, IdeaRound, PauseConfig, RoundStatus, RoundType};
 
 #[derive(Accounts)]
 pub struct TransitionRound<'info> {
     #[account(
         mut,
-        seeds = [b"idea_round".as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
         bump = idea_round.bump
     )]
     pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 /// Permissionless crank: anyone can trigger round state transitions after deadlines.
 pub fn handler(ctx: Context<TransitionRound>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
     let round = &mut ctx.accounts.idea_round;
@@ -23,14 +36,30 @@ pub fn handler(ctx: Context<TransitionRound>) -> Result<()> {
         RoundStatus::Open => {
             require!(now >= round.submission_end, GovernanceError::TooEarly);
             round.status = RoundStatus::Voting;
+            // Snapshot eligible voting weight, in tally units, for quorum checks;
+            // deposits topped up from now on are held pending until voting ends
+            round.eligible_weight_snapshot = ctx.accounts.governance_config.eligible_vote_weight();
+            round.weight_snapshot_at = now;
+            if round.round_type == RoundType::Standard {
+                ctx.accounts.governance_config.hold_deposits_until(round.voting_end);
+            }
         }
         RoundStatus::Voting => {
+            // Conviction pools have no voting deadline; the admin closes them
+            require!(
+                round.round_type != RoundType::Conviction,
+                GovernanceError::WrongRoundType
+            );
             require!(now >= round.voting_end, GovernanceError::TooEarly);
             round.status = RoundStatus::Closed;
         }
         RoundStatus::Closed => {
             return Err(GovernanceError::AlreadyClosed.into());
         }
+        RoundStatus::Cancelled => {
+            // Cancelled is terminal -- deadlines no longer drive the round
+            return Err(GovernanceError::RoundCancelled.into());
+        }
     }
 
     Ok(())
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus, RoundType};
+
+#[derive(Accounts)]
+pub struct UpdateConviction<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), idea_round.round_index.to_le_bytes().as_ref()],
+        bump = idea_round.bump,
+        constraint = idea_round.round_type == RoundType::Conviction @ GovernanceError::WrongRoundType
+    )]
+    pub idea_round: Account<'info, IdeaRound>,
+
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), idea_round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: accrue an idea's conviction and approve it once it
+/// passes the threshold for its requested share of the unallocated budget.
+pub fn handler(ctx: Context<UpdateConviction>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.governance_config;
+    let round = &ctx.accounts.idea_round;
+
+    require!(
+        round.status == RoundStatus::Open || round.status == RoundStatus::Voting,
+        GovernanceError::RoundNotLive
+    );
+    require!(
+        ctx.accounts.idea.status == IdeaStatus::Submitted,
+        GovernanceError::IdeaVotingClosed
+    );
+
+    let idea = &mut ctx.accounts.idea;
+    idea.accrue_conviction(now, config.conviction_half_life);
+
+    // Requests that no longer fit the remaining pool simply keep accruing
+    let available = round.unallocated_budget();
+    let requested = idea.requested_amount;
+    if requested == 0 || requested > available {
+        return Ok(());
+    }
+
+    let share_bps = ((requested as u128) * 10_000 / (available as u128)) as u64;
+    let threshold = match config.conviction_threshold(share_bps) {
+        Some(threshold) if threshold > 0 => threshold,
+        _ => return Ok(()),
+    };
+
+    if idea.conviction >= threshold {
+        idea.status = IdeaStatus::Approved;
+        idea.allocated_amount = requested;
+        idea.execution_eligible_at = now
+            .checked_add(config.execution_timelock)
+            .ok_or(GovernanceError::Overflow)?;
+
+        let round = &mut ctx.accounts.idea_round;
+        round.budget_allocated = round
+            .budget_allocated
+            .checked_add(requested)
+            .ok_or(GovernanceError::Overflow)?;
+    }
+
+    Ok(())
+}
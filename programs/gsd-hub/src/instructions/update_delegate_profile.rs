@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{DelegateProfile, PauseConfig};
+
+#[derive(Accounts)]
+pub struct UpdateDelegateProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegate_profile".as_ref(), delegate_profile.realm.as_ref(), delegate.key().as_ref()],
+        bump = delegate_profile.bump
+    )]
+    pub delegate_profile: Account<'info, DelegateProfile>,
+
+    pub delegate: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Replace the delegate's statement and topics, and open or close the
+/// profile to new delegations. Participation counters are untouched.
+pub fn handler(
+    ctx: Context<UpdateDelegateProfile>,
+    statement_hash: [u8; 32],
+    topics: u32,
+    accepting_delegations: bool,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let profile = &mut ctx.accounts.delegate_profile;
+    profile.statement_hash = statement_hash;
+    profile.topics = topics;
+    profile.accepting_delegations = accepting_delegations;
+    profile.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -1,25 +1,23 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GovernanceError;
-use crate::state::GovernanceConfig;
+use crate::state::{GovernanceConfig, PauseConfig};
 
 #[derive(Accounts)]
 pub struct UpdateGovernanceConfig<'info> {
     #[account(
         mut,
-        seeds = [b"governance_config".as_ref()],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump,
-        realloc = 8 + 168,
-        realloc::payer = admin,
-        realloc::zero = false,
         constraint = governance_config.admin == admin.key() @ GovernanceError::UnauthorizedAdmin
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    #[account(mut)]
     pub admin: Signer<'info>,
 
-    pub system_program: Program<'info, System>,
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(
@@ -27,11 +25,15 @@ pub fn handler(
     quadratic_voting_enabled: bool,
     civic_gatekeeper_network: Pubkey,
     decay_half_life_days: u16,
+    require_delegate_profile: bool,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
     let config = &mut ctx.accounts.governance_config;
     config.quadratic_voting_enabled = quadratic_voting_enabled;
     config.civic_gatekeeper_network = civic_gatekeeper_network;
     config.decay_half_life_days = decay_half_life_days;
+    config.require_delegate_profile = require_delegate_profile;
 
     Ok(())
 }
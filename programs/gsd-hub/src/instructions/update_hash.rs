@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::DeveloperProfile;
+use crate::state::{DeveloperProfile, PauseConfig};
 
 #[derive(Accounts)]
 pub struct UpdateProfileHash<'info> {
@@ -12,9 +12,15 @@ pub struct UpdateProfileHash<'info> {
     pub developer_profile: Account<'info, DeveloperProfile>,
 
     pub authority: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<UpdateProfileHash>, new_hash: [u8; 32]) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
     let profile = &mut ctx.accounts.developer_profile;
     profile.profile_hash = new_hash;
     profile.updated_at = Clock::get()?.unix_timestamp;
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{GovernanceConfig, PauseConfig, QuorumType, RoundSchedule};
+
+#[derive(Accounts)]
+pub struct UpdateRoundSchedule<'info> {
+    #[account(
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
+        bump = governance_config.bump,
+        constraint = admin.key() == governance_config.admin @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"round_schedule".as_ref(), governance_config.realm.as_ref()],
+        bump = round_schedule.bump
+    )]
+    pub round_schedule: Account<'info, RoundSchedule>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Admin-only: change the cadence of future rounds or pause/resume the schedule.
+///
+/// Rounds already created are unaffected. `next_submission_start` is kept so the
+/// next round stays anchored to the existing calendar.
+pub fn handler(
+    ctx: Context<UpdateRoundSchedule>,
+    interval: i64,
+    submission_length: i64,
+    voting_length: i64,
+    quorum_type: QuorumType,
+    content_hash: [u8; 32],
+    is_active: bool,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    require!(
+        RoundSchedule::is_valid_cadence(interval, submission_length, voting_length),
+        GovernanceError::InvalidSchedule
+    );
+
+    let schedule = &mut ctx.accounts.round_schedule;
+    schedule.interval = interval;
+    schedule.submission_length = submission_length;
+    schedule.voting_length = voting_length;
+    schedule.quorum_type = quorum_type;
+    schedule.content_hash = content_hash;
+    schedule.is_active = is_active;
+
+    Ok(())
+}
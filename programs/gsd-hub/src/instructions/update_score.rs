@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::DeveloperProfile;
+use crate::errors::{GovernanceError, GsdHubError};
+use crate::state::{DeveloperProfile, OperatorRole, PauseConfig, ScoreAuditEntry, ScoreConfig};
 
 #[derive(Accounts)]
 pub struct UpdateContributionScore<'info> {
@@ -7,9 +8,6 @@ pub struct UpdateContributionScore<'info> {
         mut,
         seeds = [b"developer", developer_wallet.key().as_ref()],
         bump = developer_profile.bump,
-        realloc = 8 + 130,
-        realloc::payer = payer,
-        realloc::zero = false,
     )]
     pub developer_profile: Account<'info, DeveloperProfile>,
 
@@ -18,12 +16,45 @@ pub struct UpdateContributionScore<'info> {
 
     pub authority: Signer<'info>,
 
+    /// Signer's hub-wide operator roles; score updates need SCORE_ORACLE
+    #[account(
+        seeds = [b"operator_role".as_ref(), OperatorRole::HUB_REALM.as_ref(), authority.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.has(OperatorRole::SCORE_ORACLE) @ GovernanceError::MissingOperatorRole
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    /// Per-update limits set by the role registry admin
+    #[account(seeds = [b"score_config".as_ref()], bump = score_config.bump)]
+    pub score_config: Account<'info, ScoreConfig>,
+
+    /// Audit record for this update, keyed by the profile's update counter
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ScoreAuditEntry::INIT_SPACE,
+        seeds = [
+            b"score_audit".as_ref(),
+            developer_wallet.key().as_ref(),
+            developer_profile.score_update_count.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub audit_entry: Account<'info, ScoreAuditEntry>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
+/// Score-oracle write of a profile's absolute score totals, for profiles not
+/// yet scored from proven contributions. The first proven credit retires this
+/// path, since absolute totals would wipe the proven ones.
 pub fn handler(
     ctx: Context<UpdateContributionScore>,
     tasks_completed: u32,
@@ -32,15 +63,73 @@ pub fn handler(
     contribution_score: u64,
     score_version: u8,
 ) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
     let profile = &mut ctx.accounts.developer_profile;
+    let limits = &ctx.accounts.score_config;
     let now = Clock::get()?.unix_timestamp;
 
+    require!(profile.proven_at == 0, GsdHubError::ProfileScoredByProofs);
+
+    // Decreases are allowed (corrections, revoked work); increases are capped
+    // so a compromised oracle key cannot inflate revenue shares in one shot.
+    require!(
+        score_version >= profile.score_version,
+        GsdHubError::ScoreVersionRegressed
+    );
+    require!(
+        contribution_score.saturating_sub(profile.contribution_score) <= limits.max_score_increase,
+        GsdHubError::ScoreIncreaseTooLarge
+    );
+    require!(
+        tasks_completed.saturating_sub(profile.tasks_completed) <= limits.max_tasks_increase,
+        GsdHubError::TaskIncreaseTooLarge
+    );
+    require!(
+        profile.oracle_updated_at == 0
+            || now.saturating_sub(profile.oracle_updated_at)
+                >= DeveloperProfile::MIN_ORACLE_UPDATE_INTERVAL,
+        GsdHubError::ScoreUpdateTooSoon
+    );
+
+    // The remaining fields cannot outgrow what they are derived from
+    require!(
+        total_verification_score
+            <= tasks_completed as u64 * DeveloperProfile::MAX_TASK_VERIFICATION_SCORE,
+        GsdHubError::VerificationTotalTooLarge
+    );
+    require!(
+        time_active_days as i64 <= profile.max_active_days(now),
+        GsdHubError::ActiveDaysTooLarge
+    );
+
+    let audit = &mut ctx.accounts.audit_entry;
+    audit.developer = ctx.accounts.developer_wallet.key();
+    audit.sequence = profile.score_update_count;
+    audit.oracle = ctx.accounts.authority.key();
+    audit.previous_score = profile.contribution_score;
+    audit.new_score = contribution_score;
+    audit.previous_tasks = profile.tasks_completed;
+    audit.new_tasks = tasks_completed;
+    audit.previous_version = profile.score_version;
+    audit.new_version = score_version;
+    audit.recorded_at = now;
+    audit.bump = ctx.bumps.audit_entry;
+
     profile.tasks_completed = tasks_completed;
     profile.total_verification_score = total_verification_score;
     profile.time_active_days = time_active_days;
     profile.contribution_score = contribution_score;
     profile.score_version = score_version;
     profile.last_contribution_at = now;
+    // Oracle scores are current as of now; decay restarts from here
+    profile.score_decayed_at = now;
+    profile.checkpoint_score(now);
+    profile.oracle_updated_at = now;
+    profile.score_update_count = profile
+        .score_update_count
+        .checked_add(1)
+        .ok_or(GsdHubError::ScoreOverflow)?;
 
     // Set first_contribution_at on first score update
     if profile.first_contribution_at == 0 {
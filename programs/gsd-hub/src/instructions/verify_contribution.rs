@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::cpi::compression::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use crate::errors::GsdHubError;
+use crate::state::{
+    ContributionAttestation, ContributionTreeConfig, ContributionTreeRegistry, PauseConfig,
+    VersionedContributionLeaf,
+};
+
+#[derive(Accounts)]
+#[instruction(leaf: VersionedContributionLeaf)]
+pub struct VerifyContribution<'info> {
+    /// Ties the tree to this program's contribution trees; only PDA-owned
+    /// trees hold leaves that went through record_contribution
+    #[account(
+        seeds = [b"contribution_tree", merkle_tree.key().as_ref()],
+        bump = tree_config.bump,
+        constraint = tree_config.pda_authority @ GsdHubError::UntrustedContributionTree,
+    )]
+    pub tree_config: Account<'info, ContributionTreeConfig>,
+
+    /// CHECK: Merkle tree account, validated by spl-account-compression CPI
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Registry that must list `merkle_tree`
+    #[account(
+        seeds = [b"contribution_tree_registry".as_ref()],
+        bump = tree_registry.bump,
+        constraint = tree_registry.is_registered(&merkle_tree.key())
+            @ GsdHubError::UntrustedContributionTree,
+    )]
+    pub tree_registry: Account<'info, ContributionTreeRegistry>,
+
+    /// Attestation to create once the proof checks out; pass None to only verify
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ContributionAttestation::INIT_SPACE,
+        seeds = [
+            b"contribution_attestation".as_ref(),
+            merkle_tree.key().as_ref(),
+            leaf.developer().as_ref(),
+            leaf.task_ref().as_ref(),
+        ],
+        bump
+    )]
+    pub attestation: Option<Account<'info, ContributionAttestation>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validated against known program ID
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Prove a v1 or v2 `leaf` is stored at `leaf_index` of the contribution tree.
+///
+/// `remaining_accounts` carries the Merkle proof nodes, leaf level first.
+/// `root` must be a root still held in the tree's changelog buffer. Leaves
+/// amended or revoked since that root no longer prove, and a tombstone is
+/// refused outright.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyContribution<'info>>,
+    leaf: VersionedContributionLeaf,
+    root: [u8; 32],
+    leaf_index: u32,
+) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::CONTRIBUTIONS)?;
+
+    require!(!leaf.is_revoked(), GsdHubError::ContributionRevoked);
+
+    let leaf_hash = leaf.to_leaf_hash();
+    crate::cpi::compression::verify_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        leaf_hash,
+        leaf_index,
+    )?;
+
+    if let Some(attestation) = ctx.accounts.attestation.as_mut() {
+        attestation.developer = leaf.developer();
+        attestation.task_ref = leaf.task_ref();
+        attestation.merkle_tree = ctx.accounts.merkle_tree.key();
+        attestation.leaf_index = leaf_index;
+        attestation.leaf_hash = leaf_hash;
+        attestation.verification_score = leaf.verification_score();
+        attestation.contributed_at = leaf.timestamp();
+        attestation.attested_at = Clock::get()?.unix_timestamp;
+        attestation.bump = ctx.bumps.attestation.unwrap();
+        attestation.payer = ctx.accounts.payer.key();
+    }
+
+    Ok(())
+}
@@ -12,10 +12,15 @@ pub struct VetoIdea<'info> {
     )]
     pub idea: Account<'info, Idea>,
 
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), governance_config.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
     pub round: Account<'info, IdeaRound>,
 
     #[account(
-        seeds = [b"governance_config".as_ref()],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
@@ -26,6 +31,8 @@ pub struct VetoIdea<'info> {
     pub veto_authority: Signer<'info>,
 }
 
+/// Veto-authority only: reject an idea. Not gated by the VOTING pause, which
+/// is exactly when a bad idea may need stopping.
 pub fn handler(ctx: Context<VetoIdea>) -> Result<()> {
     let idea = &mut ctx.accounts.idea;
 
@@ -35,6 +42,17 @@ pub fn handler(ctx: Context<VetoIdea>) -> Result<()> {
         GovernanceError::NotVetoable
     );
 
+    // Funded escrows are past the veto window; unfunded allocations return to the round budget
+    require!(!idea.escrow_funded, GovernanceError::EscrowAlreadyFunded);
+    if idea.allocated_amount > 0 {
+        let round = &mut ctx.accounts.round;
+        round.budget_allocated = round
+            .budget_allocated
+            .checked_sub(idea.allocated_amount)
+            .ok_or(GovernanceError::Overflow)?;
+        idea.allocated_amount = 0;
+    }
+
     idea.status = IdeaStatus::Vetoed;
 
     Ok(())
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+use crate::state::{Idea, IdeaRound, IdeaStatus, PauseConfig, RoundStatus};
+
+#[derive(Accounts)]
+pub struct VoidIdea<'info> {
+    #[account(
+        mut,
+        seeds = [b"idea".as_ref(), round.key().as_ref(), idea.idea_index.to_le_bytes().as_ref()],
+        bump = idea.bump
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        mut,
+        seeds = [b"idea_round".as_ref(), round.realm.as_ref(), round.round_index.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.status == RoundStatus::Cancelled @ GovernanceError::RoundNotCancelled
+    )]
+    pub round: Account<'info, IdeaRound>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: mark an idea of a cancelled round as Void.
+pub fn handler(ctx: Context<VoidIdea>) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::VOTING)?;
+
+    let idea = &mut ctx.accounts.idea;
+
+    require!(
+        idea.status == IdeaStatus::Submitted || idea.status == IdeaStatus::Approved,
+        GovernanceError::NotVoidable
+    );
+
+    // Allocations not yet moved into the idea escrow return to the round budget
+    if !idea.escrow_funded && idea.allocated_amount > 0 {
+        let round = &mut ctx.accounts.round;
+        round.budget_allocated = round
+            .budget_allocated
+            .checked_sub(idea.allocated_amount)
+            .ok_or(GovernanceError::Overflow)?;
+        idea.allocated_amount = 0;
+    }
+
+    idea.status = IdeaStatus::Void;
+
+    Ok(())
+}
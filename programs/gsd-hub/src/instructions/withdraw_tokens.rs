@@ -2,20 +2,20 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::GovernanceError;
-use crate::state::{GovernanceConfig, VoteDeposit};
+use crate::state::{GovernanceConfig, PauseConfig, VoteDeposit};
 
 #[derive(Accounts)]
 pub struct WithdrawTokens<'info> {
     #[account(
         mut,
-        seeds = [b"governance_config".as_ref()],
+        seeds = [b"governance_config".as_ref(), governance_config.realm.as_ref()],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
     #[account(
         mut,
-        seeds = [b"vote_deposit".as_ref(), depositor.key().as_ref()],
+        seeds = [b"vote_deposit".as_ref(), governance_config.realm.as_ref(), depositor.key().as_ref()],
         bump = vote_deposit.bump,
         constraint = vote_deposit.active_votes == 0 @ GovernanceError::ActiveVotesExist
     )]
@@ -31,9 +31,15 @@ pub struct WithdrawTokens<'info> {
     pub escrow_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+
+    /// CHECK: ["pause_config"] PDA, read by `PauseConfig::require_active`
+    #[account(seeds = [b"pause_config".as_ref()], bump)]
+    pub pause_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()> {
+    PauseConfig::require_active(&ctx.accounts.pause_config, PauseConfig::DEPOSITS)?;
+
     let deposit = &ctx.accounts.vote_deposit;
     require!(
         amount <= deposit.deposited_amount,
@@ -43,6 +49,7 @@ pub fn handler(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()> {
     // Transfer tokens from escrow to user using PDA signer
     let governance_config_seeds: &[&[u8]] = &[
         b"governance_config".as_ref(),
+        ctx.accounts.governance_config.realm.as_ref(),
         &[ctx.accounts.governance_config.bump],
     ];
     let signer_seeds = &[governance_config_seeds];
@@ -60,10 +67,13 @@ pub fn handler(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()> {
 
     // Decrement deposit amount
     let deposit = &mut ctx.accounts.vote_deposit;
+    let old_amount = deposit.deposited_amount;
     deposit.deposited_amount = deposit
         .deposited_amount
         .checked_sub(amount)
         .ok_or(GovernanceError::Overflow)?;
+    // Withdraw settled tokens last, so no top-up can become eligible early
+    deposit.pending_amount = deposit.pending_amount.min(deposit.deposited_amount);
 
     // Reset timestamps if fully withdrawn
     if deposit.deposited_amount == 0 {
@@ -72,11 +82,10 @@ pub fn handler(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()> {
     }
 
     // Decrement global total deposited
-    let config = &mut ctx.accounts.governance_config;
-    config.total_deposited = config
-        .total_deposited
-        .checked_sub(amount)
-        .ok_or(GovernanceError::Overflow)?;
+    let new_amount = deposit.deposited_amount;
+    ctx.accounts
+        .governance_config
+        .record_deposit_change(old_amount, new_amount)?;
 
     Ok(())
 }
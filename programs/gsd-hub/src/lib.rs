@@ -2,11 +2,16 @@ use anchor_lang::prelude::*;
 
 pub mod cpi;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod math;
 pub mod state;
 
 use instructions::*;
-use state::{QuorumType, RevenueToken, VerificationType, VoteChoice};
+use state::{
+    ContributionDetails, ContributionInput, ContributionLeaf, ContributionLeafV2, QuorumType,
+    RevenueToken, VerificationType, VersionedContributionLeaf, VoteChoice, VoteReceiptLeaf,
+};
 
 declare_id!("Gn3kafdEiBZ51T5ewMTtXLUDYzECk87kPwxDAjspqYhw");
 
@@ -32,8 +37,9 @@ pub mod gsd_hub {
         ctx: Context<InitContributionTree>,
         max_depth: u32,
         max_buffer_size: u32,
+        pda_authority: bool,
     ) -> Result<()> {
-        instructions::init_contribution_tree::handler(ctx, max_depth, max_buffer_size)
+        instructions::init_contribution_tree::handler(ctx, max_depth, max_buffer_size, pda_authority)
     }
 
     pub fn record_contribution(
@@ -42,6 +48,7 @@ pub mod gsd_hub {
         task_ref: [u8; 32],
         verification_score: u16,
         content_hash: [u8; 32],
+        details: Option<ContributionDetails>,
     ) -> Result<()> {
         instructions::record_contribution::handler(
             ctx,
@@ -49,6 +56,7 @@ pub mod gsd_hub {
             task_ref,
             verification_score,
             content_hash,
+            details,
         )
     }
 
@@ -70,6 +78,10 @@ pub mod gsd_hub {
         )
     }
 
+    pub fn migrate_developer_profile(ctx: Context<MigrateDeveloperProfile>) -> Result<()> {
+        instructions::migrate_developer_profile::handler(ctx)
+    }
+
     pub fn init_governance_config(
         ctx: Context<InitGovernanceConfig>,
         deposit_timelock: i64,
@@ -85,6 +97,7 @@ pub mod gsd_hub {
         voting_end: i64,
         quorum_type: QuorumType,
         content_hash: [u8; 32],
+        budget_amount: u64,
     ) -> Result<()> {
         instructions::create_round::handler(
             ctx,
@@ -93,17 +106,95 @@ pub mod gsd_hub {
             voting_end,
             quorum_type,
             content_hash,
+            budget_amount,
+        )
+    }
+
+    pub fn init_round_schedule(
+        ctx: Context<InitRoundSchedule>,
+        first_submission_start: i64,
+        interval: i64,
+        submission_length: i64,
+        voting_length: i64,
+        quorum_type: QuorumType,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::init_round_schedule::handler(
+            ctx,
+            first_submission_start,
+            interval,
+            submission_length,
+            voting_length,
+            quorum_type,
+            content_hash,
+        )
+    }
+
+    pub fn update_round_schedule(
+        ctx: Context<UpdateRoundSchedule>,
+        interval: i64,
+        submission_length: i64,
+        voting_length: i64,
+        quorum_type: QuorumType,
+        content_hash: [u8; 32],
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::update_round_schedule::handler(
+            ctx,
+            interval,
+            submission_length,
+            voting_length,
+            quorum_type,
+            content_hash,
+            is_active,
         )
     }
 
-    pub fn submit_idea(ctx: Context<SubmitIdea>, content_hash: [u8; 32]) -> Result<()> {
-        instructions::submit_idea::handler(ctx, content_hash)
+    pub fn create_scheduled_round(ctx: Context<CreateScheduledRound>) -> Result<()> {
+        instructions::create_scheduled_round::handler(ctx)
+    }
+
+    pub fn submit_idea(
+        ctx: Context<SubmitIdea>,
+        content_hash: [u8; 32],
+        requested_amount: u64,
+    ) -> Result<()> {
+        instructions::submit_idea::handler(ctx, content_hash, requested_amount)
     }
 
     pub fn transition_round(ctx: Context<TransitionRound>) -> Result<()> {
         instructions::transition_round::handler(ctx)
     }
 
+    pub fn extend_round(
+        ctx: Context<ExtendRound>,
+        new_submission_end: Option<i64>,
+        new_voting_end: Option<i64>,
+    ) -> Result<()> {
+        instructions::extend_round::handler(ctx, new_submission_end, new_voting_end)
+    }
+
+    pub fn reschedule_round(
+        ctx: Context<RescheduleRound>,
+        submission_start: i64,
+        submission_end: i64,
+        voting_end: i64,
+    ) -> Result<()> {
+        instructions::reschedule_round::handler(ctx, submission_start, submission_end, voting_end)
+    }
+
+    pub fn cancel_round(ctx: Context<CancelRound>) -> Result<()> {
+        instructions::cancel_round::handler(ctx)
+    }
+
+    pub fn set_round_paused(ctx: Context<SetRoundPaused>, paused: bool) -> Result<()> {
+        instructions::set_round_paused::handler(ctx, paused)
+    }
+
+    pub fn void_idea(ctx: Context<VoidIdea>) -> Result<()> {
+        instructions::void_idea::handler(ctx)
+    }
+
     pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
         instructions::deposit_tokens::handler(ctx, amount)
     }
@@ -112,8 +203,12 @@ pub mod gsd_hub {
         instructions::withdraw_tokens::handler(ctx, amount)
     }
 
-    pub fn cast_vote(ctx: Context<CastVote>, vote: VoteChoice) -> Result<()> {
-        instructions::cast_vote::handler(ctx, vote)
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        vote: VoteChoice,
+        reason_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::cast_vote::handler(ctx, vote, reason_hash)
     }
 
     pub fn relinquish_vote(ctx: Context<RelinquishVote>) -> Result<()> {
@@ -124,6 +219,357 @@ pub mod gsd_hub {
         instructions::veto_idea::handler(ctx)
     }
 
+    pub fn finalize_idea(ctx: Context<FinalizeIdea>) -> Result<()> {
+        instructions::finalize_idea::handler(ctx)
+    }
+
+    pub fn close_idea_early(ctx: Context<CloseIdeaEarly>) -> Result<()> {
+        instructions::close_idea_early::handler(ctx)
+    }
+
+    pub fn finalize_round_budget<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeRoundBudget<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_round_budget::handler(ctx)
+    }
+
+    pub fn fund_idea_escrow(ctx: Context<FundIdeaEscrow>) -> Result<()> {
+        instructions::fund_idea_escrow::handler(ctx)
+    }
+
+    pub fn add_idea_milestone(
+        ctx: Context<AddIdeaMilestone>,
+        task_ref: [u8; 32],
+        amount: u64,
+        min_score: u16,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::add_idea_milestone::handler(ctx, task_ref, amount, min_score, deadline)
+    }
+
+    pub fn approve_idea_milestone(ctx: Context<ApproveIdeaMilestone>) -> Result<()> {
+        instructions::approve_idea_milestone::handler(ctx)
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>) -> Result<()> {
+        instructions::release_milestone::handler(ctx)
+    }
+
+    pub fn reclaim_milestone(ctx: Context<ReclaimMilestone>) -> Result<()> {
+        instructions::reclaim_milestone::handler(ctx)
+    }
+
+    pub fn reclaim_unplanned_allocation(ctx: Context<ReclaimUnplannedAllocation>) -> Result<()> {
+        instructions::reclaim_unplanned_allocation::handler(ctx)
+    }
+
+    pub fn reclaim_round_budget(ctx: Context<ReclaimRoundBudget>) -> Result<()> {
+        instructions::reclaim_round_budget::handler(ctx)
+    }
+
+    pub fn enable_quadratic_funding(
+        ctx: Context<EnableQuadraticFunding>,
+        require_gateway: bool,
+    ) -> Result<()> {
+        instructions::enable_quadratic_funding::handler(ctx, require_gateway)
+    }
+
+    pub fn contribute_to_idea(ctx: Context<ContributeToIdea>, amount: u64) -> Result<()> {
+        instructions::contribute_to_idea::handler(ctx, amount)
+    }
+
+    pub fn finalize_qf_match(ctx: Context<FinalizeQfMatch>) -> Result<()> {
+        instructions::finalize_qf_match::handler(ctx)
+    }
+
+    pub fn claim_idea_payout(ctx: Context<ClaimIdeaPayout>) -> Result<()> {
+        instructions::claim_idea_payout::handler(ctx)
+    }
+
+    pub fn refund_qf_contribution(ctx: Context<RefundQfContribution>) -> Result<()> {
+        instructions::refund_qf_contribution::handler(ctx)
+    }
+
+    pub fn configure_conviction(
+        ctx: Context<ConfigureConviction>,
+        half_life: i64,
+        rho_bps: u16,
+        max_share_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_conviction::handler(ctx, half_life, rho_bps, max_share_bps)
+    }
+
+    pub fn enable_conviction_voting(ctx: Context<EnableConvictionVoting>) -> Result<()> {
+        instructions::enable_conviction_voting::handler(ctx)
+    }
+
+    pub fn set_conviction_stake(ctx: Context<SetConvictionStake>, amount: u64) -> Result<()> {
+        instructions::set_conviction_stake::handler(ctx, amount)
+    }
+
+    pub fn update_conviction(ctx: Context<UpdateConviction>) -> Result<()> {
+        instructions::update_conviction::handler(ctx)
+    }
+
+    pub fn close_conviction_round(ctx: Context<CloseConvictionRound>) -> Result<()> {
+        instructions::close_conviction_round::handler(ctx)
+    }
+
+    pub fn init_round_vote_tree(
+        ctx: Context<InitRoundVoteTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::init_round_vote_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    pub fn cast_vote_compressed(
+        ctx: Context<CastVoteCompressed>,
+        vote: VoteChoice,
+        reason_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::cast_vote_compressed::handler(ctx, vote, reason_hash)
+    }
+
+    pub fn relinquish_compressed_votes(ctx: Context<RelinquishCompressedVotes>) -> Result<()> {
+        instructions::relinquish_compressed_votes::handler(ctx)
+    }
+
+    pub fn init_archive_tree(
+        ctx: Context<InitArchiveTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::init_archive_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    pub fn archive_idea(ctx: Context<ArchiveIdea>) -> Result<()> {
+        instructions::archive_idea::handler(ctx)
+    }
+
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        instructions::close_vote_record::handler(ctx)
+    }
+
+    pub fn close_vote_nullifier(ctx: Context<CloseVoteNullifier>) -> Result<()> {
+        instructions::close_vote_nullifier::handler(ctx)
+    }
+
+    pub fn archive_round(ctx: Context<ArchiveRound>) -> Result<()> {
+        instructions::archive_round::handler(ctx)
+    }
+
+    pub fn register_delegate(
+        ctx: Context<RegisterDelegate>,
+        statement_hash: [u8; 32],
+        topics: u32,
+    ) -> Result<()> {
+        instructions::register_delegate::handler(ctx, statement_hash, topics)
+    }
+
+    pub fn update_delegate_profile(
+        ctx: Context<UpdateDelegateProfile>,
+        statement_hash: [u8; 32],
+        topics: u32,
+        accepting_delegations: bool,
+    ) -> Result<()> {
+        instructions::update_delegate_profile::handler(
+            ctx,
+            statement_hash,
+            topics,
+            accepting_delegations,
+        )
+    }
+
+    pub fn tally_delegate_round(ctx: Context<TallyDelegateRound>) -> Result<()> {
+        instructions::tally_delegate_round::handler(ctx)
+    }
+
+    pub fn init_pause_config(ctx: Context<InitPauseConfig>, guardian: Pubkey) -> Result<()> {
+        instructions::init_pause_config::handler(ctx, guardian)
+    }
+
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused: u8) -> Result<()> {
+        instructions::set_pause_flags::handler(ctx, paused)
+    }
+
+    pub fn emergency_withdraw<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EmergencyWithdraw<'info>>,
+    ) -> Result<()> {
+        instructions::emergency_withdraw::handler(ctx)
+    }
+
+    pub fn strip_compressed_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StripCompressedVote<'info>>,
+        receipt: VoteReceiptLeaf,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::strip_compressed_vote::handler(ctx, receipt, root, leaf_index)
+    }
+
+    pub fn propose_governance_admin(
+        ctx: Context<ProposeGovernanceAdmin>,
+        new_admin: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::propose_governance_admin::handler(ctx, new_admin, expires_at)
+    }
+
+    pub fn accept_governance_admin(ctx: Context<AcceptGovernanceAdmin>) -> Result<()> {
+        instructions::accept_governance_admin::handler(ctx)
+    }
+
+    pub fn propose_revenue_admin(
+        ctx: Context<ProposeRevenueAdmin>,
+        new_admin: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::propose_revenue_admin::handler(ctx, new_admin, expires_at)
+    }
+
+    pub fn accept_revenue_admin(ctx: Context<AcceptRevenueAdmin>) -> Result<()> {
+        instructions::accept_revenue_admin::handler(ctx)
+    }
+
+    pub fn propose_verification_admin(
+        ctx: Context<ProposeVerificationAdmin>,
+        new_admin: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::propose_verification_admin::handler(ctx, new_admin, expires_at)
+    }
+
+    pub fn accept_verification_admin(ctx: Context<AcceptVerificationAdmin>) -> Result<()> {
+        instructions::accept_verification_admin::handler(ctx)
+    }
+
+    pub fn propose_tree_authority(
+        ctx: Context<ProposeTreeAuthority>,
+        new_authority: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::propose_tree_authority::handler(ctx, new_authority, expires_at)
+    }
+
+    pub fn accept_tree_authority(ctx: Context<AcceptTreeAuthority>) -> Result<()> {
+        instructions::accept_tree_authority::handler(ctx)
+    }
+
+    pub fn init_role_registry(ctx: Context<InitRoleRegistry>, admin: Pubkey) -> Result<()> {
+        instructions::init_role_registry::handler(ctx, admin)
+    }
+
+    pub fn set_operator_roles(
+        ctx: Context<SetOperatorRoles>,
+        realm: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::set_operator_roles::handler(ctx, realm, roles)
+    }
+
+    pub fn propose_role_admin(
+        ctx: Context<ProposeRoleAdmin>,
+        new_admin: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::propose_role_admin::handler(ctx, new_admin, expires_at)
+    }
+
+    pub fn accept_role_admin(ctx: Context<AcceptRoleAdmin>) -> Result<()> {
+        instructions::accept_role_admin::handler(ctx)
+    }
+
+    pub fn set_score_limits(
+        ctx: Context<SetScoreLimits>,
+        max_score_increase: u64,
+        max_tasks_increase: u32,
+        decay_realm: Pubkey,
+    ) -> Result<()> {
+        instructions::set_score_limits::handler(
+            ctx,
+            max_score_increase,
+            max_tasks_increase,
+            decay_realm,
+        )
+    }
+
+    pub fn verify_contribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyContribution<'info>>,
+        leaf: VersionedContributionLeaf,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::verify_contribution::handler(ctx, leaf, root, leaf_index)
+    }
+
+    pub fn apply_contribution_proof<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyContributionProof<'info>>,
+        leaf: ContributionLeaf,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::apply_contribution_proof::handler(ctx, leaf, root, leaf_index)
+    }
+
+    pub fn decay_contribution_score(ctx: Context<DecayContributionScore>) -> Result<()> {
+        instructions::decay_contribution_score::handler(ctx)
+    }
+
+    pub fn record_contributions_batch(
+        ctx: Context<RecordContributionsBatch>,
+        entries: Vec<ContributionInput>,
+    ) -> Result<()> {
+        instructions::record_contributions_batch::handler(ctx, entries)
+    }
+
+    pub fn init_contribution_tree_registry(
+        ctx: Context<InitContributionTreeRegistry>,
+        registry_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::init_contribution_tree_registry::handler(ctx, registry_authority)
+    }
+
+    pub fn rollover_contribution_tree(ctx: Context<RolloverContributionTree>) -> Result<()> {
+        instructions::rollover_contribution_tree::handler(ctx)
+    }
+
+    pub fn migrate_tree_config(ctx: Context<MigrateTreeConfig>) -> Result<()> {
+        instructions::migrate_tree_config::handler(ctx)
+    }
+
+    pub fn set_tree_writers(ctx: Context<SetTreeWriters>, writers: Vec<Pubkey>) -> Result<()> {
+        instructions::set_tree_writers::handler(ctx, writers)
+    }
+
+    pub fn amend_contribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AmendContribution<'info>>,
+        previous: VersionedContributionLeaf,
+        amended: ContributionInput,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::amend_contribution::handler(ctx, previous, amended, root, leaf_index)
+    }
+
+    pub fn revoke_contribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevokeContribution<'info>>,
+        previous: VersionedContributionLeaf,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::revoke_contribution::handler(ctx, previous, root, leaf_index)
+    }
+
+    pub fn apply_contribution_proof_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyContributionProofV2<'info>>,
+        leaf: ContributionLeafV2,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        instructions::apply_contribution_proof_v2::handler(ctx, leaf, root, leaf_index)
+    }
+
     pub fn init_revenue_config(
         ctx: Context<InitRevenueConfig>,
         treasury_address: Pubkey,
@@ -181,6 +627,7 @@ pub mod gsd_hub {
         min_reviewers: u8,
         consensus_threshold_bps: u16,
         review_timeout_days: u8,
+        min_milestone_score: u16,
     ) -> Result<()> {
         instructions::init_verification_config::handler(
             ctx,
@@ -193,6 +640,7 @@ pub mod gsd_hub {
             min_reviewers,
             consensus_threshold_bps,
             review_timeout_days,
+            min_milestone_score,
         )
     }
 
@@ -259,12 +707,14 @@ pub mod gsd_hub {
         quadratic_voting_enabled: bool,
         civic_gatekeeper_network: Pubkey,
         decay_half_life_days: u16,
+        require_delegate_profile: bool,
     ) -> Result<()> {
         instructions::update_governance_config::handler(
             ctx,
             quadratic_voting_enabled,
             civic_gatekeeper_network,
             decay_half_life_days,
+            require_delegate_profile,
         )
     }
 }
@@ -0,0 +1,197 @@
+//! Fixed-point helpers shared by time-decay and scoring calculations.
+
+/// Integer square root using Newton's method for u64 values.
+/// Used for quadratic voting weight calculation: weight = floor(sqrt(tokens)).
+pub fn isqrt(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+    let mut x = n;
+    // ceil(n / 2) without overflowing at u64::MAX
+    let mut y = n / 2 + n % 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Fixed-point scale for decay factors: 1.0 == DECAY_SCALE.
+pub const DECAY_SCALE: u64 = 1_000_000_000_000;
+
+/// 2^(-1/2^i) scaled by DECAY_SCALE, for i = 1..=30.
+const HALVING_ROOTS: [u64; 30] = [
+    707_106_781_187,
+    840_896_415_254,
+    917_004_043_205,
+    957_603_280_699,
+    978_572_062_088,
+    989_228_013_194,
+    994_599_423_484,
+    997_296_056_085,
+    998_647_112_891,
+    999_323_327_503,
+    999_661_606_496,
+    999_830_788_932,
+    999_915_390_887,
+    999_957_694_548,
+    999_978_847_050,
+    999_989_423_469,
+    999_994_711_721,
+    999_997_355_857,
+    999_998_677_928,
+    999_999_338_964,
+    999_999_669_482,
+    999_999_834_741,
+    999_999_917_370,
+    999_999_958_685,
+    999_999_979_343,
+    999_999_989_671,
+    999_999_994_836,
+    999_999_997_418,
+    999_999_998_709,
+    999_999_999_354,
+];
+
+/// Fixed-point 2^(-elapsed / half_life), scaled by DECAY_SCALE.
+///
+/// Whole half-lives are applied as right shifts; the fractional remainder is
+/// expanded bit by bit against the HALVING_ROOTS table. A zero half-life
+/// means no decay.
+pub fn decay_factor(elapsed: u64, half_life: u64) -> u64 {
+    if half_life == 0 {
+        return DECAY_SCALE;
+    }
+
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+
+    let mut factor = (DECAY_SCALE >> halvings) as u128;
+    let mut remainder = (elapsed % half_life) as u128;
+    let half_life = half_life as u128;
+
+    for root in HALVING_ROOTS.iter() {
+        if remainder == 0 || factor == 0 {
+            break;
+        }
+        remainder *= 2;
+        if remainder >= half_life {
+            remainder -= half_life;
+            factor = factor * (*root as u128) / DECAY_SCALE as u128;
+        }
+    }
+
+    factor as u64
+}
+
+/// Apply a DECAY_SCALE factor to a value: value * factor / DECAY_SCALE.
+pub fn apply_decay(value: u64, factor: u64) -> u64 {
+    ((value as u128) * (factor as u128) / DECAY_SCALE as u128) as u64
+}
+
+/// Fixed-point precision of the contribution score formula.
+pub const SCORE_PRECISION: u64 = 1_000_000;
+
+/// On-chain port of `calculateContributionScore` (packages/utils/src/score.ts):
+/// isqrt(tasks * P) * total_verification_score * isqrt(days * P) / P.
+///
+/// Any zero input yields 0. Returns None if the score does not fit in u64.
+pub fn contribution_score(
+    tasks_completed: u32,
+    total_verification_score: u64,
+    time_active_days: u32,
+) -> Option<u64> {
+    if tasks_completed == 0 || total_verification_score == 0 || time_active_days == 0 {
+        return Some(0);
+    }
+
+    let task_factor = isqrt(tasks_completed as u64 * SCORE_PRECISION) as u128;
+    let time_factor = isqrt(time_active_days as u64 * SCORE_PRECISION) as u128;
+    let score = task_factor
+        .checked_mul(total_verification_score as u128)?
+        .checked_mul(time_factor)?
+        / SCORE_PRECISION as u128;
+
+    u64::try_from(score).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_floors_the_square_root() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(2), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(1_000_000), 1_000);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn halving_roots_square_to_the_previous_root() {
+        // root[0]^2 == 1/2 and root[i]^2 == root[i - 1]; squaring a rounded
+        // entry doubles its half-unit error, plus one unit for the floor
+        let square = |r: u64| (r as u128 * r as u128 / DECAY_SCALE as u128) as u64;
+        assert!(square(HALVING_ROOTS[0]).abs_diff(DECAY_SCALE / 2) <= 2);
+        for i in 1..HALVING_ROOTS.len() {
+            assert!(
+                square(HALVING_ROOTS[i]).abs_diff(HALVING_ROOTS[i - 1]) <= 2,
+                "HALVING_ROOTS[{}]",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn decay_factor_halves_every_half_life() {
+        let half_life = 30 * 86_400;
+        assert_eq!(decay_factor(12_345, 0), DECAY_SCALE);
+        assert_eq!(decay_factor(0, half_life), DECAY_SCALE);
+        assert_eq!(decay_factor(half_life, half_life), DECAY_SCALE / 2);
+        assert_eq!(decay_factor(2 * half_life, half_life), DECAY_SCALE / 4);
+        assert_eq!(decay_factor(64 * half_life, half_life), 0);
+        assert_eq!(decay_factor(half_life / 2, half_life), HALVING_ROOTS[0]);
+        // 1.5 half-lives: 2^-1.5
+        assert!(decay_factor(3 * half_life / 2, half_life).abs_diff(353_553_390_593) <= 1);
+    }
+
+    #[test]
+    fn decay_factor_is_monotone() {
+        let half_life = 7 * 86_400;
+        let mut previous = DECAY_SCALE;
+        for elapsed in (0..20 * half_life).step_by(3_613) {
+            let factor = decay_factor(elapsed, half_life);
+            assert!(factor <= previous, "elapsed {}", elapsed);
+            previous = factor;
+        }
+    }
+
+    #[test]
+    fn apply_decay_scales_by_the_factor() {
+        assert_eq!(apply_decay(8_500, DECAY_SCALE), 8_500);
+        assert_eq!(apply_decay(8_500, DECAY_SCALE / 2), 4_250);
+        assert_eq!(apply_decay(u64::MAX, DECAY_SCALE), u64::MAX);
+    }
+
+    #[test]
+    fn contribution_score_matches_the_off_chain_formula() {
+        assert_eq!(contribution_score(0, 8_500, 1), Some(0));
+        assert_eq!(contribution_score(1, 0, 1), Some(0));
+        assert_eq!(contribution_score(1, 8_500, 0), Some(0));
+        // isqrt(1e6) * 8500 * isqrt(1e6) / 1e6
+        assert_eq!(contribution_score(1, 8_500, 1), Some(8_500));
+        // isqrt(4e6) * 20000 * isqrt(9e6) / 1e6 = 2000 * 20000 * 3000 / 1e6
+        assert_eq!(contribution_score(4, 20_000, 9), Some(120_000));
+        // isqrt(2e6) = 1414
+        assert_eq!(contribution_score(2, 10_000, 1), Some(14_140));
+        assert_eq!(contribution_score(u32::MAX, u64::MAX, u32::MAX), None);
+    }
+}
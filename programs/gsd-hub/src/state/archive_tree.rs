@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Per-realm configuration for the round archive Merkle tree.
+///
+/// PDA seeds: `[b"archive_tree", realm.as_ref()]`
+///
+/// The PDA is the tree authority, so the permissionless archival cranks can
+/// append idea summaries without an admin signature.
+#[account]
+#[derive(InitSpace)]
+pub struct ArchiveTreeConfig {
+    /// Address of the concurrent Merkle tree account (32 bytes)
+    pub merkle_tree: Pubkey,
+    /// Number of idea summaries appended to the tree (8 bytes)
+    pub archived_ideas: u64,
+    /// Number of rounds fully archived and closed (8 bytes)
+    pub archived_rounds: u64,
+    /// PDA bump seed (1 byte)
+    pub bump: u8,
+    /// Unix timestamp of tree creation (8 bytes)
+    pub created_at: i64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// Total: 8 (discriminator) + 32 + 8 + 8 + 1 + 8 + 32 = 97 bytes
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+/// An approved idea competing for the round budget.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct BudgetRankEntry {
+    /// Index of the idea within its round (4 bytes)
+    pub idea_index: u32,
+    /// Yes minus No weight the idea is ranked by (16 bytes)
+    pub net_yes_weight: i128,
+    /// Amount of the budget mint the idea requested (8 bytes)
+    pub requested_amount: u64,
+    /// Whether the allocation has been written to the Idea account (1 byte)
+    pub allocated: bool,
+}
+// Total: 4 + 16 + 8 + 1 = 29 bytes
+
+/// Work-in-progress ranking that lets `finalize_round_budget` run over
+/// several transactions instead of needing every Idea at once.
+///
+/// Ideas are first ranked in index order, then the funded prefix is
+/// allocated back to the Idea accounts in any order. The account is closed
+/// to `payer` once every funded idea has its allocation.
+#[account]
+#[derive(InitSpace)]
+pub struct BudgetRanking {
+    /// IdeaRound being finalized (32 bytes)
+    pub round: Pubkey,
+    /// Cranker that paid rent for this account and receives it back (32 bytes)
+    pub payer: Pubkey,
+    /// Next idea index to rank; ranking is complete at the round's idea_count (4 bytes)
+    pub next_idea_index: u32,
+    /// Number of leading entries funded, set once ranking completes (4 bytes)
+    pub funded_count: u32,
+    /// Number of funded entries whose allocation has been written (4 bytes)
+    pub allocated_count: u32,
+    /// Whether every idea has been ranked and funded_count is final (1 byte)
+    pub ranking_complete: bool,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Approved ideas with a request, highest net Yes weight first, ties by lower index (4 + 29 * 300 bytes)
+    #[max_len(300)]
+    pub entries: Vec<BudgetRankEntry>,
+}
+// PDA seeds: ["budget_ranking", idea_round.key()]
+// Total: 8 (disc) + 32 + 32 + 4 + 4 + 4 + 1 + 1 + (4 + 29 * 300) = 8790 bytes
+
+impl BudgetRanking {
+    pub const MAX_ENTRIES: usize = 300;
+
+    /// Insert `entry` behind every entry ranked at least as high. Ideas are
+    /// ranked in index order, so equal weights keep the lower index first.
+    pub fn insert(&mut self, entry: BudgetRankEntry) {
+        let position = self
+            .entries
+            .partition_point(|e| e.net_yes_weight >= entry.net_yes_weight);
+        self.entries.insert(position, entry);
+    }
+
+    /// Fix the funded prefix: full requests in rank order until the next one
+    /// no longer fits `budget`. Returns the total allocated.
+    pub fn settle_funded_prefix(&mut self, budget: u64) -> u64 {
+        let mut remaining = budget;
+        let mut funded = 0;
+        for entry in self.entries.iter() {
+            if entry.requested_amount > remaining {
+                break;
+            }
+            remaining -= entry.requested_amount;
+            funded += 1;
+        }
+        self.funded_count = funded;
+        self.ranking_complete = true;
+        budget - remaining
+    }
+
+    /// Rank position of the idea at `idea_index` within the funded prefix.
+    pub fn funded_position(&self, idea_index: u32) -> Option<usize> {
+        self.entries[..self.funded_count as usize]
+            .iter()
+            .position(|e| e.idea_index == idea_index)
+    }
+}
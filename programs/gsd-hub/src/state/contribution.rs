@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use sha2::{Digest, Sha256};
 
+use crate::errors::GsdHubError;
+
 /// Leaf data for the contribution Merkle tree.
 ///
 /// This is NOT a PDA account -- it is serialized, hashed, and appended
@@ -22,10 +24,322 @@ pub struct ContributionLeaf {
 }
 // Total serialized: 32 + 32 + 2 + 8 + 32 = 106 bytes
 
+/// Kind of work a v2 contribution leaf records (1 byte, Borsh enum tag).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContributionKind {
+    Code,
+    Review,
+    Docs,
+    Design,
+}
+
+/// One author of a co-authored contribution and their share of the credit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct AuthorShare {
+    /// Author wallet (32 bytes)
+    pub author: Pubkey,
+    /// Share of the contribution in basis points (2 bytes)
+    pub share_bps: u16,
+}
+// Total serialized: 32 + 2 = 34 bytes
+
+/// Optional `record_contribution` argument; when present the program
+/// records ContributionLeafV2 leaves instead of a v1 ContributionLeaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ContributionDetails {
+    /// Kind of work (1 byte)
+    pub kind: ContributionKind,
+    /// SHA-256 hash of the project or repository identifier (32 bytes)
+    pub project_id: [u8; 32],
+    /// Difficulty multiplier in basis points, 10000 = 1.0x (2 bytes)
+    pub difficulty_weight: u16,
+    /// Every author, lead `developer` first, with shares summing to 10000;
+    /// empty = `developer` alone (4 + 34 * n bytes)
+    pub authors: Vec<AuthorShare>,
+}
+
+impl ContributionDetails {
+    /// Most authors one contribution may be split between.
+    pub const MAX_AUTHORS: usize = 4;
+
+    /// Validated author split: `developer` alone with the full share when
+    /// `authors` is empty, otherwise `authors` itself, which must list
+    /// `developer` first, name each author once, give each a non-zero share
+    /// and sum to 10000 bps.
+    pub fn author_shares(&self, developer: Pubkey) -> Result<Vec<AuthorShare>> {
+        if self.authors.is_empty() {
+            return Ok(vec![AuthorShare {
+                author: developer,
+                share_bps: 10_000,
+            }]);
+        }
+
+        require!(
+            self.authors.len() <= Self::MAX_AUTHORS && self.authors[0].author == developer,
+            GsdHubError::InvalidAuthorShares
+        );
+        let mut total: u32 = 0;
+        for (i, share) in self.authors.iter().enumerate() {
+            require!(
+                share.share_bps > 0
+                    && self.authors[..i].iter().all(|a| a.author != share.author),
+                GsdHubError::InvalidAuthorShares
+            );
+            total += share.share_bps as u32;
+        }
+        require!(total == 10_000, GsdHubError::InvalidAuthorShares);
+
+        Ok(self.authors.clone())
+    }
+}
+
+/// Versioned contribution leaf. v1 leaves (ContributionLeaf) carry no
+/// version tag and are always exactly 106 bytes; v2 payloads start with
+/// `version = 2` and are 145 bytes, so indexers tell them apart by length
+/// and leading byte. The hash covers the version byte, so a v2 leaf never
+/// collides with a v1 leaf.
+///
+/// A co-authored contribution is recorded as one leaf per author, each
+/// carrying that author's `share_bps`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ContributionLeafV2 {
+    /// Encoding version, always `ContributionLeafV2::VERSION` (1 byte)
+    pub version: u8,
+    /// Wallet that made the contribution (32 bytes)
+    pub developer: Pubkey,
+    /// SHA-256 hash of the task identifier (32 bytes)
+    pub task_ref: [u8; 32],
+    /// Verification score: 0-10000 representing 0.00%-100.00% (2 bytes)
+    pub verification_score: u16,
+    /// Unix timestamp of when the contribution was recorded (8 bytes)
+    pub timestamp: i64,
+    /// SHA-256 hash of off-chain contribution content (32 bytes)
+    pub content_hash: [u8; 32],
+    /// Kind of work (1 byte)
+    pub kind: ContributionKind,
+    /// SHA-256 hash of the project or repository identifier (32 bytes)
+    pub project_id: [u8; 32],
+    /// Difficulty multiplier in basis points, 10000 = 1.0x (2 bytes)
+    pub difficulty_weight: u16,
+    /// Number of co-authors besides `developer`, 0 = solo (1 byte)
+    pub co_author_count: u8,
+    /// `developer`'s share of the contribution in basis points (2 bytes)
+    pub share_bps: u16,
+}
+// Total serialized: 1 + 32 + 32 + 2 + 8 + 32 + 1 + 32 + 2 + 1 + 2 = 145 bytes
+
+impl ContributionLeafV2 {
+    /// Leading version byte of v2 payloads.
+    pub const VERSION: u8 = 2;
+
+    /// Serialized size of the leaf data.
+    pub const SIZE: usize = 145;
+
+    /// The leaf crediting `share` of a contribution: `leaf`'s task, score
+    /// and content with the details of the whole contribution.
+    pub fn for_author(
+        leaf: &ContributionLeaf,
+        details: &ContributionDetails,
+        share: &AuthorShare,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            developer: share.author,
+            task_ref: leaf.task_ref,
+            verification_score: leaf.verification_score,
+            timestamp: leaf.timestamp,
+            content_hash: leaf.content_hash,
+            kind: details.kind,
+            project_id: details.project_id,
+            difficulty_weight: details.difficulty_weight,
+            co_author_count: details.authors.len().saturating_sub(1) as u8,
+            share_bps: share.share_bps,
+        }
+    }
+
+    /// `verification_score` scaled to this author's share; what
+    /// `apply_contribution_proof_v2` credits to the author's profile.
+    pub fn credited_score(&self) -> u64 {
+        self.verification_score as u64 * self.share_bps as u64 / 10_000
+    }
+
+    /// Tombstone replacing this leaf on revocation: every field kept except
+    /// a zero score and `ContributionLeaf::REVOKED_CONTENT_HASH`.
+    pub fn tombstone(&self) -> Self {
+        Self {
+            verification_score: 0,
+            content_hash: ContributionLeaf::REVOKED_CONTENT_HASH,
+            ..self.clone()
+        }
+    }
+
+    /// Whether this leaf is a revocation tombstone.
+    pub fn is_revoked(&self) -> bool {
+        self.verification_score == 0
+            && self.content_hash == ContributionLeaf::REVOKED_CONTENT_HASH
+    }
+
+    /// SHA-256 of the 145-byte serialization, version byte included.
+    pub fn to_leaf_hash(&self) -> [u8; 32] {
+        let data = AnchorSerialize::try_to_vec(self).expect("ContributionLeafV2 serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&data);
+        hasher.finalize().into()
+    }
+}
+
+/// A stored contribution leaf of either encoding, for instructions that act
+/// on whatever a tree holds. Only the inner leaf is hashed; the variant tag
+/// travels in instruction data and noop payloads.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum VersionedContributionLeaf {
+    V1 { leaf: ContributionLeaf },
+    V2 { leaf: ContributionLeafV2 },
+}
+
+impl VersionedContributionLeaf {
+    pub fn developer(&self) -> Pubkey {
+        match self {
+            Self::V1 { leaf } => leaf.developer,
+            Self::V2 { leaf } => leaf.developer,
+        }
+    }
+
+    pub fn task_ref(&self) -> [u8; 32] {
+        match self {
+            Self::V1 { leaf } => leaf.task_ref,
+            Self::V2 { leaf } => leaf.task_ref,
+        }
+    }
+
+    pub fn verification_score(&self) -> u16 {
+        match self {
+            Self::V1 { leaf } => leaf.verification_score,
+            Self::V2 { leaf } => leaf.verification_score,
+        }
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            Self::V1 { leaf } => leaf.timestamp,
+            Self::V2 { leaf } => leaf.timestamp,
+        }
+    }
+
+    /// Score the apply-proof instruction of this version credits.
+    pub fn credited_score(&self) -> u64 {
+        match self {
+            Self::V1 { leaf } => leaf.verification_score as u64,
+            Self::V2 { leaf } => leaf.credited_score(),
+        }
+    }
+
+    /// This leaf with `input`'s score and content hash; every other field,
+    /// v2 details included, is kept.
+    pub fn amended(&self, input: &ContributionInput) -> Self {
+        match self {
+            Self::V1 { leaf } => Self::V1 {
+                leaf: ContributionLeaf {
+                    verification_score: input.verification_score,
+                    content_hash: input.content_hash,
+                    ..leaf.clone()
+                },
+            },
+            Self::V2 { leaf } => Self::V2 {
+                leaf: ContributionLeafV2 {
+                    verification_score: input.verification_score,
+                    content_hash: input.content_hash,
+                    ..leaf.clone()
+                },
+            },
+        }
+    }
+
+    /// Revocation tombstone of the same version.
+    pub fn tombstone(&self) -> Self {
+        match self {
+            Self::V1 { leaf } => Self::V1 { leaf: leaf.tombstone() },
+            Self::V2 { leaf } => Self::V2 { leaf: leaf.tombstone() },
+        }
+    }
+
+    /// Whether this leaf is a revocation tombstone.
+    pub fn is_revoked(&self) -> bool {
+        match self {
+            Self::V1 { leaf } => leaf.is_revoked(),
+            Self::V2 { leaf } => leaf.is_revoked(),
+        }
+    }
+
+    /// Hash stored in the tree: that of the inner leaf, without the tag.
+    pub fn to_leaf_hash(&self) -> [u8; 32] {
+        match self {
+            Self::V1 { leaf } => leaf.to_leaf_hash(),
+            Self::V2 { leaf } => leaf.to_leaf_hash(),
+        }
+    }
+}
+
+/// One entry of a `record_contributions_batch` call; the program stamps the
+/// timestamp when turning it into a ContributionLeaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ContributionInput {
+    /// Wallet that made the contribution (32 bytes)
+    pub developer: Pubkey,
+    /// SHA-256 hash of the task identifier (32 bytes)
+    pub task_ref: [u8; 32],
+    /// Verification score: 0-10000 representing 0.00%-100.00% (2 bytes)
+    pub verification_score: u16,
+    /// SHA-256 hash of off-chain contribution content (32 bytes)
+    pub content_hash: [u8; 32],
+}
+// Total serialized: 32 + 32 + 2 + 32 = 98 bytes
+
+/// Noop payload emitted when `amend_contribution` or `revoke_contribution`
+/// replaces a leaf, so indexers can patch their copy of the tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ContributionChange {
+    /// Tree holding the replaced leaf (32 bytes)
+    pub merkle_tree: Pubkey,
+    /// Index of the replaced leaf (4 bytes)
+    pub leaf_index: u32,
+    /// Hash of the leaf that was replaced (32 bytes)
+    pub previous_leaf_hash: [u8; 32],
+    /// True when `leaf` is a revocation tombstone (1 byte)
+    pub revoked: bool,
+    /// Leaf now stored at `leaf_index` (1 + 106 or 145 bytes)
+    pub leaf: VersionedContributionLeaf,
+}
+// Total serialized: 32 + 4 + 32 + 1 + 1 + 106 = 176 bytes (v1), 215 bytes (v2)
+
 impl ContributionLeaf {
     /// Serialized size of the leaf data.
     pub const SIZE: usize = 106;
 
+    /// Content hash marking a revocation tombstone (no SHA-256 output in practice).
+    pub const REVOKED_CONTENT_HASH: [u8; 32] = [0u8; 32];
+
+    /// Most leaves one `record_contributions_batch` call may append. Each
+    /// append CPI rehashes a full tree path (~20k CU at depth 14), so eight
+    /// stays well inside a 200k default compute budget; eight 98-byte
+    /// inputs also still fit a single 1232-byte transaction.
+    pub const MAX_BATCH: usize = 8;
+
+    /// Tombstone replacing this leaf on revocation: same developer, task and
+    /// timestamp, zero score and `REVOKED_CONTENT_HASH`.
+    pub fn tombstone(&self) -> Self {
+        Self {
+            verification_score: 0,
+            content_hash: Self::REVOKED_CONTENT_HASH,
+            ..self.clone()
+        }
+    }
+
+    /// Whether this leaf is a revocation tombstone.
+    pub fn is_revoked(&self) -> bool {
+        self.verification_score == 0 && self.content_hash == Self::REVOKED_CONTENT_HASH
+    }
+
     /// Serialize the leaf and hash it for Merkle tree insertion.
     ///
     /// Returns a 32-byte SHA-256 hash suitable for use as a concurrent Merkle tree leaf.
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Proof-of-contribution record created by `verify_contribution`.
+///
+/// Other instructions and programs can read this PDA instead of carrying a
+/// Merkle proof: its existence means the leaf was proven against the
+/// contribution tree when `attested_at` was recorded. `amend_contribution`
+/// and `revoke_contribution` close it when they rewrite the leaf.
+#[account]
+#[derive(InitSpace)]
+pub struct ContributionAttestation {
+    /// Wallet that made the contribution (32 bytes)
+    pub developer: Pubkey,
+    /// SHA-256 hash of the task identifier (32 bytes)
+    pub task_ref: [u8; 32],
+    /// Contribution tree holding the leaf (32 bytes)
+    pub merkle_tree: Pubkey,
+    /// Index of the leaf in the tree (4 bytes)
+    pub leaf_index: u32,
+    /// Hash of the proven ContributionLeaf (32 bytes)
+    pub leaf_hash: [u8; 32],
+    /// Verification score carried by the leaf, 0-10000 (2 bytes)
+    pub verification_score: u16,
+    /// Timestamp stored in the leaf when it was recorded (8 bytes)
+    pub contributed_at: i64,
+    /// Unix timestamp of the proof verification (8 bytes)
+    pub attested_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Wallet that paid the rent, refunded when the leaf is rewritten (32 bytes)
+    pub payer: Pubkey,
+}
+// PDA seeds: ["contribution_attestation", merkle_tree, developer, task_ref]
+// Total: 8 (disc) + 32 + 32 + 32 + 4 + 32 + 2 + 8 + 8 + 1 + 32 = 191 bytes
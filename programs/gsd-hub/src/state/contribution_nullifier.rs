@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Per-developer guard that a proven contribution has been counted.
+///
+/// Created by `apply_contribution_proof`; because the PDA can only be
+/// initialized once, the same task can never add to a developer's
+/// verification total twice.
+#[account]
+#[derive(InitSpace)]
+pub struct ContributionNullifier {
+    /// Developer credited for the contribution (32 bytes)
+    pub developer: Pubkey,
+    /// SHA-256 hash of the task identifier (32 bytes)
+    pub task_ref: [u8; 32],
+    /// Hash of the ContributionLeaf that was counted (32 bytes)
+    pub leaf_hash: [u8; 32],
+    /// Unix timestamp the leaf was counted (8 bytes)
+    pub counted_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+}
+// PDA seeds: ["contribution_nullifier", developer, task_ref]
+// Total: 8 (disc) + 32 + 32 + 32 + 8 + 1 = 113 bytes
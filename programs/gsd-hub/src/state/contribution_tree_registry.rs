@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// A contribution tree listed in the registry, in rollover order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct RegisteredTree {
+    /// Address of the concurrent Merkle tree account (32 bytes)
+    pub merkle_tree: Pubkey,
+    /// Maximum number of leaves, `2^max_depth` (8 bytes)
+    pub capacity: u64,
+    /// Unix timestamp the tree became the active tree (8 bytes)
+    pub activated_at: i64,
+}
+// Total: 32 + 8 + 8 = 48 bytes
+
+/// Ordered list of every contribution tree, with exactly one active tree
+/// that `record_contribution` appends to.
+///
+/// PDA seeds: `[b"contribution_tree_registry"]`
+///
+/// Indexers enumerate `trees` to discover every tree that has ever held
+/// contribution leaves.
+#[account]
+#[derive(InitSpace)]
+pub struct ContributionTreeRegistry {
+    /// Key allowed to roll over to a new tree (32 bytes)
+    pub authority: Pubkey,
+    /// Merkle tree currently receiving contributions, default until the first rollover (32 bytes)
+    pub active_tree: Pubkey,
+    /// PDA bump seed (1 byte)
+    pub bump: u8,
+    /// Unix timestamp of registry creation (8 bytes)
+    pub created_at: i64,
+    /// Every registered tree, oldest first (4 + 48 * 32 bytes)
+    #[max_len(32)]
+    pub trees: Vec<RegisteredTree>,
+}
+// Total: 8 (discriminator) + 32 + 32 + 1 + 8 + (4 + 48 * 32) = 1621 bytes
+
+impl ContributionTreeRegistry {
+    pub const MAX_TREES: usize = 32;
+
+    /// Whether `merkle_tree` has ever been registered.
+    pub fn is_registered(&self, merkle_tree: &Pubkey) -> bool {
+        self.trees.iter().any(|tree| tree.merkle_tree == *merkle_tree)
+    }
+
+    /// Capacity of the active tree, 0 when no tree has been activated.
+    pub fn active_capacity(&self) -> u64 {
+        self.trees
+            .iter()
+            .find(|tree| tree.merkle_tree == self.active_tree)
+            .map_or(0, |tree| tree.capacity)
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ConvictionStake {
+    /// Wallet whose deposit is staked (32 bytes)
+    pub voter: Pubkey,
+    /// Idea the deposit is staked on (32 bytes)
+    pub idea: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Amount of the voter's deposit currently staked on the idea (8 bytes)
+    pub amount: u64,
+    /// Unix timestamp of the last stake change (8 bytes)
+    pub updated_at: i64,
+}
+// PDA seeds: ["conviction_stake", idea.key(), voter.key()]
+// Total: 8 + 32 + 32 + 1 + 8 + 8 = 89 bytes
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+/// Number of rounds ahead of `next_round` whose participation can be tracked.
+pub const PARTICIPATION_WINDOW: u32 = 256;
+
+/// Public profile of a wallet offering to vote on behalf of delegators.
+///
+/// Participation counters are maintained on-chain: cast_vote records the
+/// rounds the delegate voted in, and the tally_delegate_round crank walks
+/// finished rounds in order, counting each as voted or missed.
+#[account]
+#[derive(InitSpace)]
+pub struct DelegateProfile {
+    /// Wallet advertising itself as a delegate (32 bytes)
+    pub delegate: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// SHA-256 hash of the off-chain delegate statement (32 bytes)
+    pub statement_hash: [u8; 32],
+    /// Bitmask of topics the delegate accepts delegations for (4 bytes)
+    pub topics: u32,
+    /// Whether the delegate currently accepts new delegations (1 byte)
+    pub accepting_delegations: bool,
+    /// Number of ideas voted on since registration (4 bytes)
+    pub ideas_voted: u32,
+    /// Number of tallied rounds in which the delegate voted (4 bytes)
+    pub rounds_voted: u32,
+    /// Number of tallied rounds with ideas in which the delegate did not vote (4 bytes)
+    pub rounds_missed: u32,
+    /// Index of the next round to be tallied (4 bytes)
+    pub next_round: u32,
+    /// Bitmap of rounds voted in, indexed by round_index % PARTICIPATION_WINDOW (32 bytes)
+    pub voted_rounds: [u8; 32],
+    /// Unix timestamp of registration (8 bytes)
+    pub registered_at: i64,
+    /// Unix timestamp of the last statement or topic change (8 bytes)
+    pub updated_at: i64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["delegate_profile", realm, delegate.key()]
+// Total: 8 + 32 + 1 + 32 + 4 + 1 + 4 + 4 + 4 + 4 + 32 + 8 + 8 + 32 = 174 bytes
+
+impl DelegateProfile {
+    /// Record a vote on one idea of round `round_index`.
+    ///
+    /// Rounds created before registration count towards ideas_voted only.
+    pub fn record_vote(&mut self, round_index: u32) -> Option<()> {
+        self.ideas_voted = self.ideas_voted.checked_add(1)?;
+        if round_index >= self.next_round
+            && round_index - self.next_round < PARTICIPATION_WINDOW
+        {
+            let slot = round_index % PARTICIPATION_WINDOW;
+            self.voted_rounds[(slot / 8) as usize] |= 1 << (slot % 8);
+        }
+        Some(())
+    }
+
+    /// Consume the participation bit of `next_round`, returning whether the
+    /// delegate voted in it, and advance the cursor.
+    pub fn take_next_round(&mut self) -> Option<bool> {
+        let slot = self.next_round % PARTICIPATION_WINDOW;
+        let mask = 1u8 << (slot % 8);
+        let voted = self.voted_rounds[(slot / 8) as usize] & mask != 0;
+        self.voted_rounds[(slot / 8) as usize] &= !mask;
+        self.next_round = self.next_round.checked_add(1)?;
+        Some(voted)
+    }
+}
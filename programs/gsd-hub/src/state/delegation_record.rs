@@ -17,6 +17,14 @@ pub struct DelegationRecord {
     pub is_active: bool,
     /// Round index from which this delegation takes effect (4 bytes)
     pub effective_from_round: u32,
+    /// Latest deposit included in delegated_amount, from VoteDeposit::deposit_timestamp;
+    /// rounds snapshotted before it leave pending_amount out (8 bytes)
+    pub deposited_at: i64,
+    /// Pending top-ups included in delegated_amount, from VoteDeposit::pending_amount (8 bytes)
+    pub pending_amount: u64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
 }
-// PDA seeds: ["delegation", delegator.key()]
-// Total: 8 (disc) + 32 + 32 + 1 + 8 + 8 + 1 + 4 = 94 bytes
+// PDA seeds: ["delegation", realm, delegator.key()]
+// Total: 8 (disc) + 32 + 32 + 1 + 8 + 8 + 1 + 4 + 8 + 8 + 32 = 142 bytes
@@ -1,5 +1,21 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::GsdHubError;
+use crate::math::{apply_decay, contribution_score, decay_factor};
+
+/// One write of contribution_score other than a decay crank, kept so
+/// revenue claims can read the score as it stood at an earlier time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct ScoreCheckpoint {
+    /// Unix timestamp of the write (8 bytes)
+    pub at: i64,
+    /// contribution_score right after the write (8 bytes)
+    pub score: u64,
+}
+// Total: 8 + 8 = 16 bytes
+
+/// Profiles created before the appended field groups existed are grown to
+/// the current layout by `migrate_developer_profile`.
 #[account]
 #[derive(InitSpace)]
 pub struct DeveloperProfile {
@@ -14,7 +30,7 @@ pub struct DeveloperProfile {
     /// SHA-256 hash of off-chain profile data (32 bytes)
     pub profile_hash: [u8; 32],
 
-    // --- Contribution score fields (appended for backward compatibility) ---
+    // --- Contribution score fields (appended) ---
 
     /// Number of verified tasks completed (4 bytes)
     pub tasks_completed: u32,
@@ -30,5 +46,196 @@ pub struct DeveloperProfile {
     pub last_contribution_at: i64,
     /// Score formula version, starts at 1 (1 byte)
     pub score_version: u8,
+
+    // --- Score audit fields (appended) ---
+
+    /// Number of score updates applied, next ScoreAuditEntry sequence (4 bytes)
+    pub score_update_count: u32,
+
+    // --- Oracle rate limit fields (appended) ---
+
+    /// Unix timestamp of the last score oracle update, 0 = never (8 bytes)
+    pub oracle_updated_at: i64,
+
+    // --- Proven score fields (appended) ---
+
+    /// Unix timestamp of the first proven contribution credit, 0 = scored by
+    /// the oracle only. Once set, the oracle can no longer overwrite totals (8 bytes)
+    pub proven_at: i64,
+
+    // --- Reputation decay fields (appended) ---
+
+    /// Unix timestamp the score was last decayed to, 0 = decay not started (8 bytes)
+    pub score_decayed_at: i64,
+
+    // --- Score checkpoint fields (appended) ---
+
+    /// Checkpoints written so far; the next goes to slot
+    /// `count % SCORE_HISTORY_LEN` (4 bytes)
+    pub score_checkpoint_count: u32,
+    /// Most recent credit, debit and oracle writes of contribution_score,
+    /// before any decay since (16 * 16 = 256 bytes)
+    pub score_history: [ScoreCheckpoint; 16],
+}
+// PDA seeds: ["developer", authority.key()]
+// Hub-wide by design: a developer carries one reputation across every realm.
+// Total: 8 (discriminator) + 32 + 1 + 8 + 8 + 32 + 4 + 8 + 4 + 8 + 8 + 8 + 1 + 4 + 8 + 8 + 8 + 4 + 256 = 418 bytes (410 data + 8 disc)
+
+impl DeveloperProfile {
+    /// Shortest interval between two decay cranks of the same profile, so
+    /// repeated floor rounding cannot be used to grind a score down.
+    pub const MIN_DECAY_INTERVAL: i64 = 86_400;
+
+    /// Shortest interval between two score oracle updates of the same
+    /// profile, so the per-update caps also bound how fast a score can grow.
+    pub const MIN_ORACLE_UPDATE_INTERVAL: i64 = 86_400;
+
+    /// Most verification score a single task can carry.
+    pub const MAX_TASK_VERIFICATION_SCORE: u64 = 10_000;
+
+    /// Checkpoints kept in score_history.
+    pub const SCORE_HISTORY_LEN: usize = 16;
+
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// Most active days the profile can claim at `now`, counting the day it
+    /// was created.
+    pub fn max_active_days(&self, now: i64) -> i64 {
+        now.saturating_sub(self.created_at).max(0) / Self::SECONDS_PER_DAY + 1
+    }
+
+    /// Decay factor accumulated since `score_decayed_at`, in DECAY_SCALE units.
+    fn decay_since_last(&self, now: i64, half_life_secs: u64) -> u64 {
+        let elapsed = if self.score_decayed_at == 0 {
+            0
+        } else {
+            now.saturating_sub(self.score_decayed_at).max(0) as u64
+        };
+        decay_factor(elapsed, half_life_secs)
+    }
+
+    /// The score as it stood at `at`: the newest checkpoint written at or
+    /// before `at`, decayed to `at`. Decay cranks write no checkpoint, so
+    /// they never change the answer, and later credits or debits are not
+    /// counted.
+    ///
+    /// When `at` predates every retained checkpoint, the oldest one is used
+    /// as is. Profiles without checkpoints fall back to the stored score,
+    /// decayed forward when its last decay is no later than `at`.
+    pub fn score_at(&self, at: i64, half_life_secs: u64) -> u64 {
+        let retained = (self.score_checkpoint_count as usize).min(Self::SCORE_HISTORY_LEN);
+        if retained == 0 {
+            if self.score_decayed_at == 0 || self.score_decayed_at > at {
+                return self.contribution_score;
+            }
+            let elapsed = (at - self.score_decayed_at) as u64;
+            return apply_decay(self.contribution_score, decay_factor(elapsed, half_life_secs));
+        }
+
+        // Walk back from the newest checkpoint
+        let newest = self.score_checkpoint_count as usize - 1;
+        let mut oldest = self.score_history[newest % Self::SCORE_HISTORY_LEN];
+        for back in 0..retained {
+            let checkpoint = self.score_history[(newest - back) % Self::SCORE_HISTORY_LEN];
+            if checkpoint.at <= at {
+                let elapsed = (at - checkpoint.at) as u64;
+                return apply_decay(checkpoint.score, decay_factor(elapsed, half_life_secs));
+            }
+            oldest = checkpoint;
+        }
+        oldest.score
+    }
+
+    /// Record contribution_score in score_history, overwriting the oldest
+    /// checkpoint once the history is full.
+    pub fn checkpoint_score(&mut self, now: i64) {
+        let slot = self.score_checkpoint_count as usize % Self::SCORE_HISTORY_LEN;
+        self.score_history[slot] = ScoreCheckpoint {
+            at: now,
+            score: self.contribution_score,
+        };
+        self.score_checkpoint_count = self.score_checkpoint_count.wrapping_add(1);
+    }
+
+    /// Fold decay since the last crank into contribution_score and
+    /// total_verification_score. The score is linear in the verification
+    /// total, so both shrink by the same factor and later proven leaves are
+    /// added to an already-decayed total.
+    pub fn apply_score_decay(&mut self, now: i64, half_life_secs: u64) {
+        let factor = self.decay_since_last(now, half_life_secs);
+        self.contribution_score = apply_decay(self.contribution_score, factor);
+        self.total_verification_score = apply_decay(self.total_verification_score, factor);
+        self.score_decayed_at = now;
+    }
+
+    /// Count one proven contribution worth `verification_score` and
+    /// recompute contribution_score on-chain. Decay owed since the last
+    /// crank is folded in first, so the new credit starts undecayed.
+    pub fn credit_contribution(
+        &mut self,
+        verification_score: u64,
+        contributed_at: i64,
+        now: i64,
+        half_life_secs: u64,
+    ) -> Result<()> {
+        self.apply_score_decay(now, half_life_secs);
+
+        self.tasks_completed = self
+            .tasks_completed
+            .checked_add(1)
+            .ok_or(GsdHubError::ScoreOverflow)?;
+        self.total_verification_score = self
+            .total_verification_score
+            .checked_add(verification_score)
+            .ok_or(GsdHubError::ScoreOverflow)?;
+
+        // Activity runs from the earliest counted contribution, as in the
+        // off-chain indexer
+        if self.first_contribution_at == 0 || contributed_at < self.first_contribution_at {
+            self.first_contribution_at = contributed_at;
+        }
+        self.last_contribution_at = self.last_contribution_at.max(contributed_at);
+        if self.proven_at == 0 {
+            self.proven_at = now;
+        }
+
+        self.recompute_contribution_score(now)
+    }
+
+    /// Take back one contribution credited with `verification_score` at
+    /// `counted_at`. The credit has decayed along with the rest of the
+    /// total since then, so only what is left of it is subtracted.
+    pub fn debit_contribution(
+        &mut self,
+        verification_score: u64,
+        counted_at: i64,
+        now: i64,
+        half_life_secs: u64,
+    ) -> Result<()> {
+        self.apply_score_decay(now, half_life_secs);
+
+        let elapsed = now.saturating_sub(counted_at).max(0) as u64;
+        let remaining = apply_decay(verification_score, decay_factor(elapsed, half_life_secs));
+        self.tasks_completed = self.tasks_completed.saturating_sub(1);
+        self.total_verification_score = self.total_verification_score.saturating_sub(remaining);
+
+        self.recompute_contribution_score(now)
+    }
+
+    fn recompute_contribution_score(&mut self, now: i64) -> Result<()> {
+        // Whole days since the first contribution, at least one
+        let active_days = now.saturating_sub(self.first_contribution_at) / Self::SECONDS_PER_DAY;
+        self.time_active_days = u32::try_from(active_days.max(1)).unwrap_or(u32::MAX);
+
+        self.contribution_score = contribution_score(
+            self.tasks_completed,
+            self.total_verification_score,
+            self.time_active_days,
+        )
+        .ok_or(GsdHubError::ScoreOverflow)?;
+        self.checkpoint_score(now);
+        self.updated_at = now;
+
+        Ok(())
+    }
 }
-// Total: 8 (discriminator) + 32 + 1 + 8 + 8 + 32 + 4 + 8 + 4 + 8 + 8 + 8 + 1 = 130 bytes (122 data + 8 disc)
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
 use super::idea_round::QuorumType;
+use super::pending_admin::PendingAdmin;
+use crate::errors::GovernanceError;
+use crate::math::isqrt;
 
 #[account]
 #[derive(InitSpace)]
@@ -25,11 +28,97 @@ pub struct GovernanceConfig {
     pub quadratic_voting_enabled: bool,
     /// Civic Pass gatekeeper network pubkey, default Pubkey::default() when disabled (32 bytes)
     pub civic_gatekeeper_network: Pubkey,
-    /// Half-life in days for voting power decay, default 180 (2 bytes)
+    /// Half-life in days for contribution score decay, default 180, 0 = disabled (2 bytes)
     pub decay_half_life_days: u16,
+
+    // --- Quadratic quorum fields ---
+
+    /// Sum of isqrt(deposited_amount) over every VoteDeposit, the eligible
+    /// weight in quadratic tally units (8 bytes)
+    pub total_quadratic_weight: u64,
+
+    // --- Vote snapshot fields ---
+
+    /// Latest voting_end of any Standard round that has entered Voting; top-ups
+    /// before it are held pending on their VoteDeposit (8 bytes)
+    pub votes_open_until: i64,
+
+    // --- Conviction voting fields ---
+
+    /// Seconds for conviction to close half the gap to the current stake, 0 = disabled (8 bytes)
+    pub conviction_half_life: i64,
+    /// Minimum share of total deposits conviction must reach, rho in bps (2 bytes)
+    pub conviction_rho_bps: u16,
+    /// Maximum requested share of the pool an idea may ask for, beta in bps (2 bytes)
+    pub conviction_max_share_bps: u16,
+
+    // --- Delegate registry fields ---
+
+    /// Whether delegate_vote requires the delegate to have a DelegateProfile (1 byte)
+    pub require_delegate_profile: bool,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+
+    // --- Admin handover fields ---
+
+    /// Authority handover awaiting acceptance (40 bytes)
+    pub pending_admin: PendingAdmin,
+}
+// PDA seeds: ["governance_config", realm]
+// Total: 8 (disc) + 32 + 32 + 32 + 1 + 4 + 8 + 8 + 8 + 1 + 32 + 2 + 8 + 8 + 8 + 2 + 2 + 1 + 32 + 40 = 269 bytes
+
+impl GovernanceConfig {
+    /// Move one deposit from `old_amount` to `new_amount` in both the token
+    /// total and the quadratic weight total.
+    pub fn record_deposit_change(&mut self, old_amount: u64, new_amount: u64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_sub(old_amount)
+            .and_then(|v| v.checked_add(new_amount))
+            .ok_or(GovernanceError::Overflow)?;
+        self.total_quadratic_weight = self
+            .total_quadratic_weight
+            .saturating_sub(isqrt(old_amount))
+            .checked_add(isqrt(new_amount))
+            .ok_or(GovernanceError::Overflow)?;
+        Ok(())
+    }
+
+    /// Weight every depositor could cast, in the units votes are tallied in:
+    /// raw tokens, or isqrt per deposit when quadratic voting is enabled.
+    pub fn eligible_vote_weight(&self) -> u64 {
+        if self.quadratic_voting_enabled {
+            self.total_quadratic_weight
+        } else {
+            self.total_deposited
+        }
+    }
+
+    /// Keep top-ups pending until `voting_end`, when a round that took its
+    /// weight snapshot earlier stops taking votes.
+    pub fn hold_deposits_until(&mut self, voting_end: i64) {
+        self.votes_open_until = self.votes_open_until.max(voting_end);
+    }
+
+    /// Reputation decay half-life in seconds; 0 disables decay.
+    pub fn decay_half_life_secs(&self) -> u64 {
+        self.decay_half_life_days as u64 * 86_400
+    }
+
+    /// Conviction an idea needs, in deposit units, given its requested share
+    /// of the available pool. Follows threshold = rho / (beta - share)^2 of
+    /// total deposits, capped at 100%. None when the share is not below beta.
+    pub fn conviction_threshold(&self, requested_share_bps: u64) -> Option<u64> {
+        let max_share = self.conviction_max_share_bps as u64;
+        if requested_share_bps >= max_share {
+            return None;
+        }
+        let gap = (max_share - requested_share_bps) as u128;
+        let fraction_bps = ((self.conviction_rho_bps as u128) * 100_000_000 / (gap * gap)).min(10_000);
+        Some(((self.total_deposited as u128) * fraction_bps / 10_000) as u64)
+    }
 }
-// PDA seeds: ["governance_config"]
-// Total: 8 (disc) + 32 + 32 + 32 + 1 + 4 + 8 + 8 + 8 + 1 + 32 + 2 = 168 bytes
 
 impl QuorumType {
     /// Returns the required basis points (bps) for quorum.
@@ -41,4 +130,9 @@ impl QuorumType {
             QuorumType::ParameterChange => 3300,
         }
     }
+
+    /// Whether `cast_weight` reaches the required share of `eligible_weight`.
+    pub fn is_met(&self, cast_weight: u64, eligible_weight: u64) -> bool {
+        (cast_weight as u128) * 10_000 >= (eligible_weight as u128) * (self.required_bps() as u128)
+    }
 }
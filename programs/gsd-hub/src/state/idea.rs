@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::math::{apply_decay, decay_factor, DECAY_SCALE};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum IdeaStatus {
     Submitted,
     Approved,
     Rejected,
     Vetoed,
+    Void,
 }
 
 #[account]
@@ -35,6 +38,57 @@ pub struct Idea {
     pub voter_count: u32,
     /// Unix timestamp after which idea can be executed if approved (8 bytes)
     pub execution_eligible_at: i64,
+
+    // --- Funding fields ---
+
+    /// Amount of the round's budget mint requested by the author (8 bytes)
+    pub requested_amount: u64,
+    /// Amount allocated by finalize_round_budget, 0 if unfunded (8 bytes)
+    pub allocated_amount: u64,
+    /// Whether the allocation has been moved into the idea's payout escrow (1 byte)
+    pub escrow_funded: bool,
+    /// Amount released to the author through verified milestones (8 bytes)
+    pub released_amount: u64,
+
+    // --- Milestone fields ---
+
+    /// Number of IdeaMilestone accounts registered by the author (1 byte)
+    pub milestone_count: u8,
+    /// Sum of all registered milestone amounts (8 bytes)
+    pub milestone_total: u64,
+
+    // --- Conviction fields ---
+
+    /// Deposit currently staked on this idea across all ConvictionStakes (8 bytes)
+    pub conviction_staked: u64,
+    /// Accumulated conviction as of conviction_updated_at, in deposit units (8 bytes)
+    pub conviction: u64,
+    /// Unix timestamp of the last conviction update (8 bytes)
+    pub conviction_updated_at: i64,
 }
 // PDA seeds: ["idea", round.key(), idea_index.to_le_bytes()]
-// Total: 8 + 32 + 32 + 4 + 1 + 1 + 32 + 8 + 8 + 8 + 8 + 4 + 8 = 154 bytes
+// Total: 8 + 32 + 32 + 4 + 1 + 1 + 32 + 8 + 8 + 8 + 8 + 4 + 8 + 8 + 8 + 1 + 8 + 1 + 8 + 8 + 8 + 8 = 212 bytes
+
+impl Idea {
+    /// Move conviction forward to `now`: conviction approaches the current
+    /// stake, closing half the gap every `half_life` seconds.
+    pub fn accrue_conviction(&mut self, now: i64, half_life: i64) {
+        let elapsed = now.saturating_sub(self.conviction_updated_at).max(0) as u64;
+        let factor = decay_factor(elapsed, half_life.max(0) as u64);
+        self.conviction = apply_decay(self.conviction, factor)
+            + apply_decay(self.conviction_staked, DECAY_SCALE - factor);
+        self.conviction_updated_at = now;
+    }
+
+    /// Total weight cast on this idea across Yes, No and Abstain.
+    pub fn total_cast_weight(&self) -> u64 {
+        self.yes_weight
+            .saturating_add(self.no_weight)
+            .saturating_add(self.abstain_weight)
+    }
+
+    /// Net Yes weight used to rank approved ideas for budget allocation.
+    pub fn net_yes_weight(&self) -> i128 {
+        self.yes_weight as i128 - self.no_weight as i128
+    }
+}
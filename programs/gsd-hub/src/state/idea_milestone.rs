@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of milestones an idea can register.
+pub const MAX_MILESTONES: u8 = 16;
+
+/// How long after an idea becomes fundable its author may register
+/// milestones; any allocation still unplanned afterwards can be reclaimed.
+pub const MILESTONE_PLANNING_PERIOD: i64 = 30 * 86_400;
+
+#[account]
+#[derive(InitSpace)]
+pub struct IdeaMilestone {
+    /// Idea whose escrow funds this milestone (32 bytes)
+    pub idea: Pubkey,
+    /// Sequential index within the idea (1 byte)
+    pub milestone_index: u8,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Amount released from the idea escrow on completion (8 bytes)
+    pub amount: u64,
+    /// SHA-256 hash of the task reference the VerificationReport must match (32 bytes)
+    pub task_ref: [u8; 32],
+    /// Minimum VerificationReport score (0-10000) required for release (2 bytes)
+    pub min_score: u16,
+    /// Whether the milestone amount has been released (1 byte)
+    pub released: bool,
+    /// Unix timestamp of release, 0 until released (8 bytes)
+    pub released_at: i64,
+    /// Unix timestamp the milestone was registered; only later verifications release it (8 bytes)
+    pub created_at: i64,
+    /// Whether the round authority or governance admin accepted the milestone (1 byte)
+    pub approved: bool,
+    /// Unix timestamp by which the task must be verified; afterwards the
+    /// amount can be reclaimed into the round budget (8 bytes)
+    pub deadline: i64,
+    /// Whether the amount was returned to the round budget (1 byte)
+    pub reclaimed: bool,
+}
+// PDA seeds: ["idea_milestone", idea.key(), milestone_index]
+// Total: 8 + 32 + 1 + 1 + 8 + 32 + 2 + 1 + 8 + 8 + 1 + 8 + 1 = 111 bytes
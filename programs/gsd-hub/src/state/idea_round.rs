@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{BudgetRanking, NULLIFIER_CAPACITY};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum RoundStatus {
     Open,
     Voting,
     Closed,
+    Cancelled,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -14,6 +17,16 @@ pub enum QuorumType {
     ParameterChange,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RoundType {
+    /// Token-weighted voting on ideas
+    Standard,
+    /// Direct contributions matched from the round budget by the QF formula
+    QuadraticFunding,
+    /// Continuous stake-weighted conviction funding from the round budget
+    Conviction,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct IdeaRound {
@@ -37,6 +50,187 @@ pub struct IdeaRound {
     pub quorum_type: QuorumType,
     /// SHA-256 hash of off-chain round metadata (32 bytes)
     pub content_hash: [u8; 32],
+
+    // --- Pause fields ---
+
+    /// Whether the admin paused submissions and votes in this round alone (1 byte)
+    pub paused: bool,
+
+    // --- Rent payer fields ---
+
+    /// Account that paid the round's rent and receives it back on archival,
+    /// Pubkey::default() for rounds created before it was recorded (32 bytes)
+    pub rent_payer: Pubkey,
+
+    // --- Funding budget fields ---
+
+    /// Mint of the escrowed funding budget, Pubkey::default() when unbudgeted (32 bytes)
+    pub budget_mint: Pubkey,
+    /// Total budget escrowed from the treasury at round creation (8 bytes)
+    pub budget_amount: u64,
+    /// Budget allocated to approved ideas by finalize_round_budget (8 bytes)
+    pub budget_allocated: u64,
+    /// Budget already moved into per-idea payout escrows (8 bytes)
+    pub budget_disbursed: u64,
+    /// Whether allocations have been computed for this round (1 byte)
+    pub budget_finalized: bool,
+    /// Eligible vote weight in tally units (see GovernanceConfig::eligible_vote_weight)
+    /// snapshotted when voting opens, used for quorum (8 bytes)
+    pub eligible_weight_snapshot: u64,
+
+    // --- Quadratic funding fields ---
+
+    /// How ideas in this round are decided and funded (1 byte)
+    pub round_type: RoundType,
+    /// Whether QF contributors must hold a valid Civic Pass gateway token (1 byte)
+    pub qf_require_gateway: bool,
+    /// Sum of every idea's QF match weight, the denominator for matching (16 bytes)
+    pub qf_total_match_weight: u128,
+    /// Number of ideas that received at least one contribution (4 bytes)
+    pub qf_idea_count: u32,
+    /// Number of contributed ideas whose match has been paid into their escrow (4 bytes)
+    pub qf_ideas_matched: u32,
+
+    // --- Vote snapshot fields ---
+
+    /// Unix timestamp eligible_weight_snapshot was taken; deposits topped up
+    /// at or after it only vote with their settled part, 0 before voting (8 bytes)
+    pub weight_snapshot_at: i64,
+
+    // --- Compressed vote receipt fields ---
+
+    /// Concurrent Merkle tree receiving vote receipts, Pubkey::default() when
+    /// votes create VoteRecord PDAs instead (32 bytes)
+    pub vote_tree: Pubkey,
+    /// Number of vote receipts appended to vote_tree (8 bytes)
+    pub vote_receipt_count: u64,
+
+    // --- Archival fields ---
+
+    /// Number of ideas summarized into the archive tree and closed (4 bytes)
+    pub archived_idea_count: u32,
+    /// VoteRecord and VoteNullifier accounts not yet closed; the round cannot
+    /// be archived while voters still need it to relinquish (4 bytes)
+    pub open_vote_accounts: u32,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["idea_round", realm, round_index.to_le_bytes()]
+// Total: 8 + 32 + 4 + 1 + 1 + 8 + 8 + 8 + 4 + 1 + 32 + 1 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 1 + 1 + 16 + 4 + 4 + 8 + 32 + 8 + 4 + 4 + 32 = 319 bytes
+
+impl IdeaRound {
+    /// Initialize a freshly created round. Shared by `create_round` and
+    /// `create_scheduled_round` so every field starts from the same state.
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        authority: Pubkey,
+        realm: Pubkey,
+        round_index: u32,
+        bump: u8,
+        submission_start: i64,
+        submission_end: i64,
+        voting_end: i64,
+        quorum_type: QuorumType,
+        content_hash: [u8; 32],
+        budget_mint: Pubkey,
+        budget_amount: u64,
+        rent_payer: Pubkey,
+    ) {
+        self.authority = authority;
+        self.realm = realm;
+        self.round_index = round_index;
+        self.bump = bump;
+        self.status = RoundStatus::Open;
+        self.submission_start = submission_start;
+        self.submission_end = submission_end;
+        self.voting_end = voting_end;
+        self.idea_count = 0;
+        self.quorum_type = quorum_type;
+        self.content_hash = content_hash;
+        self.paused = false;
+        self.rent_payer = rent_payer;
+        self.budget_mint = budget_mint;
+        self.budget_amount = budget_amount;
+        self.budget_allocated = 0;
+        self.budget_disbursed = 0;
+        self.budget_finalized = false;
+        self.eligible_weight_snapshot = 0;
+        self.round_type = RoundType::Standard;
+        self.qf_require_gateway = false;
+        self.qf_total_match_weight = 0;
+        self.qf_idea_count = 0;
+        self.qf_ideas_matched = 0;
+        self.weight_snapshot_at = 0;
+        self.vote_tree = Pubkey::default();
+        self.vote_receipt_count = 0;
+        self.archived_idea_count = 0;
+        self.open_vote_accounts = 0;
+    }
+
+    /// Cutoff deposits must predate to vote with their full amount. Rounds that
+    /// entered Voting before the snapshot time was recorded fall back to
+    /// submission_end.
+    pub fn vote_snapshot_at(&self) -> i64 {
+        if self.weight_snapshot_at == 0 {
+            self.submission_end
+        } else {
+            self.weight_snapshot_at
+        }
+    }
+
+    /// Account refunded the round's rent when it is archived.
+    pub fn rent_recipient(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.authority
+        } else {
+            self.rent_payer
+        }
+    }
+
+    /// Whether this round escrows a funding budget.
+    pub fn has_budget(&self) -> bool {
+        self.budget_mint != Pubkey::default()
+    }
+
+    /// Most ideas the round can hold. Budgeted Standard rounds are bounded by
+    /// the BudgetRanking that finalize_round_budget fills, and compressed-vote
+    /// rounds by the bits in each voter's VoteNullifier.
+    pub fn idea_capacity(&self) -> u32 {
+        let mut capacity = u32::MAX;
+        if self.has_budget() && self.round_type == RoundType::Standard {
+            capacity = BudgetRanking::MAX_ENTRIES as u32;
+        }
+        if self.has_vote_tree() {
+            capacity = capacity.min(NULLIFIER_CAPACITY);
+        }
+        capacity
+    }
+
+    /// Whether votes in this round are stored as compressed receipts.
+    pub fn has_vote_tree(&self) -> bool {
+        self.vote_tree != Pubkey::default()
+    }
+
+    /// Budget not yet allocated to any idea.
+    pub fn unallocated_budget(&self) -> u64 {
+        self.budget_amount.saturating_sub(self.budget_allocated)
+    }
+
+    /// Budget allocated to ideas but not yet moved into their payout escrows.
+    pub fn outstanding_allocations(&self) -> u64 {
+        self.budget_allocated.saturating_sub(self.budget_disbursed)
+    }
+
+    /// Whether the budget can no longer be claimed by ideas, so leftovers may be reclaimed.
+    pub fn is_budget_settled(&self) -> bool {
+        match self.round_type {
+            RoundType::Standard => self.budget_finalized,
+            RoundType::QuadraticFunding => {
+                self.status == RoundStatus::Closed && self.qf_ideas_matched == self.qf_idea_count
+            }
+            RoundType::Conviction => self.status == RoundStatus::Closed,
+        }
+    }
 }
-// PDA seeds: ["idea_round", round_index.to_le_bytes()]
-// Total: 8 + 32 + 4 + 1 + 1 + 8 + 8 + 8 + 4 + 1 + 32 = 107 bytes
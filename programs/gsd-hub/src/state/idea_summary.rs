@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+use super::idea::IdeaStatus;
+
+/// Leaf data for the round archive Merkle tree.
+///
+/// This is NOT a PDA account -- it preserves the outcome of an Idea after
+/// the account itself is closed. The full serialized data (137 bytes) is
+/// emitted via spl-noop so indexers can serve archived rounds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct IdeaSummaryLeaf {
+    /// The IdeaRound the idea belonged to (32 bytes)
+    pub round: Pubkey,
+    /// The closed Idea account (32 bytes)
+    pub idea: Pubkey,
+    /// Sequential index within the round (4 bytes)
+    pub idea_index: u32,
+    /// Final status of the idea (1 byte)
+    pub status: IdeaStatus,
+    /// Total weight of Yes votes (8 bytes)
+    pub yes_weight: u64,
+    /// Total weight of No votes (8 bytes)
+    pub no_weight: u64,
+    /// Total weight of Abstain votes (8 bytes)
+    pub abstain_weight: u64,
+    /// Number of unique voters (4 bytes)
+    pub voter_count: u32,
+    /// SHA-256 hash of off-chain idea content (32 bytes)
+    pub content_hash: [u8; 32],
+    /// Unix timestamp of archival (8 bytes)
+    pub archived_at: i64,
+}
+// Total serialized: 32 + 32 + 4 + 1 + 8 + 8 + 8 + 4 + 32 + 8 = 137 bytes
+
+impl IdeaSummaryLeaf {
+    /// Serialized size of the leaf data.
+    pub const SIZE: usize = 137;
+
+    /// Serialize the summary and hash it for Merkle tree insertion.
+    pub fn to_leaf_hash(&self) -> [u8; 32] {
+        let data = AnchorSerialize::try_to_vec(self).expect("IdeaSummaryLeaf serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&data);
+        hasher.finalize().into()
+    }
+}
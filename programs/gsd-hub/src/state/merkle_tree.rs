@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::operator_role::OperatorRole;
+use super::pending_admin::PendingAdmin;
+
 /// On-chain configuration for a contribution Merkle tree.
 ///
 /// PDA seeds: `[b"contribution_tree", merkle_tree.key().as_ref()]`
@@ -7,7 +10,8 @@ use anchor_lang::prelude::*;
 /// Stores metadata about the concurrent Merkle tree used for
 /// compressed contribution records. The actual tree data lives
 /// in the spl-account-compression account; this PDA tracks
-/// authority, contribution count, and creation time.
+/// authority, contribution count, and creation time. Configs created
+/// before the appended field groups existed are grown by `migrate_tree_config`.
 #[account]
 #[derive(InitSpace)]
 pub struct ContributionTreeConfig {
@@ -21,5 +25,29 @@ pub struct ContributionTreeConfig {
     pub bump: u8,
     /// Unix timestamp of tree creation (8 bytes)
     pub created_at: i64,
+
+    // --- Authority handover fields (appended) ---
+
+    /// Authority handover awaiting acceptance (40 bytes)
+    pub pending_authority: PendingAdmin,
+
+    // --- Writer allowlist fields (appended) ---
+
+    /// Whether the tree's compression authority is this PDA rather than `authority` (1 byte)
+    pub pda_authority: bool,
+    /// Keys besides `authority` allowed to append, managed by `set_tree_writers` (4 + 32 * 8 bytes)
+    #[max_len(8)]
+    pub writers: Vec<Pubkey>,
+}
+// Total: 8 (discriminator) + 32 + 32 + 8 + 1 + 8 + 40 + 1 + (4 + 32 * 8) = 390 bytes
+
+impl ContributionTreeConfig {
+    pub const MAX_WRITERS: usize = 8;
+
+    /// Whether `signer` may append: the tree authority, an allowlisted
+    /// writer, or a TREE_WRITER operator.
+    pub fn may_write(&self, signer: Pubkey, operator_role: &Option<Account<OperatorRole>>) -> bool {
+        self.writers.contains(&signer)
+            || OperatorRole::permits(signer, self.authority, operator_role, OperatorRole::TREE_WRITER)
+    }
 }
-// Total: 8 (discriminator) + 32 + 32 + 8 + 1 + 8 = 89 bytes
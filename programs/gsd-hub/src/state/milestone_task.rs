@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Marks a task_ref as taken by one milestone of an idea.
+///
+/// Created by `add_idea_milestone`; because the PDA can only be initialized
+/// once, a single verified task can never release two milestones.
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneTask {
+    /// Milestone the task_ref belongs to (32 bytes)
+    pub milestone: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+}
+// PDA seeds: ["milestone_task", idea.key(), task_ref]
+// Total: 8 (disc) + 32 + 1 = 41 bytes
@@ -1,35 +1,77 @@
+pub mod archive_tree;
+pub mod budget_ranking;
 pub mod contribution;
+pub mod contribution_attestation;
+pub mod contribution_nullifier;
+pub mod contribution_tree_registry;
+pub mod conviction_stake;
+pub mod delegate_profile;
 pub mod delegation_record;
 pub mod developer;
 pub mod governance_config;
 pub mod idea;
+pub mod idea_milestone;
 pub mod idea_round;
+pub mod idea_summary;
 pub mod merkle_tree;
+pub mod milestone_task;
+pub mod operator_role;
+pub mod pause_config;
 pub mod peer_review;
+pub mod pending_admin;
+pub mod qf_contribution;
+pub mod qf_idea_tally;
 pub mod revenue_claim;
 pub mod revenue_config;
 pub mod revenue_event;
 pub mod revenue_vault;
 pub mod reviewer_profile;
+pub mod role_registry;
+pub mod round_schedule;
+pub mod score_audit_entry;
+pub mod score_config;
 pub mod verification_config;
 pub mod verification_report;
 pub mod vote_deposit;
+pub mod vote_nullifier;
+pub mod vote_receipt;
 pub mod vote_record;
 
+pub use archive_tree::*;
+pub use budget_ranking::*;
 pub use contribution::*;
+pub use contribution_attestation::*;
+pub use contribution_nullifier::*;
+pub use contribution_tree_registry::*;
+pub use conviction_stake::*;
+pub use delegate_profile::*;
 pub use delegation_record::*;
 pub use developer::*;
 pub use governance_config::*;
 pub use idea::*;
+pub use idea_milestone::*;
 pub use idea_round::*;
+pub use idea_summary::*;
 pub use merkle_tree::*;
+pub use milestone_task::*;
+pub use operator_role::*;
+pub use pause_config::*;
 pub use peer_review::*;
+pub use pending_admin::*;
+pub use qf_contribution::*;
+pub use qf_idea_tally::*;
 pub use revenue_claim::*;
 pub use revenue_config::*;
 pub use revenue_event::*;
 pub use revenue_vault::*;
 pub use reviewer_profile::*;
+pub use role_registry::*;
+pub use round_schedule::*;
+pub use score_audit_entry::*;
+pub use score_config::*;
 pub use verification_config::*;
 pub use verification_report::*;
 pub use vote_deposit::*;
+pub use vote_nullifier::*;
+pub use vote_receipt::*;
 pub use vote_record::*;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorRole {
+    /// Operator key these permissions belong to (32 bytes)
+    pub holder: Pubkey,
+    /// Bitmask of granted roles, see the `OperatorRole::*` role constants (1 byte)
+    pub roles: u8,
+    /// Realm admin (or role registry admin for hub grants) that last changed the roles (32 bytes)
+    pub granted_by: Pubkey,
+    /// Unix timestamp of the last change (8 bytes)
+    pub updated_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Realm the roles apply in; `HUB_REALM` for hub-wide roles (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["operator_role", realm, holder.key()]
+// Total: 8 (disc) + 32 + 1 + 32 + 8 + 1 + 32 = 114 bytes
+
+impl OperatorRole {
+    /// Realm key of hub-wide grants, managed by the role registry admin
+    pub const HUB_REALM: Pubkey = Pubkey::new_from_array([0; 32]);
+
+    /// May create governance rounds in the grant's realm
+    pub const ROUND_CREATOR: u8 = 1 << 0;
+    /// May record revenue events
+    pub const REVENUE_RECORDER: u8 = 1 << 1;
+    /// May execute revenue burns
+    pub const BURN_EXECUTOR: u8 = 1 << 2;
+    /// May update developer contribution scores
+    pub const SCORE_ORACLE: u8 = 1 << 3;
+    /// May submit AI verification reports
+    pub const VERIFICATION_ORACLE: u8 = 1 << 4;
+    /// May append to contribution trees owned by their tree_config PDA
+    pub const TREE_WRITER: u8 = 1 << 5;
+    /// Every defined role
+    pub const ALL: u8 = Self::ROUND_CREATOR
+        | Self::REVENUE_RECORDER
+        | Self::BURN_EXECUTOR
+        | Self::SCORE_ORACLE
+        | Self::VERIFICATION_ORACLE
+        | Self::TREE_WRITER;
+    /// Roles a realm's GovernanceConfig.admin may grant for that realm
+    pub const REALM_ROLES: u8 = Self::ROUND_CREATOR
+        | Self::REVENUE_RECORDER
+        | Self::BURN_EXECUTOR
+        | Self::VERIFICATION_ORACLE;
+    /// Roles over hub-wide state, granted under `HUB_REALM`
+    pub const HUB_ROLES: u8 = Self::SCORE_ORACLE | Self::TREE_WRITER;
+
+    /// Whether this assignment carries `role`.
+    pub fn has(&self, role: u8) -> bool {
+        self.roles & role == role
+    }
+
+    /// Whether `signer` may act as `role`: it is the subsystem's own `admin`,
+    /// or it passed its OperatorRole (seeds tie the account to the signer)
+    /// and that assignment carries `role`.
+    pub fn permits(
+        signer: Pubkey,
+        admin: Pubkey,
+        operator_role: &Option<Account<OperatorRole>>,
+        role: u8,
+    ) -> bool {
+        signer == admin || operator_role.as_ref().is_some_and(|r| r.has(role))
+    }
+}
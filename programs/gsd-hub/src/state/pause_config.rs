@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PauseConfig {
+    /// Key allowed to raise and clear pause flags (32 bytes)
+    pub guardian: Pubkey,
+    /// Bitmask of paused subsystems, see the `PauseConfig::*` flag constants (1 byte)
+    pub paused: u8,
+    /// Unix timestamp of the last flag change (8 bytes)
+    pub updated_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+}
+// PDA seeds: ["pause_config"]
+// Hub-wide by design: the emergency switch halts the program, not one realm.
+// Total: 8 (disc) + 32 + 1 + 8 + 1 = 50 bytes
+
+impl PauseConfig {
+    /// Rounds, ideas, votes, delegation and conviction
+    pub const VOTING: u8 = 1 << 0;
+    /// Governance token deposits and withdrawals, QF contributions and refunds
+    pub const DEPOSITS: u8 = 1 << 1;
+    /// Revenue events, claims, burns and treasury-funded round budgets
+    pub const REVENUE: u8 = 1 << 2;
+    /// AI and peer verification
+    pub const VERIFICATION: u8 = 1 << 3;
+    /// Developer registration, contribution recording and scores
+    pub const CONTRIBUTIONS: u8 = 1 << 4;
+    /// Every defined subsystem flag
+    pub const ALL: u8 = Self::VOTING
+        | Self::DEPOSITS
+        | Self::REVENUE
+        | Self::VERIFICATION
+        | Self::CONTRIBUTIONS;
+
+    /// Whether any of the subsystems in `flags` is currently paused.
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused & flags != 0
+    }
+
+    /// Fail with `SubsystemPaused` if `flag` is raised.
+    ///
+    /// `info` must be the ["pause_config"] PDA (callers pin it by seeds). The
+    /// account may not exist yet -- until the guardian is installed nothing is
+    /// paused.
+    pub fn require_active(info: &AccountInfo, flag: u8) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, GovernanceError::InvalidPauseConfig);
+        let data = info.try_borrow_data()?;
+        let config = PauseConfig::try_deserialize(&mut &data[..])?;
+        require!(config.paused & flag == 0, GovernanceError::SubsystemPaused);
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GovernanceError;
+
+/// Authority handover proposed by the current admin, embedded in every
+/// config account. Takes effect only once the proposed key signs `accept_*`.
+///
+/// Handover instructions skip the pause check so a compromised key can still
+/// be rotated while the guardian has the protocol paused.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct PendingAdmin {
+    /// Proposed new authority, Pubkey::default() when nothing is pending (32 bytes)
+    pub admin: Pubkey,
+    /// Unix timestamp after which the proposal lapses, 0 = never (8 bytes)
+    pub expires_at: i64,
+}
+// Total: 32 + 8 = 40 bytes
+
+impl PendingAdmin {
+    /// Record a proposal; `new_admin == Pubkey::default()` cancels any pending one.
+    pub fn propose(&mut self, new_admin: Pubkey, expires_at: Option<i64>, now: i64) -> Result<()> {
+        if new_admin == Pubkey::default() {
+            *self = PendingAdmin::default();
+            return Ok(());
+        }
+        let expires_at = expires_at.unwrap_or(0);
+        require!(
+            expires_at == 0 || expires_at > now,
+            GovernanceError::InvalidHandoverExpiry
+        );
+        self.admin = new_admin;
+        self.expires_at = expires_at;
+        Ok(())
+    }
+
+    /// Check that `candidate` may take over now and clear the proposal.
+    pub fn accept(&mut self, candidate: Pubkey, now: i64) -> Result<()> {
+        require!(
+            self.admin != Pubkey::default(),
+            GovernanceError::NoPendingAdmin
+        );
+        require_keys_eq!(self.admin, candidate, GovernanceError::NotPendingAdmin);
+        require!(
+            self.expires_at == 0 || now <= self.expires_at,
+            GovernanceError::PendingAdminExpired
+        );
+        *self = PendingAdmin::default();
+        Ok(())
+    }
+}
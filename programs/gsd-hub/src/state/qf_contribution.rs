@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct QfContribution {
+    /// Wallet that contributed (32 bytes)
+    pub contributor: Pubkey,
+    /// Idea contributed to (32 bytes)
+    pub idea: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Cumulative amount contributed to this idea (8 bytes)
+    pub amount: u64,
+    /// Whether the contribution was refunded after round cancellation (1 byte)
+    pub refunded: bool,
+}
+// PDA seeds: ["qf_contribution", idea.key(), contributor.key()]
+// Total: 8 + 32 + 32 + 1 + 8 + 1 = 82 bytes
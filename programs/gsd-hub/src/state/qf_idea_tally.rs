@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct QfIdeaTally {
+    /// IdeaRound this tally belongs to (32 bytes)
+    pub round: Pubkey,
+    /// Idea receiving the contributions (32 bytes)
+    pub idea: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Sum of all direct contributions held in the idea escrow, less refunds (8 bytes)
+    pub total_contributed: u64,
+    /// Number of distinct contributors (4 bytes)
+    pub contributor_count: u32,
+    /// Sum over contributors of isqrt(contribution) (8 bytes)
+    pub sqrt_sum: u64,
+    /// Sum over contributors of isqrt(contribution)^2 (8 bytes)
+    pub sqrt_square_sum: u64,
+    /// QF match weight: sqrt_sum^2 - sqrt_square_sum (16 bytes)
+    pub match_weight: u128,
+    /// Matching amount paid from the round budget at finalization (8 bytes)
+    pub matched_amount: u64,
+    /// Whether the match has been paid into the idea escrow (1 byte)
+    pub matched: bool,
+}
+// PDA seeds: ["qf_tally", idea.key()]
+// Total: 8 + 32 + 32 + 1 + 8 + 4 + 8 + 8 + 16 + 8 + 1 = 126 bytes
+
+impl QfIdeaTally {
+    /// Match weight from the current sums. Squaring floored square roots keeps
+    /// the weight non-negative: only cross-contributor terms remain.
+    pub fn compute_match_weight(&self) -> u128 {
+        let sum = self.sqrt_sum as u128;
+        (sum * sum).saturating_sub(self.sqrt_square_sum as u128)
+    }
+}
@@ -19,4 +19,5 @@ pub struct RevenueClaim {
     pub claimed_at: i64,
 }
 // PDA seeds: ["revenue_claim", claimant.key(), revenue_event.key()]
+// Realm-scoped through revenue_event, whose address is seeded by realm.
 // Total: 8 (disc) + 32 + 32 + 1 + 8 + 8 + 8 + 8 = 105 bytes
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use super::pending_admin::PendingAdmin;
+
 #[account]
 #[derive(InitSpace)]
 pub struct RevenueConfig {
@@ -27,6 +29,14 @@ pub struct RevenueConfig {
     pub event_count: u32,
     /// Minimum amount to trigger distribution (8 bytes)
     pub min_revenue_threshold: u64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+
+    // --- Admin handover fields ---
+
+    /// Authority handover awaiting acceptance (40 bytes)
+    pub pending_admin: PendingAdmin,
 }
-// PDA seeds: ["revenue_config"]
-// Total: 8 (disc) + 32 + 1 + 2 + 2 + 2 + 2 + 32 + 32 + 32 + 32 + 4 + 8 = 189 bytes
+// PDA seeds: ["revenue_config", realm]
+// Total: 8 (disc) + 32 + 1 + 2 + 2 + 2 + 2 + 32 + 32 + 32 + 32 + 4 + 8 + 32 + 40 = 261 bytes
@@ -40,7 +40,7 @@ pub struct RevenueEvent {
     pub recorded_at: i64,
     /// Signature of the originating revenue transaction (64 bytes)
     pub origin_signature: [u8; 64],
-    /// Total contribution score snapshotted at recording time (8 bytes)
+    /// Sum of contribution scores decayed to recorded_at, snapshotted off-chain (8 bytes)
     pub total_contribution_score: u64,
     /// How much of developer_pool has been claimed (8 bytes)
     pub claimed_amount: u64,
@@ -48,6 +48,16 @@ pub struct RevenueEvent {
     pub burn_signature: [u8; 64],
     /// Actual $GSD tokens burned (8 bytes)
     pub gsd_burned: u64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["revenue_event", realm, event_index.to_le_bytes()]
+// Total: 8 (disc) + 4 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 64 + 8 + 8 + 64 + 8 + 32 = 258 bytes
+
+impl RevenueEvent {
+    /// Part of the developer pool not yet paid out.
+    pub fn unclaimed_amount(&self) -> u64 {
+        self.developer_pool.saturating_sub(self.claimed_amount)
+    }
 }
-// PDA seeds: ["revenue_event", event_index.to_le_bytes()]
-// Total: 8 (disc) + 4 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 64 + 8 + 8 + 64 + 8 = 226 bytes
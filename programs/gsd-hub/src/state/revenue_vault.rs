@@ -1,6 +1,6 @@
 /// Revenue Vault PDA seed constant.
 ///
-/// The revenue vault is a system account PDA (seeds: ["revenue_vault", event_index.to_le_bytes()])
+/// The revenue vault is a system account PDA (seeds: ["revenue_vault", realm, event_index.to_le_bytes()])
 /// that holds the developer pool SOL for a given revenue event.
 ///
 /// For USDC: the vault is an Associated Token Account (ATA) derived from this PDA,
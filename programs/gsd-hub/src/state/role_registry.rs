@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use super::pending_admin::PendingAdmin;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleRegistry {
+    /// Key allowed to grant and revoke hub-wide operator roles (32 bytes)
+    pub admin: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Unix timestamp of registry creation (8 bytes)
+    pub created_at: i64,
+    /// Admin handover awaiting acceptance (40 bytes)
+    pub pending_admin: PendingAdmin,
+}
+// PDA seeds: ["role_registry"]
+// Total: 8 (disc) + 32 + 1 + 8 + 40 = 89 bytes
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use super::idea_round::QuorumType;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoundSchedule {
+    /// Admin who created the schedule; generated rounds take the current admin instead (32 bytes)
+    pub authority: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Whether the crank may create new rounds from this schedule (1 byte)
+    pub is_active: bool,
+    /// Seconds between the submission starts of consecutive rounds, e.g. 30 days (8 bytes)
+    pub interval: i64,
+    /// Length of each round's submission window in seconds (8 bytes)
+    pub submission_length: i64,
+    /// Length of each round's voting window in seconds (8 bytes)
+    pub voting_length: i64,
+    /// Quorum type applied to every generated round (1 byte)
+    pub quorum_type: QuorumType,
+    /// SHA-256 hash of off-chain metadata copied into every generated round (32 bytes)
+    pub content_hash: [u8; 32],
+    /// Unix timestamp at which the next generated round opens submissions (8 bytes)
+    pub next_submission_start: i64,
+    /// Number of rounds created by this schedule (4 bytes)
+    pub rounds_created: u32,
+    /// round_index of the most recently generated round, valid once rounds_created > 0 (4 bytes)
+    pub last_round_index: u32,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["round_schedule", realm]
+// Total: 8 (disc) + 32 + 1 + 1 + 8 + 8 + 8 + 1 + 32 + 8 + 4 + 4 + 32 = 147 bytes
+
+impl RoundSchedule {
+    /// Validate cadence parameters: positive lengths and non-overlapping submission windows.
+    pub fn is_valid_cadence(interval: i64, submission_length: i64, voting_length: i64) -> bool {
+        interval > 0 && submission_length > 0 && voting_length > 0 && submission_length <= interval
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// One immutable record per contribution score update.
+#[account]
+#[derive(InitSpace)]
+pub struct ScoreAuditEntry {
+    /// Developer wallet whose profile was updated (32 bytes)
+    pub developer: Pubkey,
+    /// Position in the developer's update history, starting at 0 (4 bytes)
+    pub sequence: u32,
+    /// Score oracle that signed the update (32 bytes)
+    pub oracle: Pubkey,
+    /// contribution_score before the update (8 bytes)
+    pub previous_score: u64,
+    /// contribution_score after the update (8 bytes)
+    pub new_score: u64,
+    /// tasks_completed before the update (4 bytes)
+    pub previous_tasks: u32,
+    /// tasks_completed after the update (4 bytes)
+    pub new_tasks: u32,
+    /// score_version before the update (1 byte)
+    pub previous_version: u8,
+    /// score_version after the update (1 byte)
+    pub new_version: u8,
+    /// Unix timestamp of the update (8 bytes)
+    pub recorded_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+}
+// PDA seeds: ["score_audit", developer, sequence.to_le_bytes()]
+// Total: 8 (disc) + 32 + 4 + 32 + 8 + 8 + 4 + 4 + 1 + 1 + 8 + 1 = 111 bytes
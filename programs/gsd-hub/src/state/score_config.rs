@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GsdHubError;
+use crate::state::GovernanceConfig;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ScoreConfig {
+    /// Largest contribution_score increase one update may apply (8 bytes)
+    pub max_score_increase: u64,
+    /// Largest tasks_completed increase one update may apply (4 bytes)
+    pub max_tasks_increase: u32,
+    /// Role registry admin that last changed the limits (32 bytes)
+    pub updated_by: Pubkey,
+    /// Unix timestamp of the last change (8 bytes)
+    pub updated_at: i64,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Realm whose GovernanceConfig.decay_half_life_days decays stored scores (32 bytes)
+    pub decay_realm: Pubkey,
+}
+// PDA seeds: ["score_config"]
+// Hub-wide by design: scores live on the hub-wide DeveloperProfile.
+// Total: 8 (disc) + 8 + 4 + 32 + 8 + 1 + 32 = 93 bytes
+
+impl ScoreConfig {
+    /// Half-life that decays stored scores, in seconds. Every reader of a
+    /// decayed score goes through here so crediting, cranking and revenue
+    /// claims agree. No decay realm means no decay; otherwise the realm's
+    /// governance config must be passed.
+    pub fn decay_half_life_secs(
+        &self,
+        governance_config: &Option<Account<GovernanceConfig>>,
+    ) -> Result<u64> {
+        if self.decay_realm == Pubkey::default() {
+            return Ok(0);
+        }
+        let config = governance_config
+            .as_ref()
+            .ok_or(GsdHubError::MissingDecayConfig)?;
+        Ok(config.decay_half_life_secs())
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use super::pending_admin::PendingAdmin;
+
 #[account]
 #[derive(InitSpace)]
 pub struct VerificationConfig {
@@ -28,6 +30,19 @@ pub struct VerificationConfig {
     pub consensus_threshold_bps: u16,
     /// Review timeout in days (1 byte) -- default 7
     pub review_timeout_days: u8,
+
+    // --- Milestone fields ---
+
+    /// Lowest min_score an idea milestone may require (1-10000) (2 bytes)
+    pub min_milestone_score: u16,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+
+    // --- Admin handover fields ---
+
+    /// Authority handover awaiting acceptance (40 bytes)
+    pub pending_admin: PendingAdmin,
 }
-// PDA seeds: ["verification_config"]
-// Total: 8 (disc) + 32 + 1 + 1 + 2 + 2 + 2 + 2 + 2 + 2 + 1 + 2 + 1 = 58 bytes
+// PDA seeds: ["verification_config", realm]
+// Total: 8 (disc) + 32 + 1 + 1 + 2 + 2 + 2 + 2 + 2 + 2 + 1 + 2 + 1 + 2 + 32 + 40 = 132 bytes
@@ -38,6 +38,10 @@ pub struct VerificationReport {
     pub reviewer_count: u8,
     /// Config version at time of verification (1 byte)
     pub config_version: u8,
+    /// Governance realm whose verification config issued the report (32 bytes)
+    pub realm: Pubkey,
+    /// Config admin or VERIFICATION_ORACLE that wrote the current result (32 bytes)
+    pub verified_by: Pubkey,
 }
-// PDA seeds: ["verification", developer.key(), task_ref]
-// Total: 8 + 32 + 32 + 1 + 1 + 1 + 2 + 2 + 32 + 8 + 1 + 1 = 121 bytes
+// PDA seeds: ["verification", realm, developer.key(), task_ref]
+// Total: 8 + 32 + 32 + 1 + 1 + 1 + 2 + 2 + 32 + 8 + 1 + 1 + 32 + 32 = 185 bytes
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::GovernanceError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct VoteDeposit {
@@ -9,12 +11,69 @@ pub struct VoteDeposit {
     pub bump: u8,
     /// Amount of governance tokens deposited (8 bytes)
     pub deposited_amount: u64,
-    /// Unix timestamp when tokens were deposited (8 bytes)
+    /// Unix timestamp of the latest deposit; rounds snapshotted before it
+    /// leave pending_amount out of this deposit's weight (8 bytes)
     pub deposit_timestamp: i64,
     /// Unix timestamp when tokens become eligible for voting (8 bytes)
     pub eligible_at: i64,
     /// Number of currently active votes using this deposit (4 bytes)
     pub active_votes: u32,
+
+    // --- Vote snapshot fields ---
+
+    /// Part of deposited_amount topped up while a round could still take
+    /// votes, excluded from rounds snapshotted before deposit_timestamp (8 bytes)
+    pub pending_amount: u64,
+
+    // --- Conviction fields ---
+
+    /// Part of the deposit currently staked on conviction ideas (8 bytes)
+    pub conviction_staked: u64,
+
+    /// Governance realm this account belongs to (32 bytes)
+    pub realm: Pubkey,
+}
+// PDA seeds: ["vote_deposit", realm, authority.key()]
+// Total: 8 + 32 + 1 + 8 + 8 + 8 + 4 + 8 + 8 + 32 = 117 bytes
+
+impl VoteDeposit {
+    /// Tokens of this deposit that may vote in a round whose eligible weight
+    /// was snapshotted at `snapshot_at`.
+    pub fn amount_at(&self, snapshot_at: i64) -> u64 {
+        amount_at_snapshot(
+            self.deposited_amount,
+            self.pending_amount,
+            self.deposit_timestamp,
+            snapshot_at,
+        )
+    }
+
+    /// Add a top-up of `amount` at `now`. While a round snapshotted earlier can
+    /// still take votes (`now < votes_open_until`) the top-up is held pending;
+    /// once none can, every earlier top-up has settled.
+    pub fn record_top_up(&mut self, amount: u64, now: i64, votes_open_until: i64) -> Result<()> {
+        self.deposited_amount = self
+            .deposited_amount
+            .checked_add(amount)
+            .ok_or(GovernanceError::Overflow)?;
+        self.pending_amount = if now < votes_open_until {
+            self.pending_amount
+                .checked_add(amount)
+                .ok_or(GovernanceError::Overflow)?
+        } else {
+            0
+        };
+        self.deposit_timestamp = now;
+        Ok(())
+    }
+}
+
+/// Tokens out of `amount` that predate `snapshot_at`. Everything counts when
+/// the latest deposit came first; otherwise the pending top-ups are left out.
+pub fn amount_at_snapshot(amount: u64, pending: u64, latest_deposit_at: i64, snapshot_at: i64) -> u64 {
+    if latest_deposit_at < snapshot_at {
+        amount
+    } else {
+        amount.saturating_sub(pending)
+    }
 }
-// PDA seeds: ["vote_deposit", authority.key()]
-// Total: 8 + 32 + 1 + 8 + 8 + 8 + 4 = 69 bytes
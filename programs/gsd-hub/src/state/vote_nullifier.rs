@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Number of ideas per round a VoteNullifier bitmap can cover.
+pub const NULLIFIER_CAPACITY: u32 = 256;
+
+/// Per-voter, per-round double-vote guard for compressed vote receipts.
+///
+/// One account per voter and round replaces one VoteRecord per vote: bit
+/// `idea_index` is set once the voter has voted on that idea.
+#[account]
+#[derive(InitSpace)]
+pub struct VoteNullifier {
+    /// Wallet that voted (32 bytes)
+    pub voter: Pubkey,
+    /// The IdeaRound voted in (32 bytes)
+    pub round: Pubkey,
+    /// Canonical PDA bump (1 byte)
+    pub bump: u8,
+    /// Number of votes cast, i.e. active votes held on the deposit (2 bytes)
+    pub vote_count: u16,
+    /// Bitmap of idea indices already voted on (32 bytes)
+    pub voted: [u8; 32],
+}
+// PDA seeds: ["vote_nullifier", round.key(), voter.key()]
+// Total: 8 + 32 + 32 + 1 + 2 + 32 = 107 bytes
+
+impl VoteNullifier {
+    /// Whether the voter has already voted on `idea_index`.
+    pub fn has_voted(&self, idea_index: u32) -> bool {
+        self.voted[(idea_index / 8) as usize] & (1 << (idea_index % 8)) != 0
+    }
+
+    /// Mark `idea_index` as voted.
+    pub fn mark_voted(&mut self, idea_index: u32) {
+        self.voted[(idea_index / 8) as usize] |= 1 << (idea_index % 8);
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+use super::vote_record::VoteChoice;
+
+/// Leaf data for a round's compressed vote receipt tree.
+///
+/// This is NOT a PDA account -- it replaces the VoteRecord PDA in rounds with
+/// a vote tree. The receipt is hashed and appended as a leaf, and the full
+/// serialized data (up to 146 bytes) is emitted via spl-noop for indexers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct VoteReceiptLeaf {
+    /// Wallet that cast the vote (32 bytes)
+    pub voter: Pubkey,
+    /// The Idea account this vote is for (32 bytes)
+    pub idea: Pubkey,
+    /// The IdeaRound this vote belongs to (32 bytes)
+    pub round: Pubkey,
+    /// The voter's choice (1 byte)
+    pub vote: VoteChoice,
+    /// Token weight applied to this vote (8 bytes)
+    pub weight: u64,
+    /// Unix timestamp of when the vote was cast (8 bytes)
+    pub voted_at: i64,
+    /// SHA-256 hash of the off-chain rationale, if the voter published one (1 + 32 bytes)
+    pub reason_hash: Option<[u8; 32]>,
+}
+// Total serialized: 32 + 32 + 32 + 1 + 8 + 8 + (1 + 32) = 146 bytes at most
+
+impl VoteReceiptLeaf {
+    /// Serialized size of the leaf data with a reason hash.
+    pub const SIZE: usize = 146;
+
+    /// Serialize the receipt and hash it for Merkle tree insertion.
+    pub fn to_leaf_hash(&self) -> [u8; 32] {
+        let data = AnchorSerialize::try_to_vec(self).expect("VoteReceiptLeaf serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&data);
+        hasher.finalize().into()
+    }
+}
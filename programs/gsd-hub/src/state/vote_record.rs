@@ -24,6 +24,11 @@ pub struct VoteRecord {
     pub weight: u64,
     /// Unix timestamp of when the vote was cast (8 bytes)
     pub voted_at: i64,
+
+    // --- Rationale fields ---
+
+    /// SHA-256 hash of the voter's off-chain rationale, if published (1 + 32 bytes)
+    pub reason_hash: Option<[u8; 32]>,
 }
 // PDA seeds: ["vote_record", voter.key(), idea.key()]
-// Total: 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 = 122 bytes
+// Total: 8 + 32 + 32 + 32 + 1 + 1 + 8 + 8 + 33 = 155 bytes
@@ -0,0 +1,380 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token Mint layout: 82 bytes total
+// See https://github.com/solana-labs/solana-program-library/blob/master/token/program/src/state.rs
+const MINT_SIZE = 82;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun using createAccount + Token program initializeMint
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  // Create account instruction
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction (discriminator = 0, decimals, mintAuthority, option<freezeAuthority>)
+  // Layout: [0] (1 byte instruction) + [decimals] (1 byte) + [mintAuthority] (32 bytes) + [1] (option=Some) + [mintAuthority] (32 bytes)
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1); // decimals
+  mintAuthority.toBuffer().copy(data, 2); // mint_authority
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35); // freeze_authority
+
+  const initMintIx = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Governance, revenue and verification configs sharing one realm and admin
+ */
+async function setupConfigs() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mintKeypair.publicKey,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  const [revenueConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRevenueConfig(
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      mintKeypair.publicKey,
+      Keypair.generate().publicKey,
+      new BN(0)
+    )
+    .accounts({
+      revenueConfig: revenueConfigPda,
+      governanceConfig: governanceConfigPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const [verificationConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("verification_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initVerificationConfig(6000, 2500, 2000, 1500, 2500, 1500, 3, 7000, 7, 5000)
+    .accounts({
+      verificationConfig: verificationConfigPda,
+      governanceConfig: governanceConfigPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const newAdmin = Keypair.generate();
+  await airdrop(provider, newAdmin.publicKey);
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    governanceConfigPda,
+    revenueConfigPda,
+    verificationConfigPda,
+    newAdmin,
+    baseTime,
+  };
+}
+
+describe("Admin Handover", () => {
+  it("hands governance admin over only after acceptance", async () => {
+    const s = await setupConfigs();
+
+    await s.program.methods
+      .proposeGovernanceAdmin(s.newAdmin.publicKey, null)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    // Proposal alone changes nothing
+    let config = await s.program.account.governanceConfig.fetch(
+      s.governanceConfigPda
+    );
+    expect(config.admin.toBase58()).to.equal(s.admin.toBase58());
+    expect(config.pendingAdmin.admin.toBase58()).to.equal(
+      s.newAdmin.publicKey.toBase58()
+    );
+
+    await s.program.methods
+      .acceptGovernanceAdmin()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        newAdmin: s.newAdmin.publicKey,
+      })
+      .signers([s.newAdmin])
+      .rpc();
+
+    config = await s.program.account.governanceConfig.fetch(
+      s.governanceConfigPda
+    );
+    expect(config.admin.toBase58()).to.equal(s.newAdmin.publicKey.toBase58());
+    expect(config.pendingAdmin.admin.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+
+    // The previous admin has lost control
+    try {
+      await s.program.methods
+        .proposeGovernanceAdmin(s.admin, null)
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          admin: s.admin,
+        })
+        .rpc();
+      expect.fail("Expected UnauthorizedAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  it("rejects acceptance by a key that was not proposed", async () => {
+    const s = await setupConfigs();
+    const stranger = Keypair.generate();
+
+    await s.program.methods
+      .proposeRevenueAdmin(s.newAdmin.publicKey, null)
+      .accounts({
+        revenueConfig: s.revenueConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .acceptRevenueAdmin()
+        .accounts({
+          revenueConfig: s.revenueConfigPda,
+          newAdmin: stranger.publicKey,
+        })
+        .signers([stranger])
+        .rpc();
+      expect.fail("Expected NotPendingAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("NotPendingAdmin");
+    }
+  });
+
+  it("lets a pending handover lapse after its expiry", async () => {
+    const s = await setupConfigs();
+
+    await s.program.methods
+      .proposeVerificationAdmin(
+        s.newAdmin.publicKey,
+        new BN(s.baseTime + 3600)
+      )
+      .accounts({
+        verificationConfig: s.verificationConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    await warpToTimestamp(s.context, s.baseTime + 3601);
+
+    try {
+      await s.program.methods
+        .acceptVerificationAdmin()
+        .accounts({
+          verificationConfig: s.verificationConfigPda,
+          newAdmin: s.newAdmin.publicKey,
+        })
+        .signers([s.newAdmin])
+        .rpc();
+      expect.fail("Expected PendingAdminExpired error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("PendingAdminExpired");
+    }
+  });
+
+  it("cancels a pending handover when proposing the default key", async () => {
+    const s = await setupConfigs();
+
+    await s.program.methods
+      .proposeGovernanceAdmin(s.newAdmin.publicKey, null)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+    await s.program.methods
+      .proposeGovernanceAdmin(PublicKey.default, null)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .acceptGovernanceAdmin()
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          newAdmin: s.newAdmin.publicKey,
+        })
+        .signers([s.newAdmin])
+        .rpc();
+      expect.fail("Expected NoPendingAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("NoPendingAdmin");
+    }
+  });
+
+  it("rejects an expiry that is already in the past", async () => {
+    const s = await setupConfigs();
+
+    try {
+      await s.program.methods
+        .proposeGovernanceAdmin(s.newAdmin.publicKey, new BN(s.baseTime - 1))
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          admin: s.admin,
+        })
+        .rpc();
+      expect.fail("Expected InvalidHandoverExpiry error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidHandoverExpiry");
+    }
+  });
+});
@@ -0,0 +1,1093 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// OperatorRole bit allowed to write verification reports that release milestones
+const ROLE_VERIFICATION_ORACLE = 1 << 4;
+
+const [ROLE_REGISTRY_PDA] = PublicKey.findProgramAddressSync(
+  [Buffer.from("role_registry")],
+  PROGRAM_ID
+);
+
+/**
+ * Point the program's ProgramData upgrade authority at `authority` so the
+ * role registry can be installed (see operator-roles.test.ts).
+ */
+function setUpgradeAuthority(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  authority: PublicKey
+) {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const data = Buffer.alloc(45);
+  data.writeUInt32LE(3, 0);
+  data.writeUInt8(1, 12);
+  authority.toBuffer().copy(data, 13);
+  context.setAccount(programData, {
+    lamports: 1_000_000_000,
+    data,
+    owner: BPF_LOADER_UPGRADEABLE_ID,
+    executable: false,
+  });
+  return programData;
+}
+
+function operatorRolePda(holder: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("operator_role"), REALM.publicKey.toBuffer(), holder.toBuffer()],
+    PROGRAM_ID
+  )[0];
+}
+
+const BUDGET = 1_000;
+
+/** Time the author gives themselves to deliver each milestone */
+const MILESTONE_WINDOW = 86_400;
+
+/**
+ * Governance + revenue config, a funded treasury, a deposited voter and a
+ * budgeted round (budget 1000) with two ideas requesting 600 and 500.
+ */
+async function setupBudgetedRound() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  const vetoAuthority = Keypair.generate();
+  await airdrop(provider, vetoAuthority.publicKey);
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  // Short deposit timelock (60s) and execution timelock (120s)
+  await program.methods
+    .initGovernanceConfig(new BN(60), new BN(120))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  // Treasury wallet with a funded token account of the budget mint
+  const treasury = Keypair.generate();
+  await airdrop(provider, treasury.publicKey);
+  const treasuryTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    treasury.publicKey
+  );
+  await mintTo(provider, mint, treasuryTokenAccount, adminKeypair, BigInt(10_000));
+
+  const [revenueConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRevenueConfig(
+      treasury.publicKey,
+      Keypair.generate().publicKey,
+      mint,
+      Keypair.generate().publicKey,
+      new BN(0)
+    )
+    .accounts({
+      revenueConfig: revenueConfigPda,
+      governanceConfig: governanceConfigPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  // Voter deposits before the round starts
+  const escrowTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    governanceConfigPda
+  );
+  const voter = Keypair.generate();
+  await airdrop(provider, voter.publicKey);
+  const voterTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    voter.publicKey
+  );
+  await mintTo(provider, mint, voterTokenAccount, adminKeypair, BigInt(1_000_000));
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  const [voteDepositPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+  await program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      voteDeposit: voteDepositPda,
+      depositor: voter.publicKey,
+      userTokenAccount: voterTokenAccount,
+      escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([voter])
+    .rpc();
+
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+  const [roundBudgetPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("round_budget"), ideaRoundPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(baseTime),
+      new BN(baseTime + 3600),
+      new BN(baseTime + 7200),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(BUDGET)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      revenueConfig: revenueConfigPda,
+      budgetMint: mint,
+      treasury: treasury.publicKey,
+      treasuryTokenAccount,
+      roundBudgetEscrow: roundBudgetPda,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .signers([treasury])
+    .rpc();
+
+  const author = Keypair.generate();
+  await airdrop(provider, author.publicKey);
+  const authorTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    author.publicKey
+  );
+
+  const ideaPdas: PublicKey[] = [];
+  for (const [index, requested] of [600, 500].entries()) {
+    const [ideaPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea"),
+        ideaRoundPda.toBuffer(),
+        Buffer.from(new Uint32Array([index]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await program.methods
+      .submitIdea(
+        Array.from(new Uint8Array(32).fill(2 + index)) as number[],
+        new BN(requested)
+      )
+      .accounts({
+        ideaRound: ideaRoundPda,
+        idea: ideaPda,
+        author: author.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+    ideaPdas.push(ideaPda);
+  }
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    mint,
+    vetoAuthority,
+    governanceConfigPda,
+    revenueConfigPda,
+    treasuryTokenAccount,
+    voter,
+    voteDepositPda,
+    ideaRoundPda,
+    roundBudgetPda,
+    ideaPdas,
+    author,
+    authorTokenAccount,
+    baseTime,
+  };
+}
+
+type Setup = Awaited<ReturnType<typeof setupBudgetedRound>>;
+
+/**
+ * Vote Yes on the given ideas, close the round and finalize every idea.
+ */
+async function voteAndFinalize(s: Setup, yesOn: number[]) {
+  await warpToTimestamp(s.context, s.baseTime + 3600);
+  await s.program.methods
+    .transitionRound()
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      governanceConfig: s.governanceConfigPda,
+    })
+    .rpc();
+
+  for (const index of yesOn) {
+    const [voteRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote_record"),
+        s.voter.publicKey.toBuffer(),
+        s.ideaPdas[index].toBuffer(),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPdas[index],
+        round: s.ideaRoundPda,
+        voteRecord: voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+  }
+
+  await warpToTimestamp(s.context, s.baseTime + 7200);
+  await s.program.methods
+    .transitionRound()
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      governanceConfig: s.governanceConfigPda,
+    })
+    .rpc();
+
+  for (const ideaPda of s.ideaPdas) {
+    await s.program.methods
+      .finalizeIdea()
+      .accounts({
+        idea: ideaPda,
+        round: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+  }
+}
+
+/**
+ * Crank finalize_round_budget with `ideaPdas` (every idea by default),
+ * paid for by the provider wallet
+ */
+async function finalizeBudget(s: Setup, ideaPdas = s.ideaPdas) {
+  await s.program.methods
+    .finalizeRoundBudget()
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      rankingPayer: s.provider.wallet.publicKey,
+      cranker: s.provider.wallet.publicKey,
+    })
+    .remainingAccounts(
+      ideaPdas.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
+    .rpc();
+}
+
+/** Realm-wide floor for milestone min scores in these tests */
+const MIN_MILESTONE_SCORE = 5000;
+
+/**
+ * Fund idea 0's escrow after the execution timelock, register a single
+ * milestone covering its allocation (approved by the round authority unless
+ * `approve` is false) and submit an AI verification for it, written by a
+ * VERIFICATION_ORACLE unless `byOracle` is false (then by the config admin).
+ */
+async function fundWithMilestone(
+  s: Setup,
+  minScore: number,
+  score: number,
+  approve = true,
+  byOracle = true
+) {
+  await voteAndFinalize(s, [0]);
+  await finalizeBudget(s);
+  await warpToTimestamp(s.context, s.baseTime + 7200 + 120);
+
+  const [ideaEscrowPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("idea_escrow"), s.ideaPdas[0].toBuffer()],
+    PROGRAM_ID
+  );
+  await s.program.methods
+    .fundIdeaEscrow()
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      idea: s.ideaPdas[0],
+      budgetMint: s.mint,
+      roundBudgetEscrow: s.roundBudgetPda,
+      ideaEscrow: ideaEscrowPda,
+      cranker: s.admin,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const [verificationConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("verification_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await s.program.methods
+    .initVerificationConfig(
+      6000,
+      2500,
+      2000,
+      1500,
+      2500,
+      1500,
+      3,
+      7000,
+      7,
+      MIN_MILESTONE_SCORE
+    )
+    .accounts({
+      verificationConfig: verificationConfigPda,
+      governanceConfig: s.governanceConfigPda,
+      admin: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const taskRef = Array.from(new Uint8Array(32).fill(7)) as number[];
+  const [milestonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("idea_milestone"), s.ideaPdas[0].toBuffer(), Buffer.from([0])],
+    PROGRAM_ID
+  );
+  const [milestoneTaskPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("milestone_task"), s.ideaPdas[0].toBuffer(), Buffer.from(taskRef)],
+    PROGRAM_ID
+  );
+  const deadline = s.baseTime + 7200 + MILESTONE_WINDOW;
+  const addAccounts = {
+    round: s.ideaRoundPda,
+    idea: s.ideaPdas[0],
+    verificationConfig: verificationConfigPda,
+    milestone: milestonePda,
+    milestoneTask: milestoneTaskPda,
+    author: s.author.publicKey,
+    systemProgram: SystemProgram.programId,
+  };
+  await s.program.methods
+    .addIdeaMilestone(taskRef, new BN(600), minScore, new BN(deadline))
+    .accounts(addAccounts)
+    .signers([s.author])
+    .rpc();
+
+  // A second milestone may not reuse the task_ref
+  const [secondMilestonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("idea_milestone"), s.ideaPdas[0].toBuffer(), Buffer.from([1])],
+    PROGRAM_ID
+  );
+  try {
+    await s.program.methods
+      .addIdeaMilestone(taskRef, new BN(1), minScore, new BN(deadline))
+      .accounts({ ...addAccounts, milestone: secondMilestonePda })
+      .signers([s.author])
+      .rpc();
+    expect.fail("Expected the milestone task to already exist");
+  } catch (err: any) {
+    expect(err.toString()).to.not.include("Expected the milestone task");
+  }
+
+  if (approve) {
+    await s.program.methods
+      .approveIdeaMilestone()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        round: s.ideaRoundPda,
+        idea: s.ideaPdas[0],
+        milestone: milestonePda,
+        approver: s.admin,
+      })
+      .rpc();
+  }
+
+  // Only verifications submitted after the milestone release it
+  await warpToTimestamp(s.context, s.baseTime + 7200 + 180);
+
+  // The realm admin grants a separate key the VERIFICATION_ORACLE role
+  const oracle = Keypair.generate();
+  await airdrop(s.provider, oracle.publicKey);
+  await s.program.methods
+    .initRoleRegistry(s.admin)
+    .accounts({
+      roleRegistry: ROLE_REGISTRY_PDA,
+      programData: setUpgradeAuthority(s.context, s.admin),
+      authority: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+  await s.program.methods
+    .setOperatorRoles(REALM.publicKey, ROLE_VERIFICATION_ORACLE)
+    .accounts({
+      roleRegistry: ROLE_REGISTRY_PDA,
+      governanceConfig: s.governanceConfigPda,
+      operatorRole: operatorRolePda(oracle.publicKey),
+      holder: oracle.publicKey,
+      admin: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const verifier = byOracle ? oracle.publicKey : s.admin;
+  const [verificationReportPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("verification"),
+      REALM.publicKey.toBuffer(),
+      s.author.publicKey.toBuffer(),
+      Buffer.from(taskRef),
+    ],
+    PROGRAM_ID
+  );
+  await s.program.methods
+    .submitVerification(
+      taskRef,
+      { ai: {} },
+      score,
+      9000,
+      Array.from(new Uint8Array(32).fill(8)) as number[]
+    )
+    .accounts({
+      authority: verifier,
+      developer: s.author.publicKey,
+      verificationConfig: verificationConfigPda,
+      verificationReport: verificationReportPda,
+      systemProgram: SystemProgram.programId,
+      operatorRole: byOracle ? operatorRolePda(oracle.publicKey) : null,
+    })
+    .signers(byOracle ? [oracle] : [])
+    .rpc();
+
+  const releaseAccounts = {
+    round: s.ideaRoundPda,
+    idea: s.ideaPdas[0],
+    milestone: milestonePda,
+    verificationReport: verificationReportPda,
+    verifierRole: operatorRolePda(verifier),
+    ideaEscrow: ideaEscrowPda,
+    authorTokenAccount: s.authorTokenAccount,
+    tokenProgram: SPL_TOKEN_PROGRAM_ID,
+  };
+  const reclaimAccounts = {
+    round: s.ideaRoundPda,
+    idea: s.ideaPdas[0],
+    milestone: milestonePda,
+    ideaEscrow: ideaEscrowPda,
+    roundBudgetEscrow: s.roundBudgetPda,
+    tokenProgram: SPL_TOKEN_PROGRAM_ID,
+  };
+
+  return {
+    ideaEscrowPda,
+    milestonePda,
+    verificationConfigPda,
+    addAccounts,
+    deadline,
+    releaseAccounts,
+    reclaimAccounts,
+  };
+}
+
+describe("Budgeted Rounds", () => {
+  it("escrows the round budget from the treasury", async () => {
+    const s = await setupBudgetedRound();
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetAmount.toNumber()).to.equal(BUDGET);
+    expect(round.budgetMint.toBase58()).to.equal(s.mint.toBase58());
+
+    expect(Number(await getTokenBalance(s.provider, s.roundBudgetPda))).to.equal(
+      BUDGET
+    );
+    expect(
+      Number(await getTokenBalance(s.provider, s.treasuryTokenAccount))
+    ).to.equal(10_000 - BUDGET);
+  });
+
+  it("rejects a request larger than the round budget", async () => {
+    const s = await setupBudgetedRound();
+    const [ideaPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea"),
+        s.ideaRoundPda.toBuffer(),
+        Buffer.from(new Uint32Array([2]).buffer),
+      ],
+      PROGRAM_ID
+    );
+
+    try {
+      await s.program.methods
+        .submitIdea(
+          Array.from(new Uint8Array(32).fill(9)) as number[],
+          new BN(BUDGET + 1)
+        )
+        .accounts({
+          ideaRound: s.ideaRoundPda,
+          idea: ideaPda,
+          author: s.author.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.author])
+        .rpc();
+      expect.fail("Expected oversized request to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("RequestExceedsBudget");
+    }
+  });
+
+  it("allocates in rank order until the budget runs out", async () => {
+    const s = await setupBudgetedRound();
+    await voteAndFinalize(s, [0, 1]);
+    await finalizeBudget(s);
+
+    // Equal net weight: lower index ranks first, second request no longer fits
+    const first = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    const second = await s.program.account.idea.fetch(s.ideaPdas[1]);
+    expect(JSON.stringify(first.status)).to.equal(JSON.stringify({ approved: {} }));
+    expect(first.allocatedAmount.toNumber()).to.equal(600);
+    expect(second.allocatedAmount.toNumber()).to.equal(0);
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetFinalized).to.equal(true);
+    expect(round.budgetAllocated.toNumber()).to.equal(600);
+  });
+
+  it("finalizes the budget across several calls", async () => {
+    const s = await setupBudgetedRound();
+    await voteAndFinalize(s, [0, 1]);
+    const [budgetRankingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("budget_ranking"), s.ideaRoundPda.toBuffer()],
+      PROGRAM_ID
+    );
+
+    // Rank idea 0, then idea 1, which completes the ranking
+    await finalizeBudget(s, [s.ideaPdas[0]]);
+    await finalizeBudget(s, [s.ideaPdas[1]]);
+    const ranking = await s.program.account.budgetRanking.fetch(budgetRankingPda);
+    expect(ranking.rankingComplete).to.equal(true);
+    expect(ranking.fundedCount).to.equal(1);
+    let round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetFinalized).to.equal(false);
+
+    // Only the funded idea needs to come back for its allocation
+    await finalizeBudget(s, [s.ideaPdas[0]]);
+    const first = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    expect(first.allocatedAmount.toNumber()).to.equal(600);
+    round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetFinalized).to.equal(true);
+    expect(round.budgetAllocated.toNumber()).to.equal(600);
+    expect(await s.context.banksClient.getAccount(budgetRankingPda)).to.equal(null);
+  });
+
+  it("rejects budget finalization while ideas are undecided", async () => {
+    const s = await setupBudgetedRound();
+    await warpToTimestamp(s.context, s.baseTime + 3600);
+    await s.program.methods
+      .transitionRound()
+      .accounts({
+        ideaRound: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+    await warpToTimestamp(s.context, s.baseTime + 7200);
+    await s.program.methods
+      .transitionRound()
+      .accounts({
+        ideaRound: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+
+    try {
+      await finalizeBudget(s);
+      expect.fail("Expected finalization with undecided ideas to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("IdeaNotFinalized");
+    }
+  });
+
+  it("funds the idea escrow only after the execution timelock", async () => {
+    const s = await setupBudgetedRound();
+    await voteAndFinalize(s, [0]);
+    await finalizeBudget(s);
+
+    const [ideaEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("idea_escrow"), s.ideaPdas[0].toBuffer()],
+      PROGRAM_ID
+    );
+    const fundAccounts = {
+      ideaRound: s.ideaRoundPda,
+      idea: s.ideaPdas[0],
+      budgetMint: s.mint,
+      roundBudgetEscrow: s.roundBudgetPda,
+      ideaEscrow: ideaEscrowPda,
+      cranker: s.admin,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+
+    try {
+      await s.program.methods.fundIdeaEscrow().accounts(fundAccounts).rpc();
+      expect.fail("Expected funding inside the execution timelock to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ExecutionTimelockActive");
+    }
+
+    await warpToTimestamp(s.context, s.baseTime + 7200 + 120);
+    await s.program.methods.fundIdeaEscrow().accounts(fundAccounts).rpc();
+    expect(Number(await getTokenBalance(s.provider, ideaEscrowPda))).to.equal(600);
+
+    const idea = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    expect(idea.escrowFunded).to.equal(true);
+  });
+
+  it("releases a milestone once its verification meets the threshold", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 7000, 8000);
+
+    await s.program.methods.releaseMilestone().accounts(m.releaseAccounts).rpc();
+
+    expect(
+      Number(await getTokenBalance(s.provider, s.authorTokenAccount))
+    ).to.equal(600);
+    const milestone = await s.program.account.ideaMilestone.fetch(m.milestonePda);
+    expect(milestone.released).to.equal(true);
+    const idea = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    expect(idea.releasedAmount.toNumber()).to.equal(600);
+  });
+
+  it("rejects milestone release below the score threshold", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 9000, 8000);
+
+    try {
+      await s.program.methods.releaseMilestone().accounts(m.releaseAccounts).rpc();
+      expect.fail("Expected low-score release to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MilestoneScoreTooLow");
+    }
+  });
+
+  it("rejects milestone release on a report the config admin wrote", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 7000, 8000, true, false);
+
+    // The admin holds no VERIFICATION_ORACLE role in the round's realm
+    try {
+      await s.program.methods.releaseMilestone().accounts(m.releaseAccounts).rpc();
+      expect.fail("Expected release on an admin-written report to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("AccountNotInitialized");
+    }
+    expect(
+      Number(await getTokenBalance(s.provider, s.authorTokenAccount))
+    ).to.equal(0);
+  });
+
+  it("rejects milestone release until the milestone is approved", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 7000, 8000, false);
+
+    try {
+      await s.program.methods.releaseMilestone().accounts(m.releaseAccounts).rpc();
+      expect.fail("Expected release of an unapproved milestone to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MilestoneNotApproved");
+    }
+
+    // The idea author cannot approve their own milestone
+    try {
+      await s.program.methods
+        .approveIdeaMilestone()
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          round: s.ideaRoundPda,
+          idea: s.ideaPdas[0],
+          milestone: m.milestonePda,
+          approver: s.author.publicKey,
+        })
+        .signers([s.author])
+        .rpc();
+      expect.fail("Expected the author's approval to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedMilestoneApprover");
+    }
+  });
+
+  it("rejects a milestone min score below the verification floor", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 7000, 8000);
+    const taskRef = Array.from(new Uint8Array(32).fill(9)) as number[];
+    const [milestonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("idea_milestone"), s.ideaPdas[0].toBuffer(), Buffer.from([1])],
+      PROGRAM_ID
+    );
+    const [milestoneTaskPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("milestone_task"), s.ideaPdas[0].toBuffer(), Buffer.from(taskRef)],
+      PROGRAM_ID
+    );
+
+    try {
+      await s.program.methods
+        .addIdeaMilestone(taskRef, new BN(1), 0, new BN(m.deadline))
+        .accounts({
+          ...m.addAccounts,
+          milestone: milestonePda,
+          milestoneTask: milestoneTaskPda,
+        })
+        .signers([s.author])
+        .rpc();
+      expect.fail("Expected a zero min score to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MilestoneScoreBelowFloor");
+    }
+  });
+
+  it("returns an unmet milestone to the round budget after its deadline", async () => {
+    const s = await setupBudgetedRound();
+    const m = await fundWithMilestone(s, 9000, 8000);
+
+    try {
+      await s.program.methods.reclaimMilestone().accounts(m.reclaimAccounts).rpc();
+      expect.fail("Expected reclaim before the deadline to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MilestoneDeadlineNotReached");
+    }
+
+    await warpToTimestamp(s.context, m.deadline + 1);
+    await s.program.methods.reclaimMilestone().accounts(m.reclaimAccounts).rpc();
+
+    expect(Number(await getTokenBalance(s.provider, m.ideaEscrowPda))).to.equal(0);
+    expect(Number(await getTokenBalance(s.provider, s.roundBudgetPda))).to.equal(
+      BUDGET
+    );
+    const milestone = await s.program.account.ideaMilestone.fetch(m.milestonePda);
+    expect(milestone.reclaimed).to.equal(true);
+    const idea = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    expect(idea.allocatedAmount.toNumber()).to.equal(0);
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetAllocated.toNumber()).to.equal(0);
+    expect(round.budgetDisbursed.toNumber()).to.equal(0);
+
+    try {
+      await s.program.methods.releaseMilestone().accounts(m.releaseAccounts).rpc();
+      expect.fail("Expected release of a reclaimed milestone to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MilestoneReclaimed");
+    }
+  });
+
+  it("returns the unallocated budget to the treasury", async () => {
+    const s = await setupBudgetedRound();
+    await voteAndFinalize(s, [0]);
+    await finalizeBudget(s);
+
+    await s.program.methods
+      .reclaimRoundBudget()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        revenueConfig: s.revenueConfigPda,
+        ideaRound: s.ideaRoundPda,
+        roundBudgetEscrow: s.roundBudgetPda,
+        treasuryTokenAccount: s.treasuryTokenAccount,
+        admin: s.admin,
+        tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    // The 600 allocation stays escrowed for the approved idea
+    expect(Number(await getTokenBalance(s.provider, s.roundBudgetPda))).to.equal(600);
+    expect(
+      Number(await getTokenBalance(s.provider, s.treasuryTokenAccount))
+    ).to.equal(10_000 - 600);
+  });
+});
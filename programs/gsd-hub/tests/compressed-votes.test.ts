@@ -0,0 +1,796 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { MerkleTree } from "@solana/spl-account-compression";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+const SPL_ACCOUNT_COMPRESSION_ID = new PublicKey(
+  "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+);
+const SPL_NOOP_ID = new PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+);
+
+// Vote tree params: depth=3 (8 receipts max), buffer=8 -> 1304-byte account
+const MAX_DEPTH = 3;
+const MAX_BUFFER_SIZE = 8;
+const MERKLE_TREE_ACCOUNT_SIZE = 1304;
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token, compression and noop programs
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+      {
+        name: "spl_account_compression",
+        programId: SPL_ACCOUNT_COMPRESSION_ID,
+      },
+      {
+        name: "spl_noop",
+        programId: SPL_NOOP_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+ * Allocate a Merkle tree account owned by spl-account-compression
+ */
+async function allocateMerkleTree(
+  provider: BankrunProvider,
+  merkleTree: Keypair
+) {
+  const rent = await provider.connection.getMinimumBalanceForRentExemption(
+    MERKLE_TREE_ACCOUNT_SIZE
+  );
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: provider.wallet.publicKey,
+      newAccountPubkey: merkleTree.publicKey,
+      lamports: rent,
+      space: MERKLE_TREE_ACCOUNT_SIZE,
+      programId: SPL_ACCOUNT_COMPRESSION_ID,
+    })
+  );
+  tx.recentBlockhash = provider.context.lastBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, merkleTree);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Full governance setup: config + round + idea + voter deposit
+ */
+async function setupFullGovernance(opts?: { depositTimelock?: number }) {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  // Create governance token mint (admin is mint authority)
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  // Veto authority
+  const vetoAuthority = Keypair.generate();
+  await airdrop(provider, vetoAuthority.publicKey);
+
+  // Derive GovernanceConfig PDA
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  const depositTimelock = opts?.depositTimelock ?? 604800; // 7 days default
+
+  // Init governance config
+  await program.methods
+    .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  // Create escrow token account owned by governance_config PDA
+  const escrowKeypair = Keypair.generate();
+  const escrowTokenAccount = await createTokenAccount(
+    provider,
+    escrowKeypair,
+    mint,
+    governanceConfigPda
+  );
+
+  // Create a voter keypair, fund it, create a token account, mint tokens
+  const voter = Keypair.generate();
+  await airdrop(provider, voter.publicKey);
+
+  const voterTokenKeypair = Keypair.generate();
+  const voterTokenAccount = await createTokenAccount(
+    provider,
+    voterTokenKeypair,
+    mint,
+    voter.publicKey
+  );
+
+  // Mint 10_000_000 tokens to voter
+  await mintTo(
+    provider,
+    mint,
+    voterTokenAccount,
+    adminKeypair,
+    BigInt(10_000_000)
+  );
+
+  // Set a base time, then create the round with timelines accommodating 7-day timelock
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  // Round with long submission + voting periods:
+  // submission_end = base + 604800 + 2000 (7 days + 2000s buffer)
+  // voting_end = base + 604800 + 4000
+  const submissionStart = baseTime;
+  const submissionEnd = baseTime + depositTimelock + 2000;
+  const votingEnd = baseTime + depositTimelock + 4000;
+
+  const roundIndex = 0;
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([roundIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(submissionStart),
+      new BN(submissionEnd),
+      new BN(votingEnd),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .rpc();
+
+  // Submit an idea
+  const submitter = Keypair.generate();
+  await airdrop(provider, submitter.publicKey);
+
+  const ideaIndex = 0;
+  const [ideaPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea"),
+      ideaRoundPda.toBuffer(),
+      Buffer.from(new Uint32Array([ideaIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
+    .accounts({
+      ideaRound: ideaRoundPda,
+      idea: ideaPda,
+      author: submitter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([submitter])
+    .rpc();
+
+  // Derive VoteDeposit PDA for voter
+  const [voteDepositPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+
+  // Derive VoteRecord PDA for voter + idea
+  const [voteRecordPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vote_record"), voter.publicKey.toBuffer(), ideaPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    adminKeypair,
+    mint,
+    mintKeypair,
+    vetoAuthority,
+    governanceConfigPda,
+    escrowTokenAccount,
+    voter,
+    voterTokenAccount,
+    voteDepositPda,
+    voteRecordPda,
+    ideaRoundPda,
+    ideaPda,
+    submitter,
+    baseTime,
+    submissionEnd,
+    votingEnd,
+    depositTimelock,
+  };
+}
+
+/**
+ * Deposit voter tokens, attach a vote tree to the round, and move the round
+ * into its voting window past the deposit timelock.
+ */
+async function setupCompressedRound() {
+  const s = await setupFullGovernance();
+
+  await s.program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      voteDeposit: s.voteDepositPda,
+      depositor: s.voter.publicKey,
+      userTokenAccount: s.voterTokenAccount,
+      escrowTokenAccount: s.escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  const merkleTree = Keypair.generate();
+  await allocateMerkleTree(s.provider, merkleTree);
+
+  await s.program.methods
+    .initRoundVoteTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      ideaRound: s.ideaRoundPda,
+      merkleTree: merkleTree.publicKey,
+      admin: s.admin,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      noopProgram: SPL_NOOP_ID,
+    })
+    .rpc();
+
+  await warpToTimestamp(s.context, s.submissionEnd);
+  await s.program.methods
+    .transitionRound()
+    .accounts({ ideaRound: s.ideaRoundPda })
+    .rpc();
+  await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+
+  const [voteNullifierPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_nullifier"),
+      s.ideaRoundPda.toBuffer(),
+      s.voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+
+  return { ...s, merkleTree: merkleTree.publicKey, voteNullifierPda };
+}
+
+function castCompressedIx(
+  s: Awaited<ReturnType<typeof setupCompressedRound>>,
+  reasonHash: number[] | null = null
+) {
+  return s.program.methods
+    .castVoteCompressed({ yes: {} }, reasonHash)
+    .accounts({
+      idea: s.ideaPda,
+      round: s.ideaRoundPda,
+      voteNullifier: s.voteNullifierPda,
+      voteDeposit: s.voteDepositPda,
+      governanceConfig: s.governanceConfigPda,
+      gatewayToken: null,
+      delegateProfile: null,
+      merkleTree: s.merkleTree,
+      voter: s.voter.publicKey,
+      noopProgram: SPL_NOOP_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter]);
+}
+
+async function castCompressed(
+  s: Awaited<ReturnType<typeof setupCompressedRound>>
+) {
+  await castCompressedIx(s).rpc();
+}
+
+/**
+ * Helper: sha256 of a borsh-serialized VoteReceiptLeaf for a Yes vote
+ * without a rationale
+ */
+function yesReceiptHash(
+  s: Awaited<ReturnType<typeof setupCompressedRound>>,
+  weight: number,
+  votedAt: number
+) {
+  const data = Buffer.alloc(114);
+  s.voter.publicKey.toBuffer().copy(data, 0);
+  s.ideaPda.toBuffer().copy(data, 32);
+  s.ideaRoundPda.toBuffer().copy(data, 64);
+  data.writeUInt8(0, 96); // VoteChoice::Yes
+  data.writeBigUInt64LE(BigInt(weight), 97);
+  data.writeBigInt64LE(BigInt(votedAt), 105);
+  data.writeUInt8(0, 113); // reason_hash: None
+  return createHash("sha256").update(data).digest();
+}
+
+/**
+ * Raise the VOTING pause flag by writing the ["pause_config"] account
+ * directly, as if the guardian had paused voting.
+ */
+function pauseVoting(s: Awaited<ReturnType<typeof setupCompressedRound>>) {
+  const [pauseConfigPda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pause_config")],
+    PROGRAM_ID
+  );
+  const data = Buffer.alloc(50);
+  createHash("sha256").update("account:PauseConfig").digest().copy(data, 0, 0, 8);
+  data.writeUInt8(1 << 0, 40); // paused = VOTING
+  data.writeUInt8(bump, 49);
+  s.context.setAccount(pauseConfigPda, {
+    lamports: 1_000_000_000,
+    data,
+    owner: PROGRAM_ID,
+    executable: false,
+  });
+  return pauseConfigPda;
+}
+
+describe("Compressed Vote Receipts", () => {
+  it("records a vote as a compressed receipt", async () => {
+    const s = await setupCompressedRound();
+
+    await castCompressed(s);
+
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.yesWeight.toNumber()).to.equal(1_000_000);
+    expect(idea.voterCount).to.equal(1);
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.voteTree.toBase58()).to.equal(s.merkleTree.toBase58());
+    expect(round.voteReceiptCount.toNumber()).to.equal(1);
+
+    const nullifier = await s.program.account.voteNullifier.fetch(
+      s.voteNullifierPda
+    );
+    expect(nullifier.voteCount).to.equal(1);
+    expect(nullifier.voted[0]).to.equal(1);
+
+    const deposit = await s.program.account.voteDeposit.fetch(
+      s.voteDepositPda
+    );
+    expect(deposit.activeVotes).to.equal(1);
+  });
+
+  it("emits VoteCast with the rationale hash", async () => {
+    const s = await setupCompressedRound();
+    const reasonHash = Array.from(new Uint8Array(32).fill(7)) as number[];
+
+    const { events } = await castCompressedIx(s, reasonHash).simulate();
+    const voteCast = events.find((e) => e.name === "voteCast");
+    expect(voteCast).to.not.be.undefined;
+    expect(voteCast!.data.voter.toBase58()).to.equal(
+      s.voter.publicKey.toBase58()
+    );
+    expect(voteCast!.data.weight.toNumber()).to.equal(1_000_000);
+    expect(voteCast!.data.reasonHash).to.deep.equal(reasonHash);
+  });
+
+  it("rejects a second vote on the same idea", async () => {
+    const s = await setupCompressedRound();
+
+    await castCompressed(s);
+    try {
+      await castCompressed(s);
+      expect.fail("Expected double vote to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("AlreadyVoted");
+    }
+  });
+
+  it("rejects cast_vote on a round with a vote tree", async () => {
+    const s = await setupCompressedRound();
+
+    try {
+      await s.program.methods
+        .castVote({ yes: {} }, null)
+        .accounts({
+          idea: s.ideaPda,
+          round: s.ideaRoundPda,
+          voteRecord: s.voteRecordPda,
+          voteDeposit: s.voteDepositPda,
+          governanceConfig: s.governanceConfigPda,
+          gatewayToken: null,
+          delegateProfile: null,
+          voter: s.voter.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.voter])
+        .rpc();
+      expect.fail("Expected cast_vote to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("CompressedVotingEnabled");
+    }
+  });
+
+  it("relinquishes all compressed votes once the round closes", async () => {
+    const s = await setupCompressedRound();
+
+    await castCompressed(s);
+
+    await warpToTimestamp(s.context, s.votingEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: s.ideaRoundPda })
+      .rpc();
+
+    await s.program.methods
+      .relinquishCompressedVotes()
+      .accounts({
+        round: s.ideaRoundPda,
+        voteNullifier: s.voteNullifierPda,
+        voteDeposit: s.voteDepositPda,
+        voter: s.voter.publicKey,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    const deposit = await s.program.account.voteDeposit.fetch(
+      s.voteDepositPda
+    );
+    expect(deposit.activeVotes).to.equal(0);
+
+    const nullifier = await s.provider.connection.getAccountInfo(
+      s.voteNullifierPda
+    );
+    expect(nullifier).to.be.null;
+  });
+
+  it("lets anyone close a closed round's nullifier for the voter", async () => {
+    const s = await setupCompressedRound();
+
+    await castCompressed(s);
+
+    await warpToTimestamp(s.context, s.votingEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: s.ideaRoundPda })
+      .rpc();
+
+    // Signed by the test wallet, not the voter
+    await s.program.methods
+      .closeVoteNullifier()
+      .accounts({
+        round: s.ideaRoundPda,
+        voteNullifier: s.voteNullifierPda,
+        voteDeposit: s.voteDepositPda,
+        voter: s.voter.publicKey,
+      })
+      .rpc();
+
+    const deposit = await s.program.account.voteDeposit.fetch(
+      s.voteDepositPda
+    );
+    expect(deposit.activeVotes).to.equal(0);
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.openVoteAccounts).to.equal(0);
+
+    const nullifier = await s.provider.connection.getAccountInfo(
+      s.voteNullifierPda
+    );
+    expect(nullifier).to.be.null;
+  });
+
+  it("strips a compressed vote's weight while the protocol is paused", async () => {
+    const s = await setupCompressedRound();
+    const votedAt = s.baseTime + s.depositTimelock + 2500;
+
+    await castCompressed(s);
+    const pauseConfig = pauseVoting(s);
+
+    const tree = MerkleTree.sparseMerkleTreeFromLeaves(
+      [yesReceiptHash(s, 1_000_000, votedAt)],
+      MAX_DEPTH
+    );
+    const proof = tree.getProof(0);
+
+    await s.program.methods
+      .stripCompressedVote(
+        {
+          voter: s.voter.publicKey,
+          idea: s.ideaPda,
+          round: s.ideaRoundPda,
+          vote: { yes: {} },
+          weight: new BN(1_000_000),
+          votedAt: new BN(votedAt),
+          reasonHash: null,
+        },
+        Array.from(proof.root) as number[],
+        0
+      )
+      .accounts({
+        pauseConfig,
+        round: s.ideaRoundPda,
+        idea: s.ideaPda,
+        voteNullifier: s.voteNullifierPda,
+        voteDeposit: s.voteDepositPda,
+        merkleTree: s.merkleTree,
+        voter: s.voter.publicKey,
+        noopProgram: SPL_NOOP_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      })
+      .remainingAccounts(
+        proof.proof.map((node) => ({
+          pubkey: new PublicKey(node),
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
+      .signers([s.voter])
+      .rpc();
+
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.yesWeight.toNumber()).to.equal(0);
+    expect(idea.voterCount).to.equal(0);
+
+    const nullifier = await s.program.account.voteNullifier.fetch(
+      s.voteNullifierPda
+    );
+    expect(nullifier.voteCount).to.equal(0);
+    expect(nullifier.voted[0]).to.equal(1);
+
+    const deposit = await s.program.account.voteDeposit.fetch(
+      s.voteDepositPda
+    );
+    expect(deposit.activeVotes).to.equal(0);
+  });
+});
@@ -7,7 +7,12 @@ import {
   SystemProgram,
   Transaction,
 } from "@solana/web3.js";
+import {
+  ConcurrentMerkleTreeAccount,
+  MerkleTree,
+} from "@solana/spl-account-compression";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { GsdHub } from "../../../target/types/gsd_hub";
 
 const IDL = require("../../../target/idl/gsd_hub.json");
@@ -28,6 +33,19 @@ const MAX_BUFFER_SIZE = 8;
 // Formula from spl-account-compression: getConcurrentMerkleTreeAccountSize(3, 8)
 const MERKLE_TREE_ACCOUNT_SIZE = 1304;
 
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// OperatorRole bit for score updates
+const ROLE_SCORE_ORACLE = 1 << 3;
+// OperatorRole bit for appending to PDA-owned contribution trees
+const ROLE_TREE_WRITER = 1 << 5;
+
+// Per-update score limits installed by setScoreLimits
+const MAX_SCORE_INCREASE = 10_000_000;
+const MAX_TASKS_INCREASE = 10;
+
 /**
  * Helper: create bankrun context with compression + noop programs loaded
  */
@@ -76,6 +94,392 @@ async function allocateMerkleTree(
   await provider.context.banksClient.processTransaction(tx);
 }
 
+/**
+ * Helper: make `admin` the upgrade authority, install it as role registry
+ * admin and grant hub-wide `roles` to `holder`. Returns the holder's
+ * OperatorRole PDA.
+ */
+async function grantOperatorRoles(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  admin: PublicKey,
+  holder: PublicKey,
+  roles: number
+) {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const data = Buffer.alloc(45);
+  data.writeUInt32LE(3, 0);
+  data.writeUInt8(1, 12);
+  admin.toBuffer().copy(data, 13);
+  context.setAccount(programData, {
+    lamports: 1_000_000_000,
+    data,
+    owner: BPF_LOADER_UPGRADEABLE_ID,
+    executable: false,
+  });
+
+  const [roleRegistry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("role_registry")],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRoleRegistry(admin)
+    .accounts({
+      roleRegistry,
+      programData,
+      authority: admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const [operatorRole] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("operator_role"),
+      PublicKey.default.toBuffer(),
+      holder.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+  await program.methods
+    .setOperatorRoles(PublicKey.default, roles)
+    .accounts({
+      roleRegistry,
+      governanceConfig: null,
+      operatorRole,
+      holder,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return operatorRole;
+}
+
+/**
+ * Helper: make `merkleTree` the registry's active contribution tree,
+ * creating the registry (with the wallet as upgrade authority) on first use.
+ */
+async function activateContributionTree(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  merkleTree: PublicKey
+) {
+  const authority = (program.provider as BankrunProvider).wallet.publicKey;
+  const [treeRegistry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("contribution_tree_registry")],
+    PROGRAM_ID
+  );
+
+  if (!(await context.banksClient.getAccount(treeRegistry))) {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [PROGRAM_ID.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_ID
+    );
+    const data = Buffer.alloc(45);
+    data.writeUInt32LE(3, 0);
+    data.writeUInt8(1, 12);
+    authority.toBuffer().copy(data, 13);
+    context.setAccount(programData, {
+      lamports: 1_000_000_000,
+      data,
+      owner: BPF_LOADER_UPGRADEABLE_ID,
+      executable: false,
+    });
+
+    await program.methods
+      .initContributionTreeRegistry(authority)
+      .accounts({
+        treeRegistry,
+        programData,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  const [treeConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("contribution_tree"), merkleTree.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .rolloverContributionTree()
+    .accounts({
+      treeRegistry,
+      treeConfig,
+      merkleTree,
+      authority,
+    })
+    .rpc();
+
+  return treeRegistry;
+}
+
+/**
+ * Helper: make `authority` a score oracle and install per-update score
+ * limits with no decay realm.
+ */
+async function setupScoreConfig(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  authority: PublicKey
+) {
+  const operatorRole = await grantOperatorRoles(
+    context,
+    program,
+    authority,
+    authority,
+    ROLE_SCORE_ORACLE
+  );
+
+  const [roleRegistry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("role_registry")],
+    PROGRAM_ID
+  );
+  const [scoreConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("score_config")],
+    PROGRAM_ID
+  );
+  await program.methods
+    .setScoreLimits(
+      new BN(MAX_SCORE_INCREASE),
+      MAX_TASKS_INCREASE,
+      PublicKey.default // no realm decays scores in these tests
+    )
+    .accounts({
+      roleRegistry,
+      scoreConfig,
+      admin: authority,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return { operatorRole, roleRegistry, scoreConfig };
+}
+
+/**
+ * Helper: register `authority` as a developer, make it a score oracle and
+ * install per-update score limits.
+ */
+async function setupScoreOracle(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  authority: PublicKey
+) {
+  const [profilePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("developer"), authority.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .registerDeveloper(Array.from(new Uint8Array(32).fill(1)) as number[])
+    .accounts({
+      developerProfile: profilePda,
+      authority,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const { operatorRole, roleRegistry, scoreConfig } = await setupScoreConfig(
+    context,
+    program,
+    authority
+  );
+
+  return { profilePda, operatorRole, roleRegistry, scoreConfig };
+}
+
+/**
+ * Helper: apply a score update for `developer` signed by the provider
+ * wallet. Returns the ScoreAuditEntry PDA it created.
+ */
+async function updateScore(
+  program: Program<GsdHub>,
+  developer: PublicKey,
+  oracle: Awaited<ReturnType<typeof setupScoreOracle>>,
+  score: {
+    tasksCompleted: number;
+    totalVerificationScore: number;
+    timeActiveDays: number;
+    contributionScore: number;
+    scoreVersion: number;
+  }
+) {
+  const authority = program.provider.publicKey!;
+  const profile = await program.account.developerProfile.fetch(
+    oracle.profilePda
+  );
+  const sequence = Buffer.alloc(4);
+  sequence.writeUInt32LE(profile.scoreUpdateCount);
+  const [auditEntry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("score_audit"), developer.toBuffer(), sequence],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .updateContributionScore(
+      score.tasksCompleted,
+      new BN(score.totalVerificationScore),
+      score.timeActiveDays,
+      new BN(score.contributionScore),
+      score.scoreVersion
+    )
+    .accounts({
+      developerProfile: oracle.profilePda,
+      developerWallet: developer,
+      authority,
+      operatorRole: oracle.operatorRole,
+      scoreConfig: oracle.scoreConfig,
+      auditEntry,
+      payer: authority,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return auditEntry;
+}
+
+/**
+ * Helper: init a contribution tree and record one contribution into it.
+ * Returns the tree accounts and the leaf exactly as it was hashed on-chain.
+ */
+async function setupRecordedContribution(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  developer: PublicKey = Keypair.generate().publicKey,
+  pdaAuthority = true
+) {
+  const provider = program.provider as BankrunProvider;
+  const authority = provider.wallet.publicKey;
+
+  const merkleTree = Keypair.generate();
+  await allocateMerkleTree(
+    provider,
+    merkleTree,
+    authority,
+    MERKLE_TREE_ACCOUNT_SIZE
+  );
+  const [treeConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("contribution_tree"), merkleTree.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, pdaAuthority)
+    .accounts({
+      treeConfig,
+      merkleTree: merkleTree.publicKey,
+      authority,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      noopProgram: SPL_NOOP_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+  await activateContributionTree(context, program, merkleTree.publicKey);
+
+  const clock = await context.banksClient.getClock();
+  const leaf = {
+    developer,
+    taskRef: Array.from(Keypair.generate().publicKey.toBytes()) as number[],
+    verificationScore: 8500,
+    timestamp: new BN(clock.unixTimestamp.toString()),
+    contentHash: Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+  };
+
+  await program.methods
+    .recordContribution(
+      leaf.developer,
+      leaf.taskRef,
+      leaf.verificationScore,
+      leaf.contentHash,
+      null
+    )
+    .accounts({
+      treeConfig,
+      merkleTree: merkleTree.publicKey,
+      authority,
+      noopProgram: SPL_NOOP_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      operatorRole: null,
+    })
+    .rpc();
+
+  return { merkleTree: merkleTree.publicKey, treeConfig, leaf };
+}
+
+type RecordedLeaf = Awaited<
+  ReturnType<typeof setupRecordedContribution>
+>["leaf"];
+
+/**
+ * Helper: SHA-256 of the 106-byte Borsh layout of ContributionLeaf
+ */
+function contributionLeafHash(leaf: RecordedLeaf): Buffer {
+  const data = Buffer.alloc(106);
+  leaf.developer.toBuffer().copy(data, 0);
+  Buffer.from(leaf.taskRef).copy(data, 32);
+  data.writeUInt16LE(leaf.verificationScore, 64);
+  data.writeBigInt64LE(BigInt(leaf.timestamp.toString()), 66);
+  Buffer.from(leaf.contentHash).copy(data, 74);
+  return createHash("sha256").update(data).digest();
+}
+
+/**
+ * Helper: SHA-256 of the 145-byte Borsh layout of the ContributionLeafV2
+ * crediting `share` (leading version byte 2, the v1 fields with the
+ * author as developer, then kind, project_id, difficulty_weight,
+ * co_author_count and share_bps)
+ */
+function contributionLeafV2Hash(
+  leaf: RecordedLeaf,
+  details: {
+    kind: object;
+    projectId: number[];
+    difficultyWeight: number;
+    authors: { author: PublicKey; shareBps: number }[];
+  },
+  share = { author: leaf.developer, shareBps: 10000 }
+): Buffer {
+  const kinds = ["code", "review", "docs", "design"];
+  const data = Buffer.alloc(145);
+  data.writeUInt8(2, 0);
+  share.author.toBuffer().copy(data, 1);
+  Buffer.from(leaf.taskRef).copy(data, 33);
+  data.writeUInt16LE(leaf.verificationScore, 65);
+  data.writeBigInt64LE(BigInt(leaf.timestamp.toString()), 67);
+  Buffer.from(leaf.contentHash).copy(data, 75);
+  data.writeUInt8(kinds.indexOf(Object.keys(details.kind)[0]), 107);
+  Buffer.from(details.projectId).copy(data, 108);
+  data.writeUInt16LE(details.difficultyWeight, 140);
+  data.writeUInt8(Math.max(details.authors.length - 1, 0), 142);
+  data.writeUInt16LE(share.shareBps, 143);
+  return createHash("sha256").update(data).digest();
+}
+
+/**
+ * Helper: proof of `hashes[index]` in a tree holding exactly `hashes`
+ */
+function merkleProof(hashes: Buffer[], index: number) {
+  const tree = MerkleTree.sparseMerkleTreeFromLeaves(hashes, MAX_DEPTH);
+  const proof = tree.getProof(index);
+  return {
+    root: Array.from(proof.root) as number[],
+    proofAccounts: proof.proof.map((node) => ({
+      pubkey: new PublicKey(node),
+      isSigner: false,
+      isWritable: false,
+    })),
+  };
+}
+
+/**
+ * Helper: proof of `leaves[index]` in a tree holding exactly `leaves`,
+ * as root plus remaining accounts for verify_contribution
+ */
+function contributionProof(leaves: RecordedLeaf[], index: number) {
+  return merkleProof(leaves.map(contributionLeafHash), index);
+}
+
 describe("Contribution Tree", () => {
   it("initializes contribution tree", async () => {
     const context = await createContext();
@@ -101,7 +505,7 @@ describe("Contribution Tree", () => {
     );
 
     await program.methods
-      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
       .accounts({
         treeConfig,
         merkleTree: merkleTree.publicKey,
@@ -146,7 +550,7 @@ describe("Contribution Tree", () => {
 
     // Init tree first
     await program.methods
-      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
       .accounts({
         treeConfig,
         merkleTree: merkleTree.publicKey,
@@ -156,6 +560,7 @@ describe("Contribution Tree", () => {
         systemProgram: SystemProgram.programId,
       })
       .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
 
     // Record a contribution
     const developer = Keypair.generate().publicKey;
@@ -168,7 +573,8 @@ describe("Contribution Tree", () => {
         developer,
         Array.from(taskRef) as number[],
         verificationScore,
-        Array.from(contentHash) as number[]
+        Array.from(contentHash) as number[],
+        null
       )
       .accounts({
         treeConfig,
@@ -176,6 +582,7 @@ describe("Contribution Tree", () => {
         authority,
         noopProgram: SPL_NOOP_ID,
         compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        operatorRole: null,
       })
       .rpc();
 
@@ -186,6 +593,233 @@ describe("Contribution Tree", () => {
     expect(config.totalContributions.toNumber()).to.equal(1);
   });
 
+  it("records a batch of contributions with one count update", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const merkleTree = Keypair.generate();
+    await allocateMerkleTree(
+      provider,
+      merkleTree,
+      authority,
+      MERKLE_TREE_ACCOUNT_SIZE
+    );
+    const [treeConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("contribution_tree"), merkleTree.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        noopProgram: SPL_NOOP_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
+
+    const entry = (i: number) => ({
+      developer: Keypair.generate().publicKey,
+      taskRef: Array.from(new Uint8Array(32).fill(i)) as number[],
+      verificationScore: 7000 + i,
+      contentHash: Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+    });
+    const recordBatch = (count: number) =>
+      program.methods
+        .recordContributionsBatch(
+          Array.from({ length: count }, (_, i) => entry(i + 1))
+        )
+        .accounts({
+          treeConfig,
+          merkleTree: merkleTree.publicKey,
+          authority,
+          noopProgram: SPL_NOOP_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
+        })
+        .rpc();
+
+    await recordBatch(4);
+
+    const config = await program.account.contributionTreeConfig.fetch(
+      treeConfig
+    );
+    expect(config.totalContributions.toNumber()).to.equal(4);
+
+    // Empty and oversized (MAX_BATCH = 8) batches are refused
+    for (const count of [0, 9]) {
+      try {
+        await recordBatch(count);
+        expect.fail("Expected InvalidBatchSize error");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidBatchSize");
+      }
+    }
+  });
+
+  it("refuses a full tree and rolls over to a pre-allocated one", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const initTree = async () => {
+      const merkleTree = Keypair.generate();
+      await allocateMerkleTree(
+        provider,
+        merkleTree,
+        authority,
+        MERKLE_TREE_ACCOUNT_SIZE
+      );
+      const [treeConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("contribution_tree"), merkleTree.publicKey.toBuffer()],
+        PROGRAM_ID
+      );
+      await program.methods
+        .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
+        .accounts({
+          treeConfig,
+          merkleTree: merkleTree.publicKey,
+          authority,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          noopProgram: SPL_NOOP_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      return { merkleTree: merkleTree.publicKey, treeConfig };
+    };
+    const record = (tree: { merkleTree: PublicKey; treeConfig: PublicKey }) =>
+      program.methods
+        .recordContribution(
+          Keypair.generate().publicKey,
+          Array.from(Keypair.generate().publicKey.toBytes()) as number[],
+          8000,
+          Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+          null
+        )
+        .accounts({
+          treeConfig: tree.treeConfig,
+          merkleTree: tree.merkleTree,
+          authority,
+          noopProgram: SPL_NOOP_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
+        })
+        .rpc();
+
+    const first = await initTree();
+    const second = await initTree();
+    const treeRegistry = await activateContributionTree(
+      context,
+      program,
+      first.merkleTree
+    );
+
+    // A pre-allocated tree that is not active is refused
+    try {
+      await record(second);
+      expect.fail("Expected InactiveContributionTree error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InactiveContributionTree");
+    }
+
+    // Fill the first tree to its 2^MAX_DEPTH capacity
+    for (let i = 0; i < 2 ** MAX_DEPTH; i++) {
+      await record(first);
+    }
+    try {
+      await record(first);
+      expect.fail("Expected TreeFull error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("TreeFull");
+    }
+
+    await activateContributionTree(context, program, second.merkleTree);
+    await record(second);
+
+    const registry = await program.account.contributionTreeRegistry.fetch(
+      treeRegistry
+    );
+    expect(registry.activeTree.toBase58()).to.equal(
+      second.merkleTree.toBase58()
+    );
+    expect(registry.trees.map((t: any) => t.merkleTree.toBase58())).to.deep.equal(
+      [first.merkleTree.toBase58(), second.merkleTree.toBase58()]
+    );
+    expect(registry.trees[0].capacity.toNumber()).to.equal(2 ** MAX_DEPTH);
+
+    // A tree can only be registered once
+    try {
+      await activateContributionTree(context, program, first.merkleTree);
+      expect.fail("Expected TreeAlreadyRegistered error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("TreeAlreadyRegistered");
+    }
+  });
+
+  it("records a versioned v2 leaf next to a v1 leaf", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    // Leaf 0 is a v1 leaf recorded without details
+    const { merkleTree, treeConfig, leaf } = await setupRecordedContribution(
+      context,
+      program
+    );
+
+    const clock = await context.banksClient.getClock();
+    const v2Leaf = {
+      developer: Keypair.generate().publicKey,
+      taskRef: Array.from(Keypair.generate().publicKey.toBytes()) as number[],
+      verificationScore: 9100,
+      timestamp: new BN(clock.unixTimestamp.toString()),
+      contentHash: Array.from(new Uint8Array(32).fill(0xee)) as number[],
+    };
+    const details = {
+      kind: { review: {} },
+      projectId: Array.from(new Uint8Array(32).fill(0x42)) as number[],
+      difficultyWeight: 15000,
+      authors: [],
+    };
+    await program.methods
+      .recordContribution(
+        v2Leaf.developer,
+        v2Leaf.taskRef,
+        v2Leaf.verificationScore,
+        v2Leaf.contentHash,
+        details
+      )
+      .accounts({
+        treeConfig,
+        merkleTree,
+        authority,
+        noopProgram: SPL_NOOP_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        operatorRole: null,
+      })
+      .rpc();
+
+    // The on-chain root matches v1 hashing for leaf 0 and v2 hashing for leaf 1
+    const expected = MerkleTree.sparseMerkleTreeFromLeaves(
+      [contributionLeafHash(leaf), contributionLeafV2Hash(v2Leaf, details)],
+      MAX_DEPTH
+    );
+    const treeAccount = await context.banksClient.getAccount(merkleTree);
+    const onChain = ConcurrentMerkleTreeAccount.fromBuffer(
+      Buffer.from(treeAccount!.data)
+    );
+    expect(Buffer.from(onChain.getCurrentRoot())).to.deep.equal(
+      Buffer.from(expected.root)
+    );
+  });
+
   it("rejects contribution with invalid verification score", async () => {
     const context = await createContext();
     const provider = new BankrunProvider(context);
@@ -206,7 +840,7 @@ describe("Contribution Tree", () => {
     );
 
     await program.methods
-      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
       .accounts({
         treeConfig,
         merkleTree: merkleTree.publicKey,
@@ -216,6 +850,7 @@ describe("Contribution Tree", () => {
         systemProgram: SystemProgram.programId,
       })
       .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
 
     const developer = Keypair.generate().publicKey;
     const taskRef = new Uint8Array(32).fill(0xab);
@@ -228,7 +863,8 @@ describe("Contribution Tree", () => {
           developer,
           Array.from(taskRef) as number[],
           invalidScore,
-          Array.from(contentHash) as number[]
+          Array.from(contentHash) as number[],
+          null
         )
         .accounts({
           treeConfig,
@@ -236,6 +872,7 @@ describe("Contribution Tree", () => {
           authority,
           noopProgram: SPL_NOOP_ID,
           compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
         })
         .rpc();
       expect.fail("Expected invalid verification score to fail");
@@ -264,7 +901,7 @@ describe("Contribution Tree", () => {
     );
 
     await program.methods
-      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
       .accounts({
         treeConfig,
         merkleTree: merkleTree.publicKey,
@@ -274,6 +911,7 @@ describe("Contribution Tree", () => {
         systemProgram: SystemProgram.programId,
       })
       .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
 
     // Create a different signer (non-authority)
     const fakeAuthority = Keypair.generate();
@@ -289,7 +927,8 @@ describe("Contribution Tree", () => {
           developer,
           Array.from(taskRef) as number[],
           verificationScore,
-          Array.from(contentHash) as number[]
+          Array.from(contentHash) as number[],
+          null
         )
         .accounts({
           treeConfig,
@@ -297,6 +936,7 @@ describe("Contribution Tree", () => {
           authority: fakeAuthority.publicKey,
           noopProgram: SPL_NOOP_ID,
           compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
         })
         .signers([fakeAuthority])
         .rpc();
@@ -307,62 +947,1017 @@ describe("Contribution Tree", () => {
     }
   });
 
-  it("updates developer contribution score", async () => {
+  it("verifies a recorded contribution and attests it", async () => {
     const context = await createContext();
     const provider = new BankrunProvider(context);
     const program = new Program<GsdHub>(IDL as GsdHub, provider);
-    const authority = provider.wallet.publicKey;
+    const { merkleTree, treeConfig, leaf } = await setupRecordedContribution(
+      context,
+      program
+    );
+    const { root, proofAccounts } = contributionProof([leaf], 0);
 
-    // First register a developer profile
-    const [profilePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("developer"), authority.toBuffer()],
+    const [attestation] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_attestation"),
+        merkleTree.toBuffer(),
+        leaf.developer.toBuffer(),
+        Buffer.from(leaf.taskRef),
+      ],
       PROGRAM_ID
     );
 
-    const profileHash = new Uint8Array(32).fill(1);
     await program.methods
-      .registerDeveloper(Array.from(profileHash) as number[])
+      .verifyContribution({ v1: { leaf } }, root, 0)
       .accounts({
-        developerProfile: profilePda,
-        authority,
+        treeConfig,
+        merkleTree,
+        attestation,
+        payer: provider.wallet.publicKey,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
         systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts(proofAccounts)
       .rpc();
 
-    // Now update the contribution score
-    const tasksCompleted = 5;
-    const totalVerificationScore = 42500; // 5 tasks * avg 8500
-    const timeActiveDays = 30;
-    const contributionScore = 1_500_000; // scaled by 1e6
-    const scoreVersion = 1;
+    const record = await program.account.contributionAttestation.fetch(
+      attestation
+    );
+    expect(record.developer.toBase58()).to.equal(leaf.developer.toBase58());
+    expect(record.taskRef).to.deep.equal(leaf.taskRef);
+    expect(record.merkleTree.toBase58()).to.equal(merkleTree.toBase58());
+    expect(record.leafIndex).to.equal(0);
+    expect(Buffer.from(record.leafHash)).to.deep.equal(
+      contributionLeafHash(leaf)
+    );
+    expect(record.verificationScore).to.equal(leaf.verificationScore);
+    expect(record.contributedAt.toString()).to.equal(leaf.timestamp.toString());
 
-    await program.methods
-      .updateContributionScore(
-        tasksCompleted,
-        new BN(totalVerificationScore),
-        timeActiveDays,
-        new BN(contributionScore),
-        scoreVersion
-      )
-      .accounts({
-        developerProfile: profilePda,
-        developerWallet: authority,
-        authority,
-        payer: authority,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
+    // Leaves in a wallet-owned tree never went through record_contribution
+    const walletOwned = await setupRecordedContribution(
+      context,
+      program,
+      leaf.developer,
+      false
+    );
+    const walletProof = contributionProof([walletOwned.leaf], 0);
+    try {
+      await program.methods
+        .verifyContribution(
+          { v1: { leaf: walletOwned.leaf } },
+          walletProof.root,
+          0
+        )
+        .accounts({
+          treeConfig: walletOwned.treeConfig,
+          merkleTree: walletOwned.merkleTree,
+          attestation: null,
+          payer: provider.wallet.publicKey,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(walletProof.proofAccounts)
+        .rpc();
+      expect.fail("Expected UntrustedContributionTree error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UntrustedContributionTree");
+    }
+  });
 
-    // Verify score fields
-    const profile = await program.account.developerProfile.fetch(profilePda);
-    expect(profile.tasksCompleted).to.equal(tasksCompleted);
-    expect(profile.totalVerificationScore.toNumber()).to.equal(
-      totalVerificationScore
+  it("rejects a proof for a tampered contribution leaf", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const { merkleTree, treeConfig, leaf } = await setupRecordedContribution(
+      context,
+      program
+    );
+    const { root, proofAccounts } = contributionProof([leaf], 0);
+
+    const inflated = { ...leaf, verificationScore: 10_000 };
+
+    try {
+      await program.methods
+        .verifyContribution({ v1: { leaf: inflated } }, root, 0)
+        .accounts({
+          treeConfig,
+          merkleTree,
+          attestation: null,
+          payer: provider.wallet.publicKey,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(proofAccounts)
+        .rpc();
+      expect.fail("Expected proof verification to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.not.include("Expected proof verification");
+    }
+  });
+
+  it("amends and then revokes a recorded contribution", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const [profilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("developer"), authority.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .registerDeveloper(Array.from(new Uint8Array(32).fill(1)) as number[])
+      .accounts({
+        developerProfile: profilePda,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await setupScoreConfig(context, program, authority);
+
+    const { merkleTree, treeConfig, leaf } = await setupRecordedContribution(
+      context,
+      program,
+      authority
+    );
+    const [nullifier] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_nullifier"),
+        authority.toBuffer(),
+        Buffer.from(leaf.taskRef),
+      ],
+      PROGRAM_ID
+    );
+    const [attestation] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_attestation"),
+        merkleTree.toBuffer(),
+        authority.toBuffer(),
+        Buffer.from(leaf.taskRef),
+      ],
+      PROGRAM_ID
+    );
+    const accounts = {
+      treeConfig,
+      merkleTree,
+      authority,
+      nullifier,
+      attestation,
+      attestationPayer: authority,
+      developerProfile: profilePda,
+      governanceConfig: null,
+      noopProgram: SPL_NOOP_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+    };
+
+    // Credit the original leaf at 8500
+    let { root, proofAccounts } = contributionProof([leaf], 0);
+    await program.methods
+      .applyContributionProof(leaf, root, 0)
+      .accounts({
+        treeConfig,
+        merkleTree,
+        developerProfile: profilePda,
+        nullifier,
+        governanceConfig: null,
+        payer: authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    // Amendments may not move a contribution to another developer or task
+    try {
+      await program.methods
+        .amendContribution(
+          { v1: { leaf } },
+          {
+            developer: Keypair.generate().publicKey,
+            taskRef: leaf.taskRef,
+            verificationScore: 9000,
+            contentHash: leaf.contentHash,
+          },
+          root,
+          0
+        )
+        .accounts(accounts)
+        .remainingAccounts(proofAccounts)
+        .rpc();
+      expect.fail("Expected ImmutableContributionKey error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ImmutableContributionKey");
+    }
+
+    // Amend the score; the original timestamp is kept and the credit follows
+    const amended = { ...leaf, verificationScore: 6000 };
+    await program.methods
+      .amendContribution(
+        { v1: { leaf } },
+        {
+          developer: amended.developer,
+          taskRef: amended.taskRef,
+          verificationScore: amended.verificationScore,
+          contentHash: amended.contentHash,
+        },
+        root,
+        0
+      )
+      .accounts(accounts)
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    let profile = await program.account.developerProfile.fetch(profilePda);
+    expect(profile.tasksCompleted).to.equal(1);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(6000);
+
+    // The old leaf no longer proves; the amended one does and is attested
+    ({ root, proofAccounts } = contributionProof([amended], 0));
+    await program.methods
+      .verifyContribution({ v1: { leaf: amended } }, root, 0)
+      .accounts({
+        treeConfig,
+        merkleTree,
+        attestation,
+        payer: authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    await program.methods
+      .revokeContribution({ v1: { leaf: amended } }, root, 0)
+      .accounts(accounts)
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    // Revoking takes the credit back; the nullifier keeps the task counted
+    profile = await program.account.developerProfile.fetch(profilePda);
+    expect(profile.tasksCompleted).to.equal(0);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(0);
+    expect(profile.contributionScore.toNumber()).to.equal(0);
+
+    // ...and closes the attestation, so the leaf no longer reads as proven
+    expect(await context.banksClient.getAccount(attestation)).to.equal(null);
+
+    // The tombstone is in the tree but can neither be verified nor credited
+    const tombstone = {
+      ...amended,
+      verificationScore: 0,
+      contentHash: Array.from(new Uint8Array(32)) as number[],
+    };
+    ({ root, proofAccounts } = contributionProof([tombstone], 0));
+    try {
+      await program.methods
+        .verifyContribution({ v1: { leaf: tombstone } }, root, 0)
+        .accounts({
+          treeConfig,
+          merkleTree,
+          attestation: null,
+          payer: authority,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(proofAccounts)
+        .rpc();
+      expect.fail("Expected ContributionRevoked error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ContributionRevoked");
+    }
+
+    // Only the tree authority may rewrite leaves
+    const stranger = Keypair.generate();
+    ({ root, proofAccounts } = contributionProof([tombstone], 0));
+    try {
+      await program.methods
+        .amendContribution(
+          { v1: { leaf: tombstone } },
+          {
+            developer: leaf.developer,
+            taskRef: leaf.taskRef,
+            verificationScore: 10000,
+            contentHash: leaf.contentHash,
+          },
+          root,
+          0
+        )
+        .accounts({ ...accounts, authority: stranger.publicKey })
+        .remainingAccounts(proofAccounts)
+        .signers([stranger])
+        .rpc();
+      expect.fail("Expected InvalidTreeAuthority error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidTreeAuthority");
+    }
+  });
+
+  it("credits a proven contribution and recomputes the score", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const developer = provider.wallet.publicKey;
+
+    const [profilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("developer"), developer.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .registerDeveloper(Array.from(new Uint8Array(32).fill(1)) as number[])
+      .accounts({
+        developerProfile: profilePda,
+        authority: developer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const scoreSetup = await setupScoreConfig(context, program, developer);
+    const { merkleTree, treeConfig, leaf } = await setupRecordedContribution(
+      context,
+      program,
+      developer
+    );
+    const { root, proofAccounts } = contributionProof([leaf], 0);
+    const [nullifier] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_nullifier"),
+        developer.toBuffer(),
+        Buffer.from(leaf.taskRef),
+      ],
+      PROGRAM_ID
+    );
+
+    const applyProof = () =>
+      program.methods
+        .applyContributionProof(leaf, root, 0)
+        .accounts({
+          treeConfig,
+          merkleTree,
+          developerProfile: profilePda,
+          nullifier,
+          governanceConfig: null,
+          payer: developer,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(proofAccounts)
+        .rpc();
+
+    await applyProof();
+
+    // One task at 8500 over one day: isqrt(1e6) * 8500 * isqrt(1e6) / 1e6
+    const profile = await program.account.developerProfile.fetch(profilePda);
+    expect(profile.tasksCompleted).to.equal(1);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(8500);
+    expect(profile.timeActiveDays).to.equal(1);
+    expect(profile.contributionScore.toNumber()).to.equal(8500);
+    expect(profile.firstContributionAt.toString()).to.equal(
+      leaf.timestamp.toString()
+    );
+
+    // The nullifier stops the same leaf from being counted again
+    try {
+      await applyProof();
+      expect.fail("Expected the nullifier to already exist");
+    } catch (err: any) {
+      expect(err.toString()).to.not.include("Expected the nullifier");
+    }
+    const after = await program.account.developerProfile.fetch(profilePda);
+    expect(after.tasksCompleted).to.equal(1);
+    expect(after.provenAt.toNumber()).to.be.greaterThan(0);
+
+    // Absolute oracle totals would wipe the proven credit
+    try {
+      await updateScore(program, developer, { profilePda, ...scoreSetup }, {
+        tasksCompleted: 0,
+        totalVerificationScore: 0,
+        timeActiveDays: 1,
+        contributionScore: 0,
+        scoreVersion: 1,
+      });
+      expect.fail("Expected ProfileScoredByProofs error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ProfileScoredByProofs");
+    }
+
+    // A wallet-owned tree could hold leaves appended outside the program
+    const walletOwned = await setupRecordedContribution(
+      context,
+      program,
+      developer,
+      false
+    );
+    const walletProof = contributionProof([walletOwned.leaf], 0);
+    const [walletNullifier] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_nullifier"),
+        developer.toBuffer(),
+        Buffer.from(walletOwned.leaf.taskRef),
+      ],
+      PROGRAM_ID
+    );
+    try {
+      await program.methods
+        .applyContributionProof(walletOwned.leaf, walletProof.root, 0)
+        .accounts({
+          treeConfig: walletOwned.treeConfig,
+          merkleTree: walletOwned.merkleTree,
+          developerProfile: profilePda,
+          nullifier: walletNullifier,
+          governanceConfig: null,
+          payer: developer,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(walletProof.proofAccounts)
+        .rpc();
+      expect.fail("Expected UntrustedContributionTree error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UntrustedContributionTree");
+    }
+  });
+
+  it("splits a co-authored contribution into per-author leaves", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const lead = provider.wallet.publicKey;
+    const coAuthor = Keypair.generate().publicKey;
+
+    const [profilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("developer"), lead.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .registerDeveloper(Array.from(new Uint8Array(32).fill(1)) as number[])
+      .accounts({
+        developerProfile: profilePda,
+        authority: lead,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await setupScoreConfig(context, program, lead);
+    // Leaf 0 is a solo v1 leaf; the co-authored contribution fills leaves 1 and 2
+    const { merkleTree, treeConfig, leaf: soloLeaf } =
+      await setupRecordedContribution(context, program);
+
+    const clock = await context.banksClient.getClock();
+    const leaf = {
+      developer: lead,
+      taskRef: Array.from(Keypair.generate().publicKey.toBytes()) as number[],
+      verificationScore: 8500,
+      timestamp: new BN(clock.unixTimestamp.toString()),
+      contentHash: Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+    };
+    const details = (authors: { author: PublicKey; shareBps: number }[]) => ({
+      kind: { code: {} },
+      projectId: Array.from(new Uint8Array(32).fill(0x42)) as number[],
+      difficultyWeight: 10000,
+      authors,
+    });
+    const record = (authors: { author: PublicKey; shareBps: number }[]) =>
+      program.methods
+        .recordContribution(
+          leaf.developer,
+          leaf.taskRef,
+          leaf.verificationScore,
+          leaf.contentHash,
+          details(authors)
+        )
+        .accounts({
+          treeConfig,
+          merkleTree,
+          authority: lead,
+          noopProgram: SPL_NOOP_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
+        })
+        .rpc();
+
+    // Shares must sum to 10000 and list the lead developer first
+    for (const authors of [
+      [
+        { author: lead, shareBps: 6000 },
+        { author: coAuthor, shareBps: 3000 },
+      ],
+      [
+        { author: coAuthor, shareBps: 3000 },
+        { author: lead, shareBps: 7000 },
+      ],
+    ]) {
+      try {
+        await record(authors);
+        expect.fail("Expected InvalidAuthorShares error");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidAuthorShares");
+      }
+    }
+
+    const authors = [
+      { author: lead, shareBps: 7000 },
+      { author: coAuthor, shareBps: 3000 },
+    ];
+    await record(authors);
+
+    const config = await program.account.contributionTreeConfig.fetch(
+      treeConfig
+    );
+    expect(config.totalContributions.toNumber()).to.equal(3);
+
+    // The lead's proven leaf credits only its 70% of the verification score
+    const hashes = [
+      contributionLeafHash(soloLeaf),
+      contributionLeafV2Hash(leaf, details(authors), authors[0]),
+      contributionLeafV2Hash(leaf, details(authors), authors[1]),
+    ];
+    const { root, proofAccounts } = merkleProof(hashes, 1);
+    const [nullifier] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_nullifier"),
+        lead.toBuffer(),
+        Buffer.from(leaf.taskRef),
+      ],
+      PROGRAM_ID
+    );
+    const leadLeaf = {
+      version: 2,
+      ...leaf,
+      kind: { code: {} },
+      projectId: details(authors).projectId,
+      difficultyWeight: 10000,
+      coAuthorCount: 1,
+      shareBps: 7000,
+    };
+    await program.methods
+      .applyContributionProofV2(leadLeaf, root, 1)
+      .accounts({
+        treeConfig,
+        merkleTree,
+        developerProfile: profilePda,
+        nullifier,
+        governanceConfig: null,
+        payer: lead,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    const profile = await program.account.developerProfile.fetch(profilePda);
+    expect(profile.tasksCompleted).to.equal(1);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(5950);
+
+    // Revoking the lead's v2 leaf writes a v2 tombstone and takes back its share
+    await program.methods
+      .revokeContribution({ v2: { leaf: leadLeaf } }, root, 1)
+      .accounts({
+        treeConfig,
+        merkleTree,
+        authority: lead,
+        nullifier,
+        attestation: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("contribution_attestation"),
+            merkleTree.toBuffer(),
+            leadLeaf.developer.toBuffer(),
+            Buffer.from(leadLeaf.taskRef),
+          ],
+          PROGRAM_ID
+        )[0],
+        attestationPayer: null,
+        developerProfile: profilePda,
+        governanceConfig: null,
+        noopProgram: SPL_NOOP_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      })
+      .remainingAccounts(proofAccounts)
+      .rpc();
+
+    const revoked = await program.account.developerProfile.fetch(profilePda);
+    expect(revoked.tasksCompleted).to.equal(0);
+    expect(revoked.totalVerificationScore.toNumber()).to.equal(0);
+  });
+
+  it("updates developer contribution score", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+    const oracle = await setupScoreOracle(context, program, authority);
+
+    // Now update the contribution score
+    const tasksCompleted = 5;
+    const totalVerificationScore = 42500; // 5 tasks * avg 8500
+    const timeActiveDays = 1; // the profile was registered today
+    const contributionScore = 1_500_000; // scaled by 1e6
+    const scoreVersion = 1;
+
+    const auditEntry = await updateScore(program, authority, oracle, {
+      tasksCompleted,
+      totalVerificationScore,
+      timeActiveDays,
+      contributionScore,
+      scoreVersion,
+    });
+
+    // Verify score fields
+    const profile = await program.account.developerProfile.fetch(
+      oracle.profilePda
+    );
+    expect(profile.tasksCompleted).to.equal(tasksCompleted);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(
+      totalVerificationScore
     );
     expect(profile.timeActiveDays).to.equal(timeActiveDays);
     expect(profile.contributionScore.toNumber()).to.equal(contributionScore);
     expect(profile.scoreVersion).to.equal(scoreVersion);
     expect(profile.firstContributionAt.toNumber()).to.be.greaterThan(0);
     expect(profile.lastContributionAt.toNumber()).to.be.greaterThan(0);
+    expect(profile.scoreUpdateCount).to.equal(1);
+
+    // Verify the audit record
+    const audit = await program.account.scoreAuditEntry.fetch(auditEntry);
+    expect(audit.developer.toBase58()).to.equal(authority.toBase58());
+    expect(audit.sequence).to.equal(0);
+    expect(audit.oracle.toBase58()).to.equal(authority.toBase58());
+    expect(audit.previousScore.toNumber()).to.equal(0);
+    expect(audit.newScore.toNumber()).to.equal(contributionScore);
+    expect(audit.previousTasks).to.equal(0);
+    expect(audit.newTasks).to.equal(tasksCompleted);
+    expect(audit.newVersion).to.equal(scoreVersion);
+  });
+
+  it("rejects score updates from a wallet without the score oracle role", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+    const oracle = await setupScoreOracle(context, program, authority);
+
+    // Re-grant the wallet the other hub-wide role only
+    await program.methods
+      .setOperatorRoles(PublicKey.default, ROLE_TREE_WRITER)
+      .accounts({
+        roleRegistry: oracle.roleRegistry,
+        governanceConfig: null,
+        operatorRole: oracle.operatorRole,
+        holder: authority,
+        admin: authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await updateScore(program, authority, oracle, {
+        tasksCompleted: 1,
+        totalVerificationScore: 8500,
+        timeActiveDays: 1,
+        contributionScore: 1000,
+        scoreVersion: 1,
+      });
+      expect.fail("Expected MissingOperatorRole error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MissingOperatorRole");
+    }
+  });
+
+  it("caps the score increase of a single update", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+    const oracle = await setupScoreOracle(context, program, authority);
+
+    try {
+      await updateScore(program, authority, oracle, {
+        tasksCompleted: 1,
+        totalVerificationScore: 8500,
+        timeActiveDays: 1,
+        contributionScore: MAX_SCORE_INCREASE + 1,
+        scoreVersion: 1,
+      });
+      expect.fail("Expected ScoreIncreaseTooLarge error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ScoreIncreaseTooLarge");
+    }
+
+    try {
+      await updateScore(program, authority, oracle, {
+        tasksCompleted: MAX_TASKS_INCREASE + 1,
+        totalVerificationScore: 8500,
+        timeActiveDays: 1,
+        contributionScore: 1000,
+        scoreVersion: 1,
+      });
+      expect.fail("Expected TaskIncreaseTooLarge error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("TaskIncreaseTooLarge");
+    }
+  });
+
+  it("rate-limits oracle updates and bounds every score field", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+    const oracle = await setupScoreOracle(context, program, authority);
+
+    const expectUpdateError = async (
+      score: Parameters<typeof updateScore>[3],
+      error: string
+    ) => {
+      try {
+        await updateScore(program, authority, oracle, score);
+        expect.fail(`Expected ${error} error`);
+      } catch (err: any) {
+        expect(err.toString()).to.include(error);
+      }
+    };
+
+    // One task cannot carry more than 10000 of verification score
+    await expectUpdateError(
+      {
+        tasksCompleted: 1,
+        totalVerificationScore: 10_001,
+        timeActiveDays: 1,
+        contributionScore: 1000,
+        scoreVersion: 1,
+      },
+      "VerificationTotalTooLarge"
+    );
+    // A profile registered today cannot claim a month of activity
+    await expectUpdateError(
+      {
+        tasksCompleted: 1,
+        totalVerificationScore: 8500,
+        timeActiveDays: 30,
+        contributionScore: 1000,
+        scoreVersion: 1,
+      },
+      "ActiveDaysTooLarge"
+    );
+
+    await updateScore(program, authority, oracle, {
+      tasksCompleted: 1,
+      totalVerificationScore: 8500,
+      timeActiveDays: 1,
+      contributionScore: 1000,
+      scoreVersion: 1,
+    });
+
+    // The per-update caps cannot be stacked back to back
+    await expectUpdateError(
+      {
+        tasksCompleted: 2,
+        totalVerificationScore: 17_000,
+        timeActiveDays: 1,
+        contributionScore: 2000,
+        scoreVersion: 1,
+      },
+      "ScoreUpdateTooSoon"
+    );
+  });
+
+  it("rejects a score version going backwards", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+    const oracle = await setupScoreOracle(context, program, authority);
+
+    await updateScore(program, authority, oracle, {
+      tasksCompleted: 1,
+      totalVerificationScore: 8500,
+      timeActiveDays: 1,
+      contributionScore: 1000,
+      scoreVersion: 2,
+    });
+
+    try {
+      await updateScore(program, authority, oracle, {
+        tasksCompleted: 2,
+        totalVerificationScore: 17000,
+        timeActiveDays: 2,
+        contributionScore: 2000,
+        scoreVersion: 1,
+      });
+      expect.fail("Expected ScoreVersionRegressed error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ScoreVersionRegressed");
+    }
+  });
+
+  it("rotates allowlisted writers on a PDA-owned tree", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const merkleTree = Keypair.generate();
+    await allocateMerkleTree(
+      provider,
+      merkleTree,
+      authority,
+      MERKLE_TREE_ACCOUNT_SIZE
+    );
+    const [treeConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("contribution_tree"), merkleTree.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, true)
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        noopProgram: SPL_NOOP_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
+
+    const setWriters = (writers: PublicKey[]) =>
+      program.methods
+        .setTreeWriters(writers)
+        .accounts({
+          treeConfig,
+          merkleTree: merkleTree.publicKey,
+          authority,
+        })
+        .rpc();
+    const recordAs = (writer: Keypair) =>
+      program.methods
+        .recordContribution(
+          Keypair.generate().publicKey,
+          Array.from(Keypair.generate().publicKey.toBytes()) as number[],
+          7500,
+          Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+          null
+        )
+        .accounts({
+          treeConfig,
+          merkleTree: merkleTree.publicKey,
+          authority: writer.publicKey,
+          noopProgram: SPL_NOOP_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+          operatorRole: null,
+        })
+        .signers([writer])
+        .rpc();
+
+    const oldBackend = Keypair.generate();
+    const newBackend = Keypair.generate();
+
+    // The tree authority itself appends through the PDA
+    await program.methods
+      .recordContribution(
+        Keypair.generate().publicKey,
+        Array.from(new Uint8Array(32).fill(0x01)) as number[],
+        9000,
+        Array.from(new Uint8Array(32).fill(0xcd)) as number[],
+        null
+      )
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority,
+        noopProgram: SPL_NOOP_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        operatorRole: null,
+      })
+      .rpc();
+
+    await setWriters([oldBackend.publicKey]);
+    await recordAs(oldBackend);
+
+    // Rotating the allowlist cuts off the lost key and admits its successor
+    await setWriters([newBackend.publicKey]);
+    try {
+      await recordAs(oldBackend);
+      expect.fail("Expected InvalidTreeAuthority error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidTreeAuthority");
+    }
+    await recordAs(newBackend);
+
+    const config = await program.account.contributionTreeConfig.fetch(
+      treeConfig
+    );
+    expect(config.pdaAuthority).to.equal(true);
+    expect(config.totalContributions.toNumber()).to.equal(3);
+
+    try {
+      await setWriters(
+        Array.from({ length: 9 }, () => Keypair.generate().publicKey)
+      );
+      expect.fail("Expected TooManyTreeWriters error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("TooManyTreeWriters");
+    }
+  });
+
+  it("hands the tree to a new authority in two steps", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const merkleTree = Keypair.generate();
+    await allocateMerkleTree(
+      provider,
+      merkleTree,
+      authority,
+      MERKLE_TREE_ACCOUNT_SIZE
+    );
+
+    const [treeConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("contribution_tree"), merkleTree.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .initContributionTree(MAX_DEPTH, MAX_BUFFER_SIZE, false)
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        noopProgram: SPL_NOOP_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await activateContributionTree(context, program, merkleTree.publicKey);
+
+    const newAuthority = Keypair.generate();
+    await program.methods
+      .proposeTreeAuthority(newAuthority.publicKey, null)
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      })
+      .rpc();
+
+    // A stranger cannot claim the pending handover
+    const stranger = Keypair.generate();
+    try {
+      await program.methods
+        .acceptTreeAuthority()
+        .accounts({
+          treeConfig,
+          merkleTree: merkleTree.publicKey,
+          newAuthority: stranger.publicKey,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        })
+        .signers([stranger])
+        .rpc();
+      expect.fail("Expected NotPendingAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("NotPendingAdmin");
+    }
+
+    await program.methods
+      .acceptTreeAuthority()
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        newAuthority: newAuthority.publicKey,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      })
+      .signers([newAuthority])
+      .rpc();
+
+    const config = await program.account.contributionTreeConfig.fetch(
+      treeConfig
+    );
+    expect(config.authority.toBase58()).to.equal(
+      newAuthority.publicKey.toBase58()
+    );
+    expect(config.pendingAuthority.admin.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+
+    // The new authority can append straight away
+    await program.methods
+      .recordContribution(
+        Keypair.generate().publicKey,
+        Array.from(new Uint8Array(32).fill(0x11)) as number[],
+        9000,
+        Array.from(new Uint8Array(32).fill(0x22)) as number[],
+        null
+      )
+      .accounts({
+        treeConfig,
+        merkleTree: merkleTree.publicKey,
+        authority: newAuthority.publicKey,
+        noopProgram: SPL_NOOP_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+        operatorRole: null,
+      })
+      .signers([newAuthority])
+      .rpc();
   });
 });
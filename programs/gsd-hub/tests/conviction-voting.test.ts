@@ -0,0 +1,693 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+const HALF_LIFE = 3600;
+
+/**
+ * Conviction pool: governance config with conviction parameters
+ * (half-life 1h, rho 0.25%, max share 20%), a 1000-token round budget, a
+ * voter with an eligible 1_000_000 deposit and one idea requesting 100
+ * (10% share -> threshold 25% of deposits).
+ */
+async function setupConvictionPool() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initGovernanceConfig(new BN(60), new BN(120))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  await program.methods
+    .configureConviction(new BN(HALF_LIFE), 25, 2000)
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      admin,
+    })
+    .rpc();
+
+  const treasury = Keypair.generate();
+  await airdrop(provider, treasury.publicKey);
+  const treasuryTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    treasury.publicKey
+  );
+  await mintTo(provider, mint, treasuryTokenAccount, adminKeypair, BigInt(10_000));
+
+  const [revenueConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRevenueConfig(
+      treasury.publicKey,
+      Keypair.generate().publicKey,
+      mint,
+      Keypair.generate().publicKey,
+      new BN(0)
+    )
+    .accounts({
+      revenueConfig: revenueConfigPda,
+      governanceConfig: governanceConfigPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  // Voter deposit, eligible after 60s
+  const escrowTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    governanceConfigPda
+  );
+  const voter = Keypair.generate();
+  await airdrop(provider, voter.publicKey);
+  const voterTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    voter.publicKey
+  );
+  await mintTo(provider, mint, voterTokenAccount, adminKeypair, BigInt(1_000_000));
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  const [voteDepositPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+  await program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      voteDeposit: voteDepositPda,
+      depositor: voter.publicKey,
+      userTokenAccount: voterTokenAccount,
+      escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([voter])
+    .rpc();
+
+  // Long-running pool: 30 day window
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+  const [roundBudgetPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("round_budget"), ideaRoundPda.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .createRound(
+      new BN(baseTime),
+      new BN(baseTime + 2_592_000),
+      new BN(baseTime + 2_592_000 + 3600),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(1_000)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      revenueConfig: revenueConfigPda,
+      budgetMint: mint,
+      treasury: treasury.publicKey,
+      treasuryTokenAccount,
+      roundBudgetEscrow: roundBudgetPda,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .signers([treasury])
+    .rpc();
+
+  await program.methods
+    .enableConvictionVoting()
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+    })
+    .rpc();
+
+  const author = Keypair.generate();
+  await airdrop(provider, author.publicKey);
+  const [ideaPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea"),
+      ideaRoundPda.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+  await program.methods
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(100))
+    .accounts({
+      ideaRound: ideaRoundPda,
+      idea: ideaPda,
+      author: author.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([author])
+    .rpc();
+
+  const [convictionStakePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("conviction_stake"), ideaPda.toBuffer(), voter.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await warpToTimestamp(context, baseTime + 60);
+
+  return {
+    context,
+    provider,
+    program,
+    governanceConfigPda,
+    escrowTokenAccount,
+    voter,
+    voterTokenAccount,
+    voteDepositPda,
+    ideaRoundPda,
+    ideaPda,
+    convictionStakePda,
+    baseTime,
+  };
+}
+
+type Setup = Awaited<ReturnType<typeof setupConvictionPool>>;
+
+async function setStake(s: Setup, amount: number) {
+  await s.program.methods
+    .setConvictionStake(new BN(amount))
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      round: s.ideaRoundPda,
+      idea: s.ideaPda,
+      voteDeposit: s.voteDepositPda,
+      convictionStake: s.convictionStakePda,
+      voter: s.voter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+}
+
+async function updateConviction(s: Setup) {
+  await s.program.methods
+    .updateConviction()
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      ideaRound: s.ideaRoundPda,
+      idea: s.ideaPda,
+    })
+    .rpc();
+}
+
+/**
+ * Raise the VOTING pause flag by writing the ["pause_config"] account
+ * directly, as if the guardian had paused voting.
+ */
+function pauseVoting(s: Setup) {
+  const [pauseConfigPda, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pause_config")],
+    PROGRAM_ID
+  );
+  const data = Buffer.alloc(50);
+  createHash("sha256").update("account:PauseConfig").digest().copy(data, 0, 0, 8);
+  data.writeUInt8(1 << 0, 40); // paused = VOTING
+  data.writeUInt8(bump, 49);
+  s.context.setAccount(pauseConfigPda, {
+    lamports: 1_000_000_000,
+    data,
+    owner: PROGRAM_ID,
+    executable: false,
+  });
+  return pauseConfigPda;
+}
+
+describe("Conviction Voting", () => {
+  it("grows conviction over time and approves past the threshold", async () => {
+    const s = await setupConvictionPool();
+    await setStake(s, 1_000_000);
+
+    // No time has passed: conviction is still zero
+    await updateConviction(s);
+    let idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.conviction.toNumber()).to.equal(0);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ submitted: {} }));
+
+    // One half-life closes half the gap: 500_000 >= 250_000 threshold
+    await warpToTimestamp(s.context, s.baseTime + 60 + HALF_LIFE);
+    await updateConviction(s);
+
+    idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.conviction.toNumber()).to.equal(500_000);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ approved: {} }));
+    expect(idea.allocatedAmount.toNumber()).to.equal(100);
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.budgetAllocated.toNumber()).to.equal(100);
+  });
+
+  it("rejects staking more than the deposit", async () => {
+    const s = await setupConvictionPool();
+
+    try {
+      await setStake(s, 1_000_001);
+      expect.fail("Expected over-stake to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientDeposit");
+    }
+  });
+
+  it("locks the deposit while staked and frees it on unstake", async () => {
+    const s = await setupConvictionPool();
+    await setStake(s, 400_000);
+
+    const withdrawAccounts = {
+      governanceConfig: s.governanceConfigPda,
+      voteDeposit: s.voteDepositPda,
+      depositor: s.voter.publicKey,
+      userTokenAccount: s.voterTokenAccount,
+      escrowTokenAccount: s.escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+    };
+
+    try {
+      await s.program.methods
+        .withdrawTokens(new BN(1_000_000))
+        .accounts(withdrawAccounts)
+        .signers([s.voter])
+        .rpc();
+      expect.fail("Expected withdrawal while staked to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ActiveVotesExist");
+    }
+
+    await setStake(s, 0);
+    const deposit = await s.program.account.voteDeposit.fetch(s.voteDepositPda);
+    expect(deposit.activeVotes).to.equal(0);
+    expect(deposit.convictionStaked.toNumber()).to.equal(0);
+  });
+
+  it("accepts new ideas after submission_end while the pool is live", async () => {
+    const s = await setupConvictionPool();
+
+    await warpToTimestamp(s.context, s.baseTime + 2_592_000 + 60);
+    await s.program.methods
+      .transitionRound()
+      .accounts({
+        ideaRound: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+
+    const author = Keypair.generate();
+    await airdrop(s.provider, author.publicKey);
+    const [lateIdeaPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea"),
+        s.ideaRoundPda.toBuffer(),
+        Buffer.from(new Uint32Array([1]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .submitIdea(Array.from(new Uint8Array(32).fill(3)) as number[], new BN(100))
+      .accounts({
+        ideaRound: s.ideaRoundPda,
+        idea: lateIdeaPda,
+        author: author.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(JSON.stringify(round.status)).to.equal(JSON.stringify({ voting: {} }));
+    expect(round.ideaCount).to.equal(2);
+    const idea = await s.program.account.idea.fetch(lateIdeaPda);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ submitted: {} }));
+  });
+
+  it("keeps the pool live past voting_end until the admin closes it", async () => {
+    const s = await setupConvictionPool();
+    await setStake(s, 1_000_000);
+
+    // Well past the round's voting_end (submission_end + 1h)
+    const afterVotingEnd = s.baseTime + 2_592_000 + 7200;
+    await warpToTimestamp(s.context, afterVotingEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({
+        ideaRound: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+
+    // No deadline closes a conviction pool
+    try {
+      await s.program.methods
+        .transitionRound()
+        .accounts({
+          ideaRound: s.ideaRoundPda,
+          governanceConfig: s.governanceConfigPda,
+        })
+        .rpc();
+      expect.fail("Expected WrongRoundType error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("WrongRoundType");
+    }
+
+    await updateConviction(s);
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ approved: {} }));
+
+    await s.program.methods
+      .closeConvictionRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.provider.wallet.publicKey,
+      })
+      .rpc();
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(JSON.stringify(round.status)).to.equal(JSON.stringify({ closed: {} }));
+  });
+
+  it("unstakes conviction on emergency withdrawal", async () => {
+    const s = await setupConvictionPool();
+    await setStake(s, 400_000);
+    const pauseConfig = pauseVoting(s);
+
+    const emergencyWithdraw = (stakes: PublicKey[]) =>
+      s.program.methods
+        .emergencyWithdraw()
+        .accounts({
+          pauseConfig,
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.voteDepositPda,
+          depositor: s.voter.publicKey,
+          userTokenAccount: s.voterTokenAccount,
+          escrowTokenAccount: s.escrowTokenAccount,
+          tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          stakes.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .signers([s.voter])
+        .rpc();
+
+    // Stakes left behind would keep accruing conviction with no tokens
+    try {
+      await emergencyWithdraw([]);
+      expect.fail("Expected ConvictionStakesOutstanding error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ConvictionStakesOutstanding");
+    }
+
+    await emergencyWithdraw([s.ideaPda, s.convictionStakePda]);
+
+    const stake = await s.program.account.convictionStake.fetch(
+      s.convictionStakePda
+    );
+    expect(stake.amount.toNumber()).to.equal(0);
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.convictionStaked.toNumber()).to.equal(0);
+    const deposit = await s.program.account.voteDeposit.fetch(s.voteDepositPda);
+    expect(deposit.depositedAmount.toNumber()).to.equal(0);
+    expect(deposit.convictionStaked.toNumber()).to.equal(0);
+    expect(deposit.activeVotes).to.equal(0);
+  });
+});
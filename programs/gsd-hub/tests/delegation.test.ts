@@ -15,6 +15,9 @@ import { GsdHub } from "../../../target/types/gsd_hub";
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
 const SPL_TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
@@ -256,7 +259,7 @@ async function setupDelegation(opts?: {
   const vetoAuthority = Keypair.generate();
 
   const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("governance_config")],
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
     PROGRAM_ID
   );
 
@@ -266,11 +269,13 @@ async function setupDelegation(opts?: {
     .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
     .accounts({
       governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
       admin,
       governanceTokenMint: mint,
       vetoAuthority: vetoAuthority.publicKey,
       systemProgram: SystemProgram.programId,
     })
+    .signers([REALM])
     .rpc();
 
   const escrowKeypair = Keypair.generate();
@@ -329,6 +334,7 @@ async function setupDelegation(opts?: {
   const [ideaRoundPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
       Buffer.from(new Uint32Array([roundIndex]).buffer),
     ],
     PROGRAM_ID
@@ -340,13 +346,15 @@ async function setupDelegation(opts?: {
       new BN(submissionEnd),
       new BN(votingEnd),
       { small: {} },
-      Array.from(new Uint8Array(32).fill(1)) as number[]
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
     )
     .accounts({
       governanceConfig: governanceConfigPda,
       ideaRound: ideaRoundPda,
       admin,
       systemProgram: SystemProgram.programId,
+      operatorRole: null,
     })
     .rpc();
 
@@ -364,7 +372,7 @@ async function setupDelegation(opts?: {
   );
 
   await program.methods
-    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[])
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
     .accounts({
       ideaRound: ideaRoundPda,
       idea: ideaPda,
@@ -376,7 +384,11 @@ async function setupDelegation(opts?: {
 
   // Deposit tokens for delegator
   const [delegatorVoteDepositPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vote_deposit"), delegator.publicKey.toBuffer()],
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      delegator.publicKey.toBuffer(),
+    ],
     PROGRAM_ID
   );
 
@@ -396,7 +408,11 @@ async function setupDelegation(opts?: {
 
   // Deposit tokens for delegate
   const [delegateVoteDepositPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vote_deposit"), delegate.publicKey.toBuffer()],
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      delegate.publicKey.toBuffer(),
+    ],
     PROGRAM_ID
   );
 
@@ -416,7 +432,11 @@ async function setupDelegation(opts?: {
 
   // Delegation PDA for delegator
   const [delegationRecordPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("delegation"), delegator.publicKey.toBuffer()],
+    [
+      Buffer.from("delegation"),
+      REALM.publicKey.toBuffer(),
+      delegator.publicKey.toBuffer(),
+    ],
     PROGRAM_ID
   );
 
@@ -444,11 +464,10 @@ async function setupDelegation(opts?: {
   // Optionally enable quadratic
   if (opts?.enableQuadratic) {
     await program.methods
-      .updateGovernanceConfig(true, gatekeeperNetwork, 180)
+      .updateGovernanceConfig(true, gatekeeperNetwork, 180, false)
       .accounts({
         governanceConfig: governanceConfigPda,
         admin,
-        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
@@ -506,6 +525,7 @@ describe("Vote Delegation", () => {
         delegationRecord: s.delegationRecordPda,
         delegator: s.delegator.publicKey,
         delegate: s.delegate.publicKey,
+        delegateProfile: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([s.delegator])
@@ -538,6 +558,7 @@ describe("Vote Delegation", () => {
         delegationRecord: s.delegationRecordPda,
         delegator: s.delegator.publicKey,
         delegate: s.delegate.publicKey,
+        delegateProfile: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([s.delegator])
@@ -546,7 +567,7 @@ describe("Vote Delegation", () => {
     // Attempt to vote as delegator -- pass own delegation PDA as remaining account
     try {
       await s.program.methods
-        .castVote({ yes: {} })
+        .castVote({ yes: {} }, null)
         .accounts({
           idea: s.ideaPda,
           round: s.ideaRoundPda,
@@ -554,6 +575,7 @@ describe("Vote Delegation", () => {
           voteDeposit: s.delegatorVoteDepositPda,
           governanceConfig: s.governanceConfigPda,
           gatewayToken: null,
+          delegateProfile: null,
           voter: s.delegator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -584,6 +606,7 @@ describe("Vote Delegation", () => {
         delegationRecord: s.delegationRecordPda,
         delegator: s.delegator.publicKey,
         delegate: s.delegate.publicKey,
+        delegateProfile: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([s.delegator])
@@ -591,7 +614,7 @@ describe("Vote Delegation", () => {
 
     // Delegate votes with DelegationRecord as remaining account
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -599,6 +622,7 @@ describe("Vote Delegation", () => {
         voteDeposit: s.delegateVoteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.delegate.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -631,6 +655,7 @@ describe("Vote Delegation", () => {
         delegationRecord: s.delegationRecordPda,
         delegator: s.delegator.publicKey,
         delegate: s.delegate.publicKey,
+        delegateProfile: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([s.delegator])
@@ -663,7 +688,7 @@ describe("Vote Delegation", () => {
 
     // Delegator can now vote directly
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -671,6 +696,7 @@ describe("Vote Delegation", () => {
         voteDeposit: s.delegatorVoteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.delegator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -694,7 +720,7 @@ describe("Vote Delegation", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -702,11 +728,13 @@ describe("Vote Delegation", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     // Create escrow
@@ -727,11 +755,19 @@ describe("Vote Delegation", () => {
     // Actually, the VoteDeposit PDA won't exist at all. So delegate_vote will fail
     // because the vote_deposit account can't be found/derived.
     const [voteDepositPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vote_deposit"), noDepositWallet.publicKey.toBuffer()],
+      [
+        Buffer.from("vote_deposit"),
+        REALM.publicKey.toBuffer(),
+        noDepositWallet.publicKey.toBuffer(),
+      ],
       PROGRAM_ID
     );
     const [delegationRecordPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("delegation"), noDepositWallet.publicKey.toBuffer()],
+      [
+        Buffer.from("delegation"),
+        REALM.publicKey.toBuffer(),
+        noDepositWallet.publicKey.toBuffer(),
+      ],
       PROGRAM_ID
     );
 
@@ -746,6 +782,7 @@ describe("Vote Delegation", () => {
           delegationRecord: delegationRecordPda,
           delegator: noDepositWallet.publicKey,
           delegate: delegate.publicKey,
+          delegateProfile: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([noDepositWallet])
@@ -767,7 +804,7 @@ describe("Vote Delegation", () => {
 
     // Cast a vote as delegator first
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -775,6 +812,7 @@ describe("Vote Delegation", () => {
         voteDeposit: s.delegatorVoteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.delegator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -797,6 +835,7 @@ describe("Vote Delegation", () => {
           delegationRecord: s.delegationRecordPda,
           delegator: s.delegator.publicKey,
           delegate: s.delegate.publicKey,
+          delegateProfile: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([s.delegator])
@@ -806,4 +845,101 @@ describe("Vote Delegation", () => {
       expect(err.toString()).to.include("CannotDelegateWithActiveVotes");
     }
   });
+
+  it("delegate profile records votes and gates delegation", async () => {
+    const s = await setupDelegation();
+
+    const [delegateProfilePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("delegate_profile"),
+        REALM.publicKey.toBuffer(),
+        s.delegate.publicKey.toBuffer(),
+      ],
+      PROGRAM_ID
+    );
+
+    // Require registered delegates
+    await s.program.methods
+      .updateGovernanceConfig(false, s.gatekeeperNetwork, 180, true)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .delegateVote()
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.delegatorVoteDepositPda,
+          delegationRecord: s.delegationRecordPda,
+          delegator: s.delegator.publicKey,
+          delegate: s.delegate.publicKey,
+          delegateProfile: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.delegator])
+        .rpc();
+      expect.fail("Expected delegation to unregistered delegate to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DelegateProfileRequired");
+    }
+
+    await s.program.methods
+      .registerDelegate(Array.from(new Uint8Array(32).fill(9)) as number[], 0b101)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        delegateProfile: delegateProfilePda,
+        delegate: s.delegate.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.delegate])
+      .rpc();
+
+    await s.program.methods
+      .delegateVote()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        voteDeposit: s.delegatorVoteDepositPda,
+        delegationRecord: s.delegationRecordPda,
+        delegator: s.delegator.publicKey,
+        delegate: s.delegate.publicKey,
+        delegateProfile: delegateProfilePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.delegator])
+      .rpc();
+
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.delegateVoteRecordPda,
+        voteDeposit: s.delegateVoteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: delegateProfilePda,
+        voter: s.delegate.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        {
+          pubkey: s.delegationRecordPda,
+          isSigner: false,
+          isWritable: false,
+        },
+      ])
+      .signers([s.delegate])
+      .rpc();
+
+    const profile = await s.program.account.delegateProfile.fetch(
+      delegateProfilePda
+    );
+    expect(profile.topics).to.equal(0b101);
+    expect(profile.ideasVoted).to.equal(1);
+    // The round predates registration, so it is never tallied
+    expect(profile.nextRound).to.equal(1);
+  });
 });
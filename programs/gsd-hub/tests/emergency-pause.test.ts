@@ -0,0 +1,768 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+ * Full governance setup: config + round + idea + voter deposit
+ */
+async function setupFullGovernance(opts?: { depositTimelock?: number }) {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  // Create governance token mint (admin is mint authority)
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  // Veto authority
+  const vetoAuthority = Keypair.generate();
+  await airdrop(provider, vetoAuthority.publicKey);
+
+  // Derive GovernanceConfig PDA
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  const depositTimelock = opts?.depositTimelock ?? 604800; // 7 days default
+
+  // Init governance config
+  await program.methods
+    .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  // Create escrow token account owned by governance_config PDA
+  const escrowKeypair = Keypair.generate();
+  const escrowTokenAccount = await createTokenAccount(
+    provider,
+    escrowKeypair,
+    mint,
+    governanceConfigPda
+  );
+
+  // Create a voter keypair, fund it, create a token account, mint tokens
+  const voter = Keypair.generate();
+  await airdrop(provider, voter.publicKey);
+
+  const voterTokenKeypair = Keypair.generate();
+  const voterTokenAccount = await createTokenAccount(
+    provider,
+    voterTokenKeypair,
+    mint,
+    voter.publicKey
+  );
+
+  // Mint 10_000_000 tokens to voter
+  await mintTo(
+    provider,
+    mint,
+    voterTokenAccount,
+    adminKeypair,
+    BigInt(10_000_000)
+  );
+
+  // Set a base time, then create the round with timelines accommodating 7-day timelock
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  // Round with long submission + voting periods:
+  // submission_end = base + 604800 + 2000 (7 days + 2000s buffer)
+  // voting_end = base + 604800 + 4000
+  const submissionStart = baseTime;
+  const submissionEnd = baseTime + depositTimelock + 2000;
+  const votingEnd = baseTime + depositTimelock + 4000;
+
+  const roundIndex = 0;
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([roundIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(submissionStart),
+      new BN(submissionEnd),
+      new BN(votingEnd),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .rpc();
+
+  // Submit an idea
+  const submitter = Keypair.generate();
+  await airdrop(provider, submitter.publicKey);
+
+  const ideaIndex = 0;
+  const [ideaPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea"),
+      ideaRoundPda.toBuffer(),
+      Buffer.from(new Uint32Array([ideaIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
+    .accounts({
+      ideaRound: ideaRoundPda,
+      idea: ideaPda,
+      author: submitter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([submitter])
+    .rpc();
+
+  // Derive VoteDeposit PDA for voter
+  const [voteDepositPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+
+  // Derive VoteRecord PDA for voter + idea
+  const [voteRecordPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vote_record"), voter.publicKey.toBuffer(), ideaPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    adminKeypair,
+    mint,
+    mintKeypair,
+    vetoAuthority,
+    governanceConfigPda,
+    escrowTokenAccount,
+    voter,
+    voterTokenAccount,
+    voteDepositPda,
+    voteRecordPda,
+    ideaRoundPda,
+    ideaPda,
+    submitter,
+    baseTime,
+    submissionEnd,
+    votingEnd,
+    depositTimelock,
+  };
+}
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+const [PAUSE_CONFIG_PDA] = PublicKey.findProgramAddressSync(
+  [Buffer.from("pause_config")],
+  PROGRAM_ID
+);
+
+/**
+ * Point the program's ProgramData upgrade authority at `authority`.
+ *
+ * Bankrun may load the program without the upgradeable loader, so the
+ * ProgramData account is written directly: enum tag 3 (ProgramData),
+ * slot u64, Option<Pubkey> upgrade authority.
+ */
+async function setUpgradeAuthority(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  authority: PublicKey
+) {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const existing = await context.banksClient.getAccount(programData);
+  const data = existing ? Buffer.from(existing.data) : Buffer.alloc(45);
+  data.writeUInt32LE(3, 0);
+  data.writeUInt8(1, 12);
+  authority.toBuffer().copy(data, 13);
+  context.setAccount(programData, {
+    lamports: existing ? existing.lamports : 1_000_000_000,
+    data,
+    owner: BPF_LOADER_UPGRADEABLE_ID,
+    executable: false,
+  });
+  return programData;
+}
+
+/**
+ * Governance setup plus a deposited voter and an installed pause guardian
+ */
+async function setupPausable() {
+  const s = await setupFullGovernance();
+
+  await s.program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      voteDeposit: s.voteDepositPda,
+      depositor: s.voter.publicKey,
+      userTokenAccount: s.voterTokenAccount,
+      escrowTokenAccount: s.escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  const guardian = Keypair.generate();
+  const programData = await setUpgradeAuthority(s.context, s.admin);
+
+  await s.program.methods
+    .initPauseConfig(guardian.publicKey)
+    .accounts({
+      pauseConfig: PAUSE_CONFIG_PDA,
+      programData,
+      authority: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return { ...s, guardian, programData };
+}
+
+async function setPauseFlags(
+  s: Awaited<ReturnType<typeof setupPausable>>,
+  flags: number
+) {
+  await s.program.methods
+    .setPauseFlags(flags)
+    .accounts({
+      pauseConfig: PAUSE_CONFIG_PDA,
+      guardian: s.guardian.publicKey,
+    })
+    .signers([s.guardian])
+    .rpc();
+}
+
+const PAUSE_VOTING = 1 << 0;
+const PAUSE_DEPOSITS = 1 << 1;
+const PAUSE_REVENUE = 1 << 2;
+
+describe("Emergency Pause", () => {
+  it("lets the upgrade authority install the guardian", async () => {
+    const s = await setupPausable();
+
+    const pause = await s.program.account.pauseConfig.fetch(PAUSE_CONFIG_PDA);
+    expect(pause.guardian.toBase58()).to.equal(
+      s.guardian.publicKey.toBase58()
+    );
+    expect(pause.paused).to.equal(0);
+  });
+
+  it("rejects guardian installation by anyone else", async () => {
+    const s = await setupFullGovernance();
+    const programData = await setUpgradeAuthority(
+      s.context,
+      Keypair.generate().publicKey
+    );
+
+    try {
+      await s.program.methods
+        .initPauseConfig(s.admin)
+        .accounts({
+          pauseConfig: PAUSE_CONFIG_PDA,
+          programData,
+          authority: s.admin,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Expected UnauthorizedUpgradeAuthority error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedUpgradeAuthority");
+    }
+  });
+
+  it("rejects flag changes from a non-guardian", async () => {
+    const s = await setupPausable();
+    const outsider = Keypair.generate();
+    await airdrop(s.provider, outsider.publicKey);
+
+    try {
+      await s.program.methods
+        .setPauseFlags(PAUSE_VOTING)
+        .accounts({
+          pauseConfig: PAUSE_CONFIG_PDA,
+          guardian: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc();
+      expect.fail("Expected UnauthorizedGuardian error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedGuardian");
+    }
+  });
+
+  it("blocks deposits while the deposit subsystem is paused", async () => {
+    const s = await setupPausable();
+    await setPauseFlags(s, PAUSE_DEPOSITS);
+
+    try {
+      await s.program.methods
+        .depositTokens(new BN(1_000))
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.voteDepositPda,
+          depositor: s.voter.publicKey,
+          userTokenAccount: s.voterTokenAccount,
+          escrowTokenAccount: s.escrowTokenAccount,
+          tokenProgram: SPL_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.voter])
+        .rpc();
+      expect.fail("Expected SubsystemPaused error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("SubsystemPaused");
+    }
+  });
+
+  it("blocks voting while only the voting subsystem is paused", async () => {
+    const s = await setupPausable();
+    await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 1000);
+    await setPauseFlags(s, PAUSE_VOTING);
+
+    try {
+      await s.program.methods
+        .castVote({ yes: {} }, null)
+        .accounts({
+          idea: s.ideaPda,
+          round: s.ideaRoundPda,
+          voteRecord: s.voteRecordPda,
+          voteDeposit: s.voteDepositPda,
+          governanceConfig: s.governanceConfigPda,
+          gatewayToken: null,
+          delegateProfile: null,
+          voter: s.voter.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.voter])
+        .rpc();
+      expect.fail("Expected SubsystemPaused error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("SubsystemPaused");
+    }
+
+    // Clearing the flag resumes voting
+    await setPauseFlags(s, 0);
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+  });
+
+  it("leaves veto and cancellation available while voting is paused", async () => {
+    const s = await setupPausable();
+    await setPauseFlags(s, PAUSE_VOTING);
+
+    await s.program.methods
+      .vetoIdea()
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+        vetoAuthority: s.vetoAuthority.publicKey,
+      })
+      .signers([s.vetoAuthority])
+      .rpc();
+
+    await s.program.methods
+      .cancelRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ vetoed: {} }));
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(JSON.stringify(round.status)).to.equal(JSON.stringify({ cancelled: {} }));
+  });
+
+  it("rejects undefined pause bits", async () => {
+    const s = await setupPausable();
+
+    try {
+      await setPauseFlags(s, 1 << 7);
+      expect.fail("Expected InvalidPauseFlags error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidPauseFlags");
+    }
+  });
+
+  it("emergency-withdraws the full deposit and strips its live votes", async () => {
+    const s = await setupPausable();
+    await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 1000);
+
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    await setPauseFlags(s, PAUSE_VOTING | PAUSE_DEPOSITS);
+    const before = await getTokenBalance(s.provider, s.voterTokenAccount);
+
+    const withdraw = () =>
+      s.program.methods
+        .emergencyWithdraw()
+        .accounts({
+          pauseConfig: PAUSE_CONFIG_PDA,
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.voteDepositPda,
+          depositor: s.voter.publicKey,
+          userTokenAccount: s.voterTokenAccount,
+          escrowTokenAccount: s.escrowTokenAccount,
+          tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        })
+        .signers([s.voter]);
+
+    // Every active vote must be passed
+    try {
+      await withdraw().rpc();
+      expect.fail("Expected VotesOutstanding error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("VotesOutstanding");
+    }
+
+    await withdraw()
+      .remainingAccounts([
+        { pubkey: s.ideaRoundPda, isWritable: false, isSigner: false },
+        { pubkey: s.ideaPda, isWritable: true, isSigner: false },
+        { pubkey: s.voteRecordPda, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    const after = await getTokenBalance(s.provider, s.voterTokenAccount);
+    expect(Number(after - before)).to.equal(1_000_000);
+
+    const deposit = await s.program.account.voteDeposit.fetch(s.voteDepositPda);
+    expect(deposit.depositedAmount.toNumber()).to.equal(0);
+    expect(deposit.activeVotes).to.equal(1);
+
+    // The withdrawn tokens no longer count on the undecided idea
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(idea.yesWeight.toNumber()).to.equal(0);
+    expect(idea.voterCount).to.equal(0);
+    const record = await s.program.account.voteRecord.fetch(s.voteRecordPda);
+    expect(record.weight.toNumber()).to.equal(0);
+
+    const config = await s.program.account.governanceConfig.fetch(
+      s.governanceConfigPda
+    );
+    expect(config.totalDeposited.toNumber()).to.equal(0);
+  });
+
+  it("rejects emergency withdrawal unless voting or deposits are paused", async () => {
+    const s = await setupPausable();
+
+    // A revenue-only pause leaves governance deposits alone
+    await setPauseFlags(s, PAUSE_REVENUE);
+    try {
+      await s.program.methods
+        .emergencyWithdraw()
+        .accounts({
+          pauseConfig: PAUSE_CONFIG_PDA,
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.voteDepositPda,
+          depositor: s.voter.publicKey,
+          userTokenAccount: s.voterTokenAccount,
+          escrowTokenAccount: s.escrowTokenAccount,
+          tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        })
+        .signers([s.voter])
+        .rpc();
+      expect.fail("Expected ProtocolNotPaused error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ProtocolNotPaused");
+    }
+  });
+});
@@ -14,6 +14,9 @@ import { GsdHub } from "../../../target/types/gsd_hub";
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
 const SPL_TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
@@ -148,7 +151,7 @@ describe("Governance Round Lifecycle", () => {
 
     // Derive GovernanceConfig PDA
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -159,11 +162,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(depositTimelock, executionTimelock)
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const config = await program.account.governanceConfig.fetch(
@@ -180,6 +185,55 @@ describe("Governance Round Lifecycle", () => {
     expect(config.totalDeposited.toNumber()).to.equal(0);
     expect(config.depositTimelock.toNumber()).to.equal(604800);
     expect(config.executionTimelock.toNumber()).to.equal(172800);
+    expect(config.realm.toBase58()).to.equal(REALM.publicKey.toBase58());
+  });
+
+  it("keeps governance configs of separate realms independent", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const admin = provider.wallet.publicKey;
+
+    const mintA = Keypair.generate();
+    const mintB = Keypair.generate();
+    await createMintAccount(provider, mintA, admin);
+    await createMintAccount(provider, mintB, admin);
+
+    const realms = [Keypair.generate(), Keypair.generate()];
+    const mints = [mintA.publicKey, mintB.publicKey];
+    const configPdas: PublicKey[] = [];
+
+    for (let i = 0; i < realms.length; i++) {
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config"), realms[i].publicKey.toBuffer()],
+        PROGRAM_ID
+      );
+      configPdas.push(configPda);
+
+      await program.methods
+        .initGovernanceConfig(new BN(604800), new BN(172800))
+        .accounts({
+          governanceConfig: configPda,
+          realm: realms[i].publicKey,
+          admin,
+          governanceTokenMint: mints[i],
+          vetoAuthority: Keypair.generate().publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([realms[i]])
+        .rpc();
+    }
+
+    expect(configPdas[0].toBase58()).to.not.equal(configPdas[1].toBase58());
+    const configA = await program.account.governanceConfig.fetch(configPdas[0]);
+    const configB = await program.account.governanceConfig.fetch(configPdas[1]);
+    expect(configA.governanceTokenMint.toBase58()).to.equal(
+      mintA.publicKey.toBase58()
+    );
+    expect(configB.governanceTokenMint.toBase58()).to.equal(
+      mintB.publicKey.toBase58()
+    );
+    expect(configB.realm.toBase58()).to.equal(realms[1].publicKey.toBase58());
   });
 
   it("creates an idea round", async () => {
@@ -194,7 +248,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -202,11 +256,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     // Create a round: submission_start = now, submission_end = now+3600, voting_end = now+7200
@@ -221,6 +277,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -235,13 +292,15 @@ describe("Governance Round Lifecycle", () => {
         submissionEnd,
         votingEnd,
         { small: {} },
-        contentHash
+        contentHash,
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -281,7 +340,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -289,11 +348,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const now = Math.floor(Date.now() / 1000);
@@ -303,6 +364,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -314,13 +376,15 @@ describe("Governance Round Lifecycle", () => {
         new BN(now + 3600),
         new BN(now + 7200),
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -344,7 +408,7 @@ describe("Governance Round Lifecycle", () => {
     ) as number[];
 
     await program.methods
-      .submitIdea(ideaContentHash)
+      .submitIdea(ideaContentHash, new BN(0))
       .accounts({
         ideaRound: ideaRoundPda,
         idea: ideaPda,
@@ -386,7 +450,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -394,11 +458,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const now = Math.floor(Date.now() / 1000);
@@ -408,6 +474,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -419,13 +486,15 @@ describe("Governance Round Lifecycle", () => {
         new BN(now + 3600),
         new BN(now + 7200),
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -447,7 +516,7 @@ describe("Governance Round Lifecycle", () => {
 
     try {
       await program.methods
-        .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[])
+        .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
         .accounts({
           ideaRound: ideaRoundPda,
           idea: ideaPda,
@@ -474,7 +543,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -482,11 +551,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const now = Math.floor(Date.now() / 1000);
@@ -496,6 +567,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -507,13 +579,15 @@ describe("Governance Round Lifecycle", () => {
         new BN(now + 3600),
         new BN(now + 7200),
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -546,7 +620,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -554,11 +628,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const now = Math.floor(Date.now() / 1000);
@@ -568,6 +644,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -580,13 +657,15 @@ describe("Governance Round Lifecycle", () => {
         new BN(now + 86400), // 24 hours from now
         new BN(now + 172800), // 48 hours from now
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -616,7 +695,7 @@ describe("Governance Round Lifecycle", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -624,11 +703,13 @@ describe("Governance Round Lifecycle", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     const now = Math.floor(Date.now() / 1000);
@@ -638,6 +719,7 @@ describe("Governance Round Lifecycle", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -649,13 +731,15 @@ describe("Governance Round Lifecycle", () => {
         new BN(now + 3600),
         new BN(now + 7200),
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -15,6 +15,9 @@ import { GsdHub } from "../../../target/types/gsd_hub";
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
 const SPL_TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
@@ -254,7 +257,7 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
 
   // Derive GovernanceConfig PDA
   const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("governance_config")],
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
     PROGRAM_ID
   );
 
@@ -265,11 +268,13 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
     .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
     .accounts({
       governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
       admin,
       governanceTokenMint: mint,
       vetoAuthority: vetoAuthority.publicKey,
       systemProgram: SystemProgram.programId,
     })
+    .signers([REALM])
     .rpc();
 
   // Create escrow token account owned by governance_config PDA
@@ -317,6 +322,7 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
   const [ideaRoundPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
       Buffer.from(new Uint32Array([roundIndex]).buffer),
     ],
     PROGRAM_ID
@@ -328,13 +334,15 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
       new BN(submissionEnd),
       new BN(votingEnd),
       { small: {} },
-      Array.from(new Uint8Array(32).fill(1)) as number[]
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
     )
     .accounts({
       governanceConfig: governanceConfigPda,
       ideaRound: ideaRoundPda,
       admin,
       systemProgram: SystemProgram.programId,
+      operatorRole: null,
     })
     .rpc();
 
@@ -353,7 +361,7 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
   );
 
   await program.methods
-    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[])
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
     .accounts({
       ideaRound: ideaRoundPda,
       idea: ideaPda,
@@ -365,7 +373,11 @@ async function setupFullGovernance(opts?: { depositTimelock?: number }) {
 
   // Derive VoteDeposit PDA for voter
   const [voteDepositPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vote_deposit"), voter.publicKey.toBuffer()],
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
     PROGRAM_ID
   );
 
@@ -456,7 +468,7 @@ describe("Governance Voting and Token Escrow", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -466,11 +478,13 @@ describe("Governance Voting and Token Escrow", () => {
       .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mint,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     // Escrow token account
@@ -507,6 +521,7 @@ describe("Governance Voting and Token Escrow", () => {
     const [ideaRoundPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([roundIndex]).buffer),
       ],
       PROGRAM_ID
@@ -518,13 +533,15 @@ describe("Governance Voting and Token Escrow", () => {
         new BN(submissionEnd),
         new BN(votingEnd),
         { small: {} },
-        Array.from(new Uint8Array(32).fill(1)) as number[]
+        Array.from(new Uint8Array(32).fill(1)) as number[],
+        new BN(0)
       )
       .accounts({
         governanceConfig: governanceConfigPda,
         ideaRound: ideaRoundPda,
         admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -542,7 +559,7 @@ describe("Governance Voting and Token Escrow", () => {
     );
 
     await program.methods
-      .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[])
+      .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
       .accounts({
         ideaRound: ideaRoundPda,
         idea: ideaPda,
@@ -554,7 +571,11 @@ describe("Governance Voting and Token Escrow", () => {
 
     // Deposit tokens (at baseTime, so eligible_at = baseTime + 604800)
     const [voteDepositPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vote_deposit"), voter.publicKey.toBuffer()],
+      [
+        Buffer.from("vote_deposit"),
+        REALM.publicKey.toBuffer(),
+        voter.publicKey.toBuffer(),
+      ],
       PROGRAM_ID
     );
 
@@ -593,7 +614,7 @@ describe("Governance Voting and Token Escrow", () => {
 
     try {
       await program.methods
-        .castVote({ yes: {} })
+        .castVote({ yes: {} }, null)
         .accounts({
           idea: ideaPda,
           round: ideaRoundPda,
@@ -601,6 +622,7 @@ describe("Governance Voting and Token Escrow", () => {
           voteDeposit: voteDepositPda,
           governanceConfig: governanceConfigPda,
           gatewayToken: null,
+          delegateProfile: null,
           voter: voter.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -642,7 +664,7 @@ describe("Governance Voting and Token Escrow", () => {
     await warpToTimestamp(s.context, votingTime);
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -650,6 +672,7 @@ describe("Governance Voting and Token Escrow", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -674,6 +697,106 @@ describe("Governance Voting and Token Escrow", () => {
     expect(deposit.activeVotes).to.equal(1);
   });
 
+  it("counts only the pre-snapshot part of a deposit topped up after the round's snapshot", async () => {
+    const s = await setupFullGovernance();
+
+    const deposit = () =>
+      s.program.methods
+        .depositTokens(new BN(1_000_000))
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          voteDeposit: s.voteDepositPda,
+          depositor: s.voter.publicKey,
+          userTokenAccount: s.voterTokenAccount,
+          escrowTokenAccount: s.escrowTokenAccount,
+          tokenProgram: SPL_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.voter])
+        .rpc();
+
+    await deposit();
+
+    await warpToTimestamp(s.context, s.submissionEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: s.ideaRoundPda })
+      .rpc();
+
+    // Top up after the eligible weight snapshot
+    await deposit();
+    const toppedUp = await s.program.account.voteDeposit.fetch(s.voteDepositPda);
+    expect(toppedUp.depositedAmount.toNumber()).to.equal(2_000_000);
+    expect(toppedUp.pendingAmount.toNumber()).to.equal(1_000_000);
+
+    await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    // The tokens held before the snapshot still vote; only the top-up is left out
+    const record = await s.program.account.voteRecord.fetch(s.voteRecordPda);
+    expect(record.weight.toNumber()).to.equal(1_000_000);
+  });
+
+  it("stores a vote reason hash on the VoteRecord", async () => {
+    const s = await setupFullGovernance();
+
+    await s.program.methods
+      .depositTokens(new BN(1_000_000))
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        voteDeposit: s.voteDepositPda,
+        depositor: s.voter.publicKey,
+        userTokenAccount: s.voterTokenAccount,
+        escrowTokenAccount: s.escrowTokenAccount,
+        tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    await warpToTimestamp(s.context, s.submissionEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: s.ideaRoundPda })
+      .rpc();
+    await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+
+    const reasonHash = Array.from(new Uint8Array(32).fill(7)) as number[];
+    await s.program.methods
+      .castVote({ no: {} }, reasonHash)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    const record = await s.program.account.voteRecord.fetch(s.voteRecordPda);
+    expect(record.reasonHash).to.deep.equal(reasonHash);
+  });
+
   it("prevents double voting on same idea", async () => {
     const s = await setupFullGovernance();
 
@@ -704,7 +827,7 @@ describe("Governance Voting and Token Escrow", () => {
 
     // First vote
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -712,6 +835,7 @@ describe("Governance Voting and Token Escrow", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -721,7 +845,7 @@ describe("Governance Voting and Token Escrow", () => {
     // Attempt second vote -- PDA already in use
     try {
       await s.program.methods
-        .castVote({ no: {} })
+        .castVote({ no: {} }, null)
         .accounts({
           idea: s.ideaPda,
           round: s.ideaRoundPda,
@@ -729,6 +853,7 @@ describe("Governance Voting and Token Escrow", () => {
           voteDeposit: s.voteDepositPda,
           governanceConfig: s.governanceConfigPda,
           gatewayToken: null,
+          delegateProfile: null,
           voter: s.voter.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -775,7 +900,7 @@ describe("Governance Voting and Token Escrow", () => {
     await warpToTimestamp(s.context, votingTime);
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -783,6 +908,7 @@ describe("Governance Voting and Token Escrow", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -837,7 +963,7 @@ describe("Governance Voting and Token Escrow", () => {
     await warpToTimestamp(s.context, votingTime);
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -845,6 +971,7 @@ describe("Governance Voting and Token Escrow", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -902,7 +1029,7 @@ describe("Governance Voting and Token Escrow", () => {
     await warpToTimestamp(s.context, votingTime);
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -910,6 +1037,7 @@ describe("Governance Voting and Token Escrow", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -1015,4 +1143,66 @@ describe("Governance Voting and Token Escrow", () => {
       expect(err.toString()).to.include("UnauthorizedVeto");
     }
   });
+
+  it("closes an idea early once remaining weight cannot change the outcome", async () => {
+    const s = await setupFullGovernance();
+
+    await s.program.methods
+      .depositTokens(new BN(1_000_000))
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        voteDeposit: s.voteDepositPda,
+        depositor: s.voter.publicKey,
+        userTokenAccount: s.voterTokenAccount,
+        escrowTokenAccount: s.escrowTokenAccount,
+        tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    await warpToTimestamp(s.context, s.submissionEnd);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: s.ideaRoundPda, governanceConfig: s.governanceConfigPda })
+      .rpc();
+
+    const closeAccounts = {
+      idea: s.ideaPda,
+      round: s.ideaRoundPda,
+      governanceConfig: s.governanceConfigPda,
+    };
+
+    // Nothing cast yet: the whole snapshot is still undecided
+    try {
+      await s.program.methods.closeIdeaEarly().accounts(closeAccounts).rpc();
+      expect.fail("Expected early close without votes to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("OutcomeNotDecided");
+    }
+
+    await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+    await s.program.methods
+      .castVote({ yes: {} }, null)
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        governanceConfig: s.governanceConfigPda,
+        gatewayToken: null,
+        delegateProfile: null,
+        voter: s.voter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.voter])
+      .rpc();
+
+    // The only depositor voted Yes: no uncast weight remains
+    await s.program.methods.closeIdeaEarly().accounts(closeAccounts).rpc();
+
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ approved: {} }));
+    expect(idea.executionEligibleAt.toNumber()).to.be.greaterThan(0);
+  });
 });
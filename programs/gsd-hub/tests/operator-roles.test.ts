@@ -0,0 +1,490 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token Mint layout: 82 bytes total
+// See https://github.com/solana-labs/solana-program-library/blob/master/token/program/src/state.rs
+const MINT_SIZE = 82;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun using createAccount + Token program initializeMint
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  // Create account instruction
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction (discriminator = 0, decimals, mintAuthority, option<freezeAuthority>)
+  // Layout: [0] (1 byte instruction) + [decimals] (1 byte) + [mintAuthority] (32 bytes) + [1] (option=Some) + [mintAuthority] (32 bytes)
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1); // decimals
+  mintAuthority.toBuffer().copy(data, 2); // mint_authority
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35); // freeze_authority
+
+  const initMintIx = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// OperatorRole bits (see state/operator_role.rs)
+const ROLE_ROUND_CREATOR = 1 << 0;
+const ROLE_REVENUE_RECORDER = 1 << 1;
+const ROLE_SCORE_ORACLE = 1 << 3;
+
+const [ROLE_REGISTRY_PDA] = PublicKey.findProgramAddressSync(
+  [Buffer.from("role_registry")],
+  PROGRAM_ID
+);
+
+/**
+ * Point the program's ProgramData upgrade authority at `authority`.
+ *
+ * Bankrun may load the program without the upgradeable loader, so the
+ * ProgramData account is written directly: enum tag 3 (ProgramData),
+ * slot u64, Option<Pubkey> upgrade authority.
+ */
+function setUpgradeAuthority(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  authority: PublicKey
+) {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const data = Buffer.alloc(45);
+  data.writeUInt32LE(3, 0);
+  data.writeUInt8(1, 12);
+  authority.toBuffer().copy(data, 13);
+  context.setAccount(programData, {
+    lamports: 1_000_000_000,
+    data,
+    owner: BPF_LOADER_UPGRADEABLE_ID,
+    executable: false,
+  });
+  return programData;
+}
+
+function operatorRolePda(holder: PublicKey, realm = REALM.publicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("operator_role"), realm.toBuffer(), holder.toBuffer()],
+    PROGRAM_ID
+  )[0];
+}
+
+function realmConfigPda(realm: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), realm.toBuffer()],
+    PROGRAM_ID
+  )[0];
+}
+
+/**
+ * Governance config for REALM plus an installed role registry, both
+ * administered by the provider wallet, and a funded operator keypair.
+ */
+async function setupRoles() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+
+  const governanceConfigPda = realmConfigPda(REALM.publicKey);
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mintKeypair.publicKey,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  const programData = setUpgradeAuthority(context, admin);
+  await program.methods
+    .initRoleRegistry(admin)
+    .accounts({
+      roleRegistry: ROLE_REGISTRY_PDA,
+      programData,
+      authority: admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const operator = Keypair.generate();
+  await airdrop(provider, operator.publicKey);
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    governanceConfigPda,
+    mint: mintKeypair.publicKey,
+    operator,
+  };
+}
+
+type Setup = Awaited<ReturnType<typeof setupRoles>>;
+
+/**
+ * Grant `roles` to `holder` in `realm` as the realm admin, or as the role
+ * registry admin when `realm` is PublicKey.default
+ */
+async function setOperatorRoles(
+  s: Setup,
+  holder: PublicKey,
+  roles: number,
+  realm = REALM.publicKey
+) {
+  await s.program.methods
+    .setOperatorRoles(realm, roles)
+    .accounts({
+      roleRegistry: ROLE_REGISTRY_PDA,
+      governanceConfig: realm.equals(PublicKey.default)
+        ? null
+        : realmConfigPda(realm),
+      operatorRole: operatorRolePda(holder, realm),
+      holder,
+      admin: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+/**
+ * Create the realm's next round signed by `creator`, passing its
+ * OperatorRole account
+ */
+async function createRoundAs(s: Setup, creator: Keypair) {
+  const config = await s.program.account.governanceConfig.fetch(
+    s.governanceConfigPda
+  );
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([config.roundCount]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  const now = Math.floor(Date.now() / 1000);
+  await warpToTimestamp(s.context, now);
+
+  await s.program.methods
+    .createRound(
+      new BN(now),
+      new BN(now + 3600),
+      new BN(now + 7200),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
+    )
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin: creator.publicKey,
+      systemProgram: SystemProgram.programId,
+      operatorRole: operatorRolePda(creator.publicKey),
+    })
+    .signers([creator])
+    .rpc();
+
+  return ideaRoundPda;
+}
+
+describe("Operator Roles", () => {
+  it("lets the upgrade authority install the role registry admin", async () => {
+    const s = await setupRoles();
+
+    const registry = await s.program.account.roleRegistry.fetch(
+      ROLE_REGISTRY_PDA
+    );
+    expect(registry.admin.toBase58()).to.equal(s.admin.toBase58());
+    expect(registry.createdAt.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("rejects registry installation by anyone else", async () => {
+    const context = await createContext();
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const programData = setUpgradeAuthority(
+      context,
+      Keypair.generate().publicKey
+    );
+
+    try {
+      await program.methods
+        .initRoleRegistry(provider.wallet.publicKey)
+        .accounts({
+          roleRegistry: ROLE_REGISTRY_PDA,
+          programData,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Expected UnauthorizedUpgradeAuthority error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedUpgradeAuthority");
+    }
+  });
+
+  it("lets a round creator open rounds without being the realm admin", async () => {
+    const s = await setupRoles();
+    await setOperatorRoles(s, s.operator.publicKey, ROLE_ROUND_CREATOR);
+
+    const ideaRoundPda = await createRoundAs(s, s.operator);
+
+    const round = await s.program.account.ideaRound.fetch(ideaRoundPda);
+    expect(round.authority.toBase58()).to.equal(
+      s.operator.publicKey.toBase58()
+    );
+    const role = await s.program.account.operatorRole.fetch(
+      operatorRolePda(s.operator.publicKey)
+    );
+    expect(role.roles).to.equal(ROLE_ROUND_CREATOR);
+    expect(role.grantedBy.toBase58()).to.equal(s.admin.toBase58());
+  });
+
+  it("rejects a holder lacking the round creator role", async () => {
+    const s = await setupRoles();
+    await setOperatorRoles(s, s.operator.publicKey, ROLE_REVENUE_RECORDER);
+
+    try {
+      await createRoundAs(s, s.operator);
+      expect.fail("Expected UnauthorizedAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  it("cuts off a revoked operator", async () => {
+    const s = await setupRoles();
+    await setOperatorRoles(s, s.operator.publicKey, ROLE_ROUND_CREATOR);
+    await createRoundAs(s, s.operator);
+
+    await setOperatorRoles(s, s.operator.publicKey, 0);
+
+    try {
+      await createRoundAs(s, s.operator);
+      expect.fail("Expected UnauthorizedAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  it("rejects undefined role bits", async () => {
+    const s = await setupRoles();
+
+    try {
+      await setOperatorRoles(s, s.operator.publicKey, 1 << 7);
+      expect.fail("Expected InvalidOperatorRoles error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidOperatorRoles");
+    }
+  });
+
+  it("rejects hub-wide roles in a realm grant", async () => {
+    const s = await setupRoles();
+
+    try {
+      await setOperatorRoles(s, s.operator.publicKey, ROLE_SCORE_ORACLE);
+      expect.fail("Expected InvalidOperatorRoles error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidOperatorRoles");
+    }
+
+    await setOperatorRoles(
+      s,
+      s.operator.publicKey,
+      ROLE_SCORE_ORACLE,
+      PublicKey.default
+    );
+    const role = await s.program.account.operatorRole.fetch(
+      operatorRolePda(s.operator.publicKey, PublicKey.default)
+    );
+    expect(role.roles).to.equal(ROLE_SCORE_ORACLE);
+  });
+
+  it("scopes grants to the realm whose admin made them", async () => {
+    const s = await setupRoles();
+    const otherRealm = Keypair.generate();
+    await s.program.methods
+      .initGovernanceConfig(new BN(604800), new BN(172800))
+      .accounts({
+        governanceConfig: realmConfigPda(otherRealm.publicKey),
+        realm: otherRealm.publicKey,
+        admin: s.operator.publicKey,
+        governanceTokenMint: s.mint,
+        vetoAuthority: Keypair.generate().publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([otherRealm, s.operator])
+      .rpc();
+
+    // The other realm's admin grants in its own realm...
+    const creator = Keypair.generate();
+    await s.program.methods
+      .setOperatorRoles(otherRealm.publicKey, ROLE_ROUND_CREATOR)
+      .accounts({
+        roleRegistry: ROLE_REGISTRY_PDA,
+        governanceConfig: realmConfigPda(otherRealm.publicKey),
+        operatorRole: operatorRolePda(creator.publicKey, otherRealm.publicKey),
+        holder: creator.publicKey,
+        admin: s.operator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([s.operator])
+      .rpc();
+
+    // ...but that grant does not open rounds in REALM
+    await airdrop(s.provider, creator.publicKey);
+    try {
+      await createRoundAs(s, creator);
+      expect.fail("Expected AccountNotInitialized error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("AccountNotInitialized");
+    }
+  });
+
+  it("rejects role changes from a non-admin", async () => {
+    const s = await setupRoles();
+
+    try {
+      await s.program.methods
+        .setOperatorRoles(REALM.publicKey, ROLE_ROUND_CREATOR)
+        .accounts({
+          roleRegistry: ROLE_REGISTRY_PDA,
+          governanceConfig: s.governanceConfigPda,
+          operatorRole: operatorRolePda(s.operator.publicKey),
+          holder: s.operator.publicKey,
+          admin: s.operator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([s.operator])
+        .rpc();
+      expect.fail("Expected UnauthorizedAdmin error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,607 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+const BUDGET = 1_000;
+
+/**
+ * Governance + revenue config and a budgeted round (matching pool 1000)
+ * converted to quadratic funding, with two submitted ideas and three funded
+ * contributors.
+ */
+async function setupQfRound() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initGovernanceConfig(new BN(60), new BN(120))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  // Treasury funds the matching pool; the pool mint is the GSD mint
+  const treasury = Keypair.generate();
+  await airdrop(provider, treasury.publicKey);
+  const treasuryTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    treasury.publicKey
+  );
+  await mintTo(provider, mint, treasuryTokenAccount, adminKeypair, BigInt(10_000));
+
+  const [revenueConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRevenueConfig(
+      treasury.publicKey,
+      Keypair.generate().publicKey,
+      mint,
+      Keypair.generate().publicKey,
+      new BN(0)
+    )
+    .accounts({
+      revenueConfig: revenueConfigPda,
+      governanceConfig: governanceConfigPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+  const [roundBudgetPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("round_budget"), ideaRoundPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(baseTime),
+      new BN(baseTime + 3600),
+      new BN(baseTime + 7200),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(BUDGET)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      revenueConfig: revenueConfigPda,
+      budgetMint: mint,
+      treasury: treasury.publicKey,
+      treasuryTokenAccount,
+      roundBudgetEscrow: roundBudgetPda,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .signers([treasury])
+    .rpc();
+
+  await program.methods
+    .enableQuadraticFunding(false)
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      revenueConfig: revenueConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+    })
+    .rpc();
+
+  const author = Keypair.generate();
+  await airdrop(provider, author.publicKey);
+  const authorTokenAccount = await createTokenAccount(
+    provider,
+    Keypair.generate(),
+    mint,
+    author.publicKey
+  );
+
+  const ideaPdas: PublicKey[] = [];
+  for (const index of [0, 1]) {
+    const [ideaPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea"),
+        ideaRoundPda.toBuffer(),
+        Buffer.from(new Uint32Array([index]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await program.methods
+      .submitIdea(Array.from(new Uint8Array(32).fill(2 + index)) as number[], new BN(0))
+      .accounts({
+        ideaRound: ideaRoundPda,
+        idea: ideaPda,
+        author: author.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([author])
+      .rpc();
+    ideaPdas.push(ideaPda);
+  }
+
+  const contributors: { keypair: Keypair; tokenAccount: PublicKey }[] = [];
+  for (let i = 0; i < 3; i++) {
+    const keypair = Keypair.generate();
+    await airdrop(provider, keypair.publicKey);
+    const tokenAccount = await createTokenAccount(
+      provider,
+      Keypair.generate(),
+      mint,
+      keypair.publicKey
+    );
+    await mintTo(provider, mint, tokenAccount, adminKeypair, BigInt(1_000));
+    contributors.push({ keypair, tokenAccount });
+  }
+
+  // Open the contribution (voting) phase
+  await warpToTimestamp(context, baseTime + 3600);
+  await program.methods
+    .transitionRound()
+    .accounts({ ideaRound: ideaRoundPda, governanceConfig: governanceConfigPda })
+    .rpc();
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    mint,
+    governanceConfigPda,
+    revenueConfigPda,
+    treasuryTokenAccount,
+    ideaRoundPda,
+    roundBudgetPda,
+    ideaPdas,
+    author,
+    authorTokenAccount,
+    contributors,
+    baseTime,
+  };
+}
+
+type Setup = Awaited<ReturnType<typeof setupQfRound>>;
+
+function qfPdas(s: Setup, ideaIndex: number, contributor: PublicKey) {
+  const ideaPda = s.ideaPdas[ideaIndex];
+  const [qfTally] = PublicKey.findProgramAddressSync(
+    [Buffer.from("qf_tally"), ideaPda.toBuffer()],
+    PROGRAM_ID
+  );
+  const [qfContribution] = PublicKey.findProgramAddressSync(
+    [Buffer.from("qf_contribution"), ideaPda.toBuffer(), contributor.toBuffer()],
+    PROGRAM_ID
+  );
+  const [ideaEscrow] = PublicKey.findProgramAddressSync(
+    [Buffer.from("idea_escrow"), ideaPda.toBuffer()],
+    PROGRAM_ID
+  );
+  return { ideaPda, qfTally, qfContribution, ideaEscrow };
+}
+
+async function contribute(
+  s: Setup,
+  ideaIndex: number,
+  contributorIndex: number,
+  amount: number
+) {
+  const { keypair, tokenAccount } = s.contributors[contributorIndex];
+  const pdas = qfPdas(s, ideaIndex, keypair.publicKey);
+  await s.program.methods
+    .contributeToIdea(new BN(amount))
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      idea: pdas.ideaPda,
+      qfTally: pdas.qfTally,
+      qfContribution: pdas.qfContribution,
+      budgetMint: s.mint,
+      ideaEscrow: pdas.ideaEscrow,
+      contributorTokenAccount: tokenAccount,
+      governanceConfig: s.governanceConfigPda,
+      gatewayToken: null,
+      contributor: keypair.publicKey,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([keypair])
+    .rpc();
+}
+
+async function closeRound(s: Setup) {
+  await warpToTimestamp(s.context, s.baseTime + 7200);
+  await s.program.methods
+    .transitionRound()
+    .accounts({ ideaRound: s.ideaRoundPda, governanceConfig: s.governanceConfigPda })
+    .rpc();
+}
+
+async function finalizeMatch(s: Setup, ideaIndex: number) {
+  const pdas = qfPdas(s, ideaIndex, PublicKey.default);
+  await s.program.methods
+    .finalizeQfMatch()
+    .accounts({
+      ideaRound: s.ideaRoundPda,
+      idea: pdas.ideaPda,
+      qfTally: pdas.qfTally,
+      roundBudgetEscrow: s.roundBudgetPda,
+      ideaEscrow: pdas.ideaEscrow,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+}
+
+describe("Quadratic Funding Rounds", () => {
+  it("tracks contributions and match weight per idea", async () => {
+    const s = await setupQfRound();
+
+    // Idea 0: two contributors of 100 -> (10 + 10)^2 - (100 + 100) = 200
+    await contribute(s, 0, 0, 100);
+    await contribute(s, 0, 1, 100);
+    // Idea 1: a single contributor never earns matching weight
+    await contribute(s, 1, 2, 400);
+
+    const { qfTally } = qfPdas(s, 0, PublicKey.default);
+    const tally = await s.program.account.qfIdeaTally.fetch(qfTally);
+    expect(tally.totalContributed.toNumber()).to.equal(200);
+    expect(tally.contributorCount).to.equal(2);
+    expect(tally.matchWeight.toNumber()).to.equal(200);
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.qfTotalMatchWeight.toNumber()).to.equal(200);
+    expect(round.qfIdeaCount).to.equal(2);
+  });
+
+  it("pays the matching pool by QF weight and lets the author claim", async () => {
+    const s = await setupQfRound();
+    await contribute(s, 0, 0, 100);
+    await contribute(s, 0, 1, 100);
+    await contribute(s, 1, 2, 400);
+    await closeRound(s);
+
+    await finalizeMatch(s, 0);
+    await finalizeMatch(s, 1);
+
+    const { ideaEscrow: escrow0, qfTally: tally0 } = qfPdas(s, 0, PublicKey.default);
+    const tally = await s.program.account.qfIdeaTally.fetch(tally0);
+    expect(tally.matchedAmount.toNumber()).to.equal(BUDGET);
+    expect(Number(await getTokenBalance(s.provider, escrow0))).to.equal(200 + BUDGET);
+
+    const idea = await s.program.account.idea.fetch(s.ideaPdas[0]);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ approved: {} }));
+    expect(idea.allocatedAmount.toNumber()).to.equal(200 + BUDGET);
+
+    await s.program.methods
+      .claimIdeaPayout()
+      .accounts({
+        round: s.ideaRoundPda,
+        idea: s.ideaPdas[0],
+        ideaEscrow: escrow0,
+        authorTokenAccount: s.authorTokenAccount,
+        author: s.author.publicKey,
+        tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      })
+      .signers([s.author])
+      .rpc();
+
+    expect(
+      Number(await getTokenBalance(s.provider, s.authorTokenAccount))
+    ).to.equal(200 + BUDGET);
+  });
+
+  it("rejects vote-based finalization in a QF round", async () => {
+    const s = await setupQfRound();
+    await closeRound(s);
+
+    try {
+      await s.program.methods
+        .finalizeIdea()
+        .accounts({
+          idea: s.ideaPdas[0],
+          round: s.ideaRoundPda,
+          governanceConfig: s.governanceConfigPda,
+        })
+        .rpc();
+      expect.fail("Expected finalize_idea in QF round to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("WrongRoundType");
+    }
+  });
+
+  it("refunds contributions when the round is cancelled", async () => {
+    const s = await setupQfRound();
+    await contribute(s, 0, 0, 300);
+
+    await s.program.methods
+      .cancelRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    const { keypair, tokenAccount } = s.contributors[0];
+    const pdas = qfPdas(s, 0, keypair.publicKey);
+    await s.program.methods
+      .refundQfContribution()
+      .accounts({
+        round: s.ideaRoundPda,
+        idea: pdas.ideaPda,
+        qfContribution: pdas.qfContribution,
+        ideaEscrow: pdas.ideaEscrow,
+        contributorTokenAccount: tokenAccount,
+        contributor: keypair.publicKey,
+        tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      })
+      .signers([keypair])
+      .rpc();
+
+    expect(Number(await getTokenBalance(s.provider, tokenAccount))).to.equal(1_000);
+  });
+});
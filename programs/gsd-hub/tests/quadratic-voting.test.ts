@@ -15,6 +15,9 @@ import { GsdHub } from "../../../target/types/gsd_hub";
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
 const SPL_TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
@@ -273,7 +276,7 @@ async function setupGovernanceWithQuadratic(opts?: {
   await airdrop(provider, vetoAuthority.publicKey);
 
   const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("governance_config")],
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
     PROGRAM_ID
   );
 
@@ -283,11 +286,13 @@ async function setupGovernanceWithQuadratic(opts?: {
     .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
     .accounts({
       governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
       admin,
       governanceTokenMint: mint,
       vetoAuthority: vetoAuthority.publicKey,
       systemProgram: SystemProgram.programId,
     })
+    .signers([REALM])
     .rpc();
 
   // Create escrow
@@ -329,6 +334,7 @@ async function setupGovernanceWithQuadratic(opts?: {
   const [ideaRoundPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
       Buffer.from(new Uint32Array([roundIndex]).buffer),
     ],
     PROGRAM_ID
@@ -340,13 +346,15 @@ async function setupGovernanceWithQuadratic(opts?: {
       new BN(submissionEnd),
       new BN(votingEnd),
       { small: {} },
-      Array.from(new Uint8Array(32).fill(1)) as number[]
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
     )
     .accounts({
       governanceConfig: governanceConfigPda,
       ideaRound: ideaRoundPda,
       admin,
       systemProgram: SystemProgram.programId,
+      operatorRole: null,
     })
     .rpc();
 
@@ -364,7 +372,7 @@ async function setupGovernanceWithQuadratic(opts?: {
   );
 
   await program.methods
-    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[])
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
     .accounts({
       ideaRound: ideaRoundPda,
       idea: ideaPda,
@@ -376,7 +384,11 @@ async function setupGovernanceWithQuadratic(opts?: {
 
   // PDAs
   const [voteDepositPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vote_deposit"), voter.publicKey.toBuffer()],
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
     PROGRAM_ID
   );
   const [voteRecordPda] = PublicKey.findProgramAddressSync(
@@ -409,11 +421,10 @@ async function setupGovernanceWithQuadratic(opts?: {
   // Optionally enable quadratic voting
   if (opts?.enableQuadratic) {
     await program.methods
-      .updateGovernanceConfig(true, gatekeeperNetwork, 180)
+      .updateGovernanceConfig(true, gatekeeperNetwork, 180, false)
       .accounts({
         governanceConfig: governanceConfigPda,
         admin,
-        systemProgram: SystemProgram.programId,
       })
       .rpc();
   }
@@ -463,7 +474,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
     });
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -471,6 +482,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: null,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -479,6 +491,10 @@ describe("Quadratic Voting and Sybil Gate", () => {
 
     const record = await s.program.account.voteRecord.fetch(s.voteRecordPda);
     expect(record.weight.toNumber()).to.equal(1_000_000); // linear -- full deposit amount
+
+    // Quorum snapshot is in the same units as the tally
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.eligibleWeightSnapshot.toNumber()).to.equal(1_000_000);
   });
 
   it("quadratic voting weight is sqrt of deposited tokens", async () => {
@@ -499,7 +515,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
     );
 
     await s.program.methods
-      .castVote({ yes: {} })
+      .castVote({ yes: {} }, null)
       .accounts({
         idea: s.ideaPda,
         round: s.ideaRoundPda,
@@ -507,6 +523,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
         voteDeposit: s.voteDepositPda,
         governanceConfig: s.governanceConfigPda,
         gatewayToken: gtPubkey,
+        delegateProfile: null,
         voter: s.voter.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -516,6 +533,14 @@ describe("Quadratic Voting and Sybil Gate", () => {
     const record = await s.program.account.voteRecord.fetch(s.voteRecordPda);
     // isqrt(10000) = 100
     expect(record.weight.toNumber()).to.equal(100);
+
+    // Quorum snapshot sums isqrt per deposit rather than raw tokens
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.eligibleWeightSnapshot.toNumber()).to.equal(100);
+    const config = await s.program.account.governanceConfig.fetch(
+      s.governanceConfigPda
+    );
+    expect(config.totalQuadraticWeight.toNumber()).to.equal(100);
   });
 
   it("quadratic voting rejects without gateway token", async () => {
@@ -526,7 +551,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
 
     try {
       await s.program.methods
-        .castVote({ yes: {} })
+        .castVote({ yes: {} }, null)
         .accounts({
           idea: s.ideaPda,
           round: s.ideaRoundPda,
@@ -534,6 +559,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
           voteDeposit: s.voteDepositPda,
           governanceConfig: s.governanceConfigPda,
           gatewayToken: null,
+          delegateProfile: null,
           voter: s.voter.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -565,7 +591,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
 
     try {
       await s.program.methods
-        .castVote({ yes: {} })
+        .castVote({ yes: {} }, null)
         .accounts({
           idea: s.ideaPda,
           round: s.ideaRoundPda,
@@ -573,6 +599,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
           voteDeposit: s.voteDepositPda,
           governanceConfig: s.governanceConfigPda,
           gatewayToken: gtPubkey,
+          delegateProfile: null,
           voter: s.voter.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -604,7 +631,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
 
     try {
       await s.program.methods
-        .castVote({ yes: {} })
+        .castVote({ yes: {} }, null)
         .accounts({
           idea: s.ideaPda,
           round: s.ideaRoundPda,
@@ -612,6 +639,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
           voteDeposit: s.voteDepositPda,
           governanceConfig: s.governanceConfigPda,
           gatewayToken: gtPubkey,
+          delegateProfile: null,
           voter: s.voter.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -634,7 +662,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -642,11 +670,13 @@ describe("Quadratic Voting and Sybil Gate", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     // Verify initial defaults
@@ -659,11 +689,10 @@ describe("Quadratic Voting and Sybil Gate", () => {
     // Update
     const newNetwork = Keypair.generate().publicKey;
     await program.methods
-      .updateGovernanceConfig(true, newNetwork, 90)
+      .updateGovernanceConfig(true, newNetwork, 90, false)
       .accounts({
         governanceConfig: governanceConfigPda,
         admin,
-        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
@@ -687,7 +716,7 @@ describe("Quadratic Voting and Sybil Gate", () => {
     const vetoAuthority = Keypair.generate();
 
     const [governanceConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_config")],
+      [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -695,11 +724,13 @@ describe("Quadratic Voting and Sybil Gate", () => {
       .initGovernanceConfig(new BN(604800), new BN(172800))
       .accounts({
         governanceConfig: governanceConfigPda,
+        realm: REALM.publicKey,
         admin,
         governanceTokenMint: mintKeypair.publicKey,
         vetoAuthority: vetoAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .signers([REALM])
       .rpc();
 
     // Try to update as non-admin
@@ -708,11 +739,10 @@ describe("Quadratic Voting and Sybil Gate", () => {
 
     try {
       await program.methods
-        .updateGovernanceConfig(true, Keypair.generate().publicKey, 90)
+        .updateGovernanceConfig(true, Keypair.generate().publicKey, 90, false)
         .accounts({
           governanceConfig: governanceConfigPda,
           admin: imposter.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .signers([imposter])
         .rpc();
@@ -124,4 +124,76 @@ describe("Developer Registration", () => {
     // Authority should not change
     expect(afterUpdate.authority.toBase58()).to.equal(authority.toBase58());
   });
+
+  it("migrates a profile written with the original layout", async () => {
+    const context = await startAnchor(".", [], []);
+    const provider = new BankrunProvider(context);
+    const program = new Program<GsdHub>(IDL as GsdHub, provider);
+    const authority = provider.wallet.publicKey;
+
+    const [profilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("developer"), authority.toBuffer()],
+      PROGRAM_ID
+    );
+
+    const profileHash = new Uint8Array(32).fill(1);
+
+    await program.methods
+      .registerDeveloper(Array.from(profileHash) as number[])
+      .accounts({
+        developerProfile: profilePda,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Truncate to the original 8 + 81 byte layout, before score fields existed
+    const current = await context.banksClient.getAccount(profilePda);
+    const fullLength = current!.data.length;
+    context.setAccount(profilePda, {
+      ...current!,
+      data: current!.data.slice(0, 89),
+    });
+
+    // The short account no longer deserializes
+    try {
+      await program.account.developerProfile.fetch(profilePda);
+      expect.fail("Expected the legacy profile to fail deserialization");
+    } catch (err: any) {
+      expect(err).to.exist;
+    }
+
+    await program.methods
+      .migrateDeveloperProfile()
+      .accounts({
+        developerProfile: profilePda,
+        developerWallet: authority,
+        payer: authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const migrated = await context.banksClient.getAccount(profilePda);
+    expect(migrated!.data.length).to.equal(fullLength);
+
+    const account = await program.account.developerProfile.fetch(profilePda);
+    expect(account.profileHash).to.deep.equal(Array.from(profileHash));
+    expect(account.contributionScore.toNumber()).to.equal(0);
+
+    // A second migration has nothing to grow
+    try {
+      await program.methods
+        .migrateDeveloperProfile()
+        .accounts({
+          developerProfile: profilePda,
+          developerWallet: authority,
+          payer: authority,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Expected a current-layout profile to be rejected");
+    } catch (err: any) {
+      expect(err.toString()).to.include("AccountAlreadyMigrated");
+    }
+  });
 });
@@ -9,11 +9,15 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import { expect } from "chai";
+import { Clock } from "solana-bankrun";
 import { GsdHub } from "../../../target/types/gsd_hub";
 
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
 const SPL_TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
@@ -22,6 +26,13 @@ const SPL_TOKEN_PROGRAM_ID = new PublicKey(
 const MINT_SIZE = 82;
 const TOKEN_ACCOUNT_SIZE = 165;
 
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// OperatorRole bit for score updates
+const ROLE_SCORE_ORACLE = 1 << 3;
+
 /**
  * Create bankrun context with SPL Token program loaded from fixtures
  */
@@ -237,9 +248,32 @@ async function setupRevenueConfig(opts?: { minThreshold?: number }) {
   const usdcMint = Keypair.generate().publicKey;
   const minThreshold = opts?.minThreshold ?? 100_000_000; // 0.1 SOL default
 
+  // Revenue config hangs off a realm's governance config
+  const governanceMint = await createMintAccount(
+    provider,
+    Keypair.generate(),
+    admin
+  );
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: governanceMint,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
   // Derive RevenueConfig PDA
   const [revenueConfigPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("revenue_config")],
+    [Buffer.from("revenue_config"), REALM.publicKey.toBuffer()],
     PROGRAM_ID
   );
 
@@ -253,17 +287,22 @@ async function setupRevenueConfig(opts?: { minThreshold?: number }) {
     )
     .accounts({
       revenueConfig: revenueConfigPda,
+      governanceConfig: governanceConfigPda,
       admin,
       systemProgram: SystemProgram.programId,
     })
     .rpc();
 
+  const scoreOracle = await setupScoreOracle(context, program, admin);
+
   return {
     context,
     provider,
     program,
     admin,
     adminKeypair,
+    scoreOracle,
+    governanceConfigPda,
     revenueConfigPda,
     treasuryAddress,
     maintenanceAddress,
@@ -273,6 +312,170 @@ async function setupRevenueConfig(opts?: { minThreshold?: number }) {
   };
 }
 
+/**
+ * Make the provider wallet a score oracle: upgrade authority, role registry
+ * admin, SCORE_ORACLE holder and setter of loose per-update score limits,
+ * with REALM's half-life decaying stored scores.
+ */
+async function setupScoreOracle(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  program: Program<GsdHub>,
+  admin: PublicKey
+) {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID
+  );
+  const data = Buffer.alloc(45);
+  data.writeUInt32LE(3, 0);
+  data.writeUInt8(1, 12);
+  admin.toBuffer().copy(data, 13);
+  context.setAccount(programData, {
+    lamports: 1_000_000_000,
+    data,
+    owner: BPF_LOADER_UPGRADEABLE_ID,
+    executable: false,
+  });
+
+  const [roleRegistry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("role_registry")],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initRoleRegistry(admin)
+    .accounts({
+      roleRegistry,
+      programData,
+      authority: admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const [operatorRole] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("operator_role"),
+      PublicKey.default.toBuffer(),
+      admin.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+  await program.methods
+    .setOperatorRoles(PublicKey.default, ROLE_SCORE_ORACLE)
+    .accounts({
+      roleRegistry,
+      governanceConfig: null,
+      operatorRole,
+      holder: admin,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  const [scoreConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("score_config")],
+    PROGRAM_ID
+  );
+  await program.methods
+    .setScoreLimits(new BN(1_000_000_000), 1_000, REALM.publicKey)
+    .accounts({
+      roleRegistry,
+      scoreConfig,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return { operatorRole, scoreConfig };
+}
+
+/**
+ * Move the bankrun clock forward by `seconds`
+ */
+async function warpForward(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  seconds: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      currentClock.unixTimestamp + BigInt(seconds)
+    )
+  );
+}
+
+/**
+ * Register `contributor` and have the oracle set its score.
+ * Returns the contributor's DeveloperProfile PDA.
+ */
+async function setupScoredContributor(
+  s: Awaited<ReturnType<typeof setupRevenueConfig>>,
+  contributor: Keypair,
+  totalVerificationScore: number,
+  contributionScore: number
+) {
+  await airdrop(s.provider, contributor.publicKey);
+  const [profilePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("developer"), contributor.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await s.program.methods
+    .registerDeveloper(Array.from(new Uint8Array(32).fill(1)) as number[])
+    .accounts({
+      developerProfile: profilePda,
+      authority: contributor.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([contributor])
+    .rpc();
+
+  await s.program.methods
+    .updateContributionScore(
+      5,
+      new BN(totalVerificationScore),
+      1,
+      new BN(contributionScore),
+      1
+    )
+    .accounts({
+      developerProfile: profilePda,
+      developerWallet: contributor.publicKey,
+      authority: s.admin,
+      operatorRole: s.scoreOracle.operatorRole,
+      scoreConfig: s.scoreOracle.scoreConfig,
+      auditEntry: firstScoreAuditEntry(contributor.publicKey),
+      payer: s.admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return profilePda;
+}
+
+/**
+ * PDA of a developer's ScoreAuditEntry with the given sequence
+ */
+function scoreAuditEntry(developer: PublicKey, sequence: number) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("score_audit"),
+      developer.toBuffer(),
+      Buffer.from(new Uint32Array([sequence]).buffer),
+    ],
+    PROGRAM_ID
+  )[0];
+}
+
+/**
+ * PDA of a developer's first ScoreAuditEntry
+ */
+function firstScoreAuditEntry(developer: PublicKey) {
+  return scoreAuditEntry(developer, 0);
+}
+
 describe("Revenue Instructions", () => {
   it("initializes revenue config with 60/20/10/10 split", async () => {
     const s = await setupRevenueConfig();
@@ -305,6 +508,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -314,6 +518,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -335,6 +540,7 @@ describe("Revenue Instructions", () => {
         developerPoolVault,
         authority: s.admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -370,6 +576,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -377,6 +584,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -396,6 +604,7 @@ describe("Revenue Instructions", () => {
           developerPoolVault,
           authority: s.admin,
           systemProgram: SystemProgram.programId,
+          operatorRole: null,
         })
         .rpc();
       expect.fail("Expected BelowMinimumThreshold error");
@@ -434,7 +643,7 @@ describe("Revenue Instructions", () => {
       .updateContributionScore(
         5, // tasks_completed
         new BN(42500), // total_verification_score
-        30, // time_active_days
+        1, // time_active_days
         new BN(contributorScore), // contribution_score
         1 // score_version
       )
@@ -442,6 +651,9 @@ describe("Revenue Instructions", () => {
         developerProfile: profilePda,
         developerWallet: contributor.publicKey,
         authority: s.admin,
+        operatorRole: s.scoreOracle.operatorRole,
+        scoreConfig: s.scoreOracle.scoreConfig,
+        auditEntry: firstScoreAuditEntry(contributor.publicKey),
         payer: s.admin,
         systemProgram: SystemProgram.programId,
       })
@@ -454,6 +666,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -461,6 +674,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -479,6 +693,7 @@ describe("Revenue Instructions", () => {
         developerPoolVault,
         authority: s.admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -509,6 +724,7 @@ describe("Revenue Instructions", () => {
         revenueEvent: revenueEventPda,
         revenueClaim: revenueClaimPda,
         developerProfile: profilePda,
+        governanceConfig: s.governanceConfigPda,
         claimant: contributor.publicKey,
         developerPoolVault,
         systemProgram: SystemProgram.programId,
@@ -579,11 +795,14 @@ describe("Revenue Instructions", () => {
       .rpc();
 
     await s.program.methods
-      .updateContributionScore(5, new BN(42500), 30, new BN(500), 1)
+      .updateContributionScore(5, new BN(42500), 1, new BN(500), 1)
       .accounts({
         developerProfile: profilePda,
         developerWallet: contributor.publicKey,
         authority: s.admin,
+        operatorRole: s.scoreOracle.operatorRole,
+        scoreConfig: s.scoreOracle.scoreConfig,
+        auditEntry: firstScoreAuditEntry(contributor.publicKey),
         payer: s.admin,
         systemProgram: SystemProgram.programId,
       })
@@ -594,6 +813,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -601,6 +821,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -619,6 +840,7 @@ describe("Revenue Instructions", () => {
         developerPoolVault,
         authority: s.admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -639,6 +861,7 @@ describe("Revenue Instructions", () => {
         revenueEvent: revenueEventPda,
         revenueClaim: revenueClaimPda,
         developerProfile: profilePda,
+        governanceConfig: s.governanceConfigPda,
         claimant: contributor.publicKey,
         developerPoolVault,
         systemProgram: SystemProgram.programId,
@@ -654,6 +877,7 @@ describe("Revenue Instructions", () => {
           revenueEvent: revenueEventPda,
           revenueClaim: revenueClaimPda,
           developerProfile: profilePda,
+          governanceConfig: s.governanceConfigPda,
           claimant: contributor.publicKey,
           developerPoolVault,
           systemProgram: SystemProgram.programId,
@@ -708,6 +932,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -715,6 +940,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -733,6 +959,7 @@ describe("Revenue Instructions", () => {
         developerPoolVault,
         authority: s.admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -751,6 +978,7 @@ describe("Revenue Instructions", () => {
         gsdTokenAccount,
         gsdMint,
         tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        operatorRole: null,
       })
       .rpc();
 
@@ -797,6 +1025,7 @@ describe("Revenue Instructions", () => {
     const [revenueEventPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -804,6 +1033,7 @@ describe("Revenue Instructions", () => {
     const [developerPoolVault] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
         Buffer.from(new Uint32Array([eventIndex]).buffer),
       ],
       PROGRAM_ID
@@ -822,6 +1052,7 @@ describe("Revenue Instructions", () => {
         developerPoolVault,
         authority: s.admin,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -838,6 +1069,7 @@ describe("Revenue Instructions", () => {
         gsdTokenAccount,
         gsdMint,
         tokenProgram: SPL_TOKEN_PROGRAM_ID,
+        operatorRole: null,
       })
       .rpc();
 
@@ -855,6 +1087,7 @@ describe("Revenue Instructions", () => {
           gsdTokenAccount,
           gsdMint,
           tokenProgram: SPL_TOKEN_PROGRAM_ID,
+          operatorRole: null,
         })
         .rpc();
       expect.fail("Expected BurnAlreadyExecuted error");
@@ -862,4 +1095,207 @@ describe("Revenue Instructions", () => {
       expect(err.toString()).to.include("BurnAlreadyExecuted");
     }
   });
+
+  it("decays stored scores by the realm half-life", async () => {
+    const s = await setupRevenueConfig();
+    const contributor = Keypair.generate();
+    const profilePda = await setupScoredContributor(
+      s,
+      contributor,
+      42500,
+      1_000_000
+    );
+
+    const decay = () =>
+      s.program.methods
+        .decayContributionScore()
+        .accounts({
+          scoreConfig: s.scoreOracle.scoreConfig,
+          governanceConfig: s.governanceConfigPda,
+          developerProfile: profilePda,
+          developerWallet: contributor.publicKey,
+        })
+        .rpc();
+
+    // Exactly one default half-life (180 days) halves score and total
+    await warpForward(s.context, 180 * 86_400);
+    await decay();
+
+    const profile = await s.program.account.developerProfile.fetch(profilePda);
+    expect(profile.contributionScore.toNumber()).to.equal(500_000);
+    expect(profile.totalVerificationScore.toNumber()).to.equal(21_250);
+
+    try {
+      await decay();
+      expect.fail("Expected DecayTooSoon error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DecayTooSoon");
+    }
+  });
+
+  it("pays revenue shares on the decayed score", async () => {
+    const s = await setupRevenueConfig();
+    const contributor = Keypair.generate();
+    const profilePda = await setupScoredContributor(s, contributor, 42500, 500);
+
+    // No crank runs; the claim applies the pending half-life itself
+    await warpForward(s.context, 180 * 86_400);
+
+    const eventIndex = 0;
+    const [revenueEventPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
+        Buffer.from(new Uint32Array([eventIndex]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    const [developerPoolVault] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
+        Buffer.from(new Uint32Array([eventIndex]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .recordRevenueEvent(
+        new BN(10_000_000_000),
+        { sol: {} },
+        Array.from(new Uint8Array(64)) as number[],
+        new BN(1000)
+      )
+      .accounts({
+        revenueConfig: s.revenueConfigPda,
+        revenueEvent: revenueEventPda,
+        developerPoolVault,
+        authority: s.admin,
+        systemProgram: SystemProgram.programId,
+        operatorRole: null,
+      })
+      .rpc();
+
+    const [revenueClaimPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_claim"),
+        contributor.publicKey.toBuffer(),
+        revenueEventPda.toBuffer(),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .claimRevenueShare()
+      .accounts({
+        revenueEvent: revenueEventPda,
+        revenueClaim: revenueClaimPda,
+        developerProfile: profilePda,
+        governanceConfig: s.governanceConfigPda,
+        claimant: contributor.publicKey,
+        developerPoolVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    // 6_000_000_000 * (500 / 2) / 1000
+    const claim = await s.program.account.revenueClaim.fetch(revenueClaimPda);
+    expect(claim.contributionScore.toNumber()).to.equal(250);
+    expect(claim.amount.toNumber()).to.equal(1_500_000_000);
+  });
+
+  it("measures claims at record time despite later cranks and score writes", async () => {
+    const s = await setupRevenueConfig();
+    const contributor = Keypair.generate();
+    const profilePda = await setupScoredContributor(s, contributor, 42500, 500);
+
+    const eventIndex = 0;
+    const [revenueEventPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_event"),
+        REALM.publicKey.toBuffer(),
+        Buffer.from(new Uint32Array([eventIndex]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    const [developerPoolVault] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_vault"),
+        REALM.publicKey.toBuffer(),
+        Buffer.from(new Uint32Array([eventIndex]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .recordRevenueEvent(
+        new BN(10_000_000_000),
+        { sol: {} },
+        Array.from(new Uint8Array(64)) as number[],
+        new BN(1000)
+      )
+      .accounts({
+        revenueConfig: s.revenueConfigPda,
+        revenueEvent: revenueEventPda,
+        developerPoolVault,
+        authority: s.admin,
+        systemProgram: SystemProgram.programId,
+        operatorRole: null,
+      })
+      .rpc();
+
+    // A permissionless crank lowers the stored score before the claim
+    await warpForward(s.context, 60 * 86_400);
+    await s.program.methods
+      .decayContributionScore()
+      .accounts({
+        scoreConfig: s.scoreOracle.scoreConfig,
+        governanceConfig: s.governanceConfigPda,
+        developerProfile: profilePda,
+        developerWallet: contributor.publicKey,
+      })
+      .rpc();
+    const profile = await s.program.account.developerProfile.fetch(profilePda);
+    expect(profile.contributionScore.toNumber()).to.be.lessThan(500);
+
+    // ...and the oracle then corrects it downwards
+    await s.program.methods
+      .updateContributionScore(5, new BN(42500), 1, new BN(100), 1)
+      .accounts({
+        developerProfile: profilePda,
+        developerWallet: contributor.publicKey,
+        authority: s.admin,
+        operatorRole: s.scoreOracle.operatorRole,
+        scoreConfig: s.scoreOracle.scoreConfig,
+        auditEntry: scoreAuditEntry(contributor.publicKey, 1),
+        payer: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const [revenueClaimPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("revenue_claim"),
+        contributor.publicKey.toBuffer(),
+        revenueEventPda.toBuffer(),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .claimRevenueShare()
+      .accounts({
+        revenueEvent: revenueEventPda,
+        revenueClaim: revenueClaimPda,
+        developerProfile: profilePda,
+        governanceConfig: s.governanceConfigPda,
+        claimant: contributor.publicKey,
+        developerPoolVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    // Still measured at record time: 6_000_000_000 * 500 / 1000
+    const claim = await s.program.account.revenueClaim.fetch(revenueClaimPda);
+    expect(claim.contributionScore.toNumber()).to.equal(500);
+    expect(claim.amount.toNumber()).to.equal(3_000_000_000);
+  });
 });
@@ -0,0 +1,411 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+const MINT_SIZE = 82;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34);
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  tx.recentBlockhash = provider.context.lastBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  tx.recentBlockhash = provider.context.lastBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Governance config + one round (submission: base..base+3600, voting until base+7200)
+ * with a single submitted idea.
+ */
+async function setupRound() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const vetoAuthority = Keypair.generate();
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mintKeypair.publicKey,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(baseTime),
+      new BN(baseTime + 3600),
+      new BN(baseTime + 7200),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .rpc();
+
+  const author = Keypair.generate();
+  await airdrop(provider, author.publicKey);
+
+  const [ideaPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea"),
+      ideaRoundPda.toBuffer(),
+      Buffer.from(new Uint32Array([0]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
+    .accounts({
+      ideaRound: ideaRoundPda,
+      idea: ideaPda,
+      author: author.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([author])
+    .rpc();
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    governanceConfigPda,
+    ideaRoundPda,
+    ideaPda,
+    baseTime,
+  };
+}
+
+describe("Round Admin Controls", () => {
+  it("extends submission and voting deadlines of a live round", async () => {
+    const s = await setupRound();
+
+    await s.program.methods
+      .extendRound(new BN(s.baseTime + 5000), new BN(s.baseTime + 9000))
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.submissionEnd.toNumber()).to.equal(s.baseTime + 5000);
+    expect(round.votingEnd.toNumber()).to.equal(s.baseTime + 9000);
+  });
+
+  it("rejects shortening a deadline", async () => {
+    const s = await setupRound();
+
+    try {
+      await s.program.methods
+        .extendRound(null, new BN(s.baseTime + 5000))
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          ideaRound: s.ideaRoundPda,
+          admin: s.admin,
+        })
+        .rpc();
+      expect.fail("Expected shortened voting_end to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DeadlineNotExtended");
+    }
+  });
+
+  it("rejects extension from non-admin", async () => {
+    const s = await setupRound();
+    const attacker = Keypair.generate();
+    await airdrop(s.provider, attacker.publicKey);
+
+    try {
+      await s.program.methods
+        .extendRound(null, new BN(s.baseTime + 9000))
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          ideaRound: s.ideaRoundPda,
+          admin: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+      expect.fail("Expected non-admin extension to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  it("pauses and resumes submissions to a single round", async () => {
+    const s = await setupRound();
+
+    const setPaused = (paused: boolean) =>
+      s.program.methods
+        .setRoundPaused(paused)
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          ideaRound: s.ideaRoundPda,
+          admin: s.admin,
+        })
+        .rpc();
+
+    await setPaused(true);
+    let round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.paused).to.equal(true);
+
+    const author = Keypair.generate();
+    await airdrop(s.provider, author.publicKey);
+    const [ideaPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea"),
+        s.ideaRoundPda.toBuffer(),
+        Buffer.from(new Uint32Array([1]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    const submit = () =>
+      s.program.methods
+        .submitIdea(Array.from(new Uint8Array(32).fill(3)) as number[], new BN(0))
+        .accounts({
+          ideaRound: s.ideaRoundPda,
+          idea: ideaPda,
+          author: author.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+    try {
+      await submit();
+      expect.fail("Expected submission to a paused round to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("RoundPaused");
+    }
+
+    await setPaused(false);
+    await submit();
+
+    round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(round.paused).to.equal(false);
+    expect(round.ideaCount).to.equal(2);
+  });
+
+  it("cancels a round and voids its ideas", async () => {
+    const s = await setupRound();
+
+    await s.program.methods
+      .cancelRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(s.ideaRoundPda);
+    expect(JSON.stringify(round.status)).to.equal(
+      JSON.stringify({ cancelled: {} })
+    );
+
+    await s.program.methods
+      .voidIdea()
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+      })
+      .rpc();
+
+    const idea = await s.program.account.idea.fetch(s.ideaPda);
+    expect(JSON.stringify(idea.status)).to.equal(JSON.stringify({ void: {} }));
+  });
+
+  it("transition_round rejects a cancelled round", async () => {
+    const s = await setupRound();
+
+    await s.program.methods
+      .cancelRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: s.ideaRoundPda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    await warpToTimestamp(s.context, s.baseTime + 3600);
+
+    try {
+      await s.program.methods
+        .transitionRound()
+        .accounts({
+          ideaRound: s.ideaRoundPda,
+        })
+        .rpc();
+      expect.fail("Expected transition of cancelled round to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("RoundCancelled");
+    }
+  });
+
+  it("voiding an idea requires a cancelled round", async () => {
+    const s = await setupRound();
+
+    try {
+      await s.program.methods
+        .voidIdea()
+        .accounts({
+          idea: s.ideaPda,
+          round: s.ideaRoundPda,
+        })
+        .rpc();
+      expect.fail("Expected void on live round to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("RoundNotCancelled");
+    }
+  });
+});
@@ -0,0 +1,783 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+const SPL_ACCOUNT_COMPRESSION_ID = new PublicKey(
+  "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+);
+const SPL_NOOP_ID = new PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+);
+
+// Archive tree params: depth=3 (8 summaries max), buffer=8 -> 1304-byte account
+const MAX_DEPTH = 3;
+const MAX_BUFFER_SIZE = 8;
+const MERKLE_TREE_ACCOUNT_SIZE = 1304;
+
+// SPL Token account layouts
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create bankrun context with SPL Token, compression and noop programs
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+      {
+        name: "spl_account_compression",
+        programId: SPL_ACCOUNT_COMPRESSION_ID,
+      },
+      {
+        name: "spl_noop",
+        programId: SPL_NOOP_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeMint instruction
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Create an SPL Token Account
+ */
+async function createTokenAccount(
+  provider: BankrunProvider,
+  tokenAccountKeypair: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(
+      TOKEN_ACCOUNT_SIZE
+    );
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: tokenAccountKeypair.publicKey,
+    lamports: rent,
+    space: TOKEN_ACCOUNT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  // InitializeAccount instruction (index = 1)
+  const data = Buffer.alloc(1);
+  data.writeUInt8(1, 0);
+
+  const initAccountIx: TransactionInstruction = {
+    keys: [
+      {
+        pubkey: tokenAccountKeypair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initAccountIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, tokenAccountKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return tokenAccountKeypair.publicKey;
+}
+
+/**
+ * Mint tokens to a token account
+ */
+async function mintTo(
+  provider: BankrunProvider,
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: bigint
+): Promise<void> {
+  // MintTo instruction (index = 7)
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0);
+  data.writeBigUInt64LE(amount, 1);
+
+  const mintToIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(mintToIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintAuthority);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Airdrop lamports to a keypair in bankrun context
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Read the raw SPL token account balance from on-chain data
+ */
+async function getTokenBalance(
+  provider: BankrunProvider,
+  tokenAccount: PublicKey
+): Promise<bigint> {
+  const accountInfo = await provider.connection.getAccountInfo(tokenAccount);
+  if (!accountInfo) throw new Error("Token account not found");
+  // SPL Token Account layout: amount is at offset 64, 8 bytes LE
+  const data = accountInfo.data;
+  return data.readBigUInt64LE(64);
+}
+
+/**
+ * Allocate a Merkle tree account owned by spl-account-compression
+ */
+async function allocateMerkleTree(
+  provider: BankrunProvider,
+  merkleTree: Keypair
+) {
+  const rent = await provider.connection.getMinimumBalanceForRentExemption(
+    MERKLE_TREE_ACCOUNT_SIZE
+  );
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: provider.wallet.publicKey,
+      newAccountPubkey: merkleTree.publicKey,
+      lamports: rent,
+      space: MERKLE_TREE_ACCOUNT_SIZE,
+      programId: SPL_ACCOUNT_COMPRESSION_ID,
+    })
+  );
+  tx.recentBlockhash = provider.context.lastBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, merkleTree);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Full governance setup: config + round + idea + voter deposit
+ */
+async function setupFullGovernance(opts?: { depositTimelock?: number }) {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+  const adminKeypair = provider.wallet.payer;
+
+  // Create governance token mint (admin is mint authority)
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const mint = mintKeypair.publicKey;
+
+  // Veto authority
+  const vetoAuthority = Keypair.generate();
+  await airdrop(provider, vetoAuthority.publicKey);
+
+  // Derive GovernanceConfig PDA
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  const depositTimelock = opts?.depositTimelock ?? 604800; // 7 days default
+
+  // Init governance config
+  await program.methods
+    .initGovernanceConfig(new BN(depositTimelock), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  // Create escrow token account owned by governance_config PDA
+  const escrowKeypair = Keypair.generate();
+  const escrowTokenAccount = await createTokenAccount(
+    provider,
+    escrowKeypair,
+    mint,
+    governanceConfigPda
+  );
+
+  // Create a voter keypair, fund it, create a token account, mint tokens
+  const voter = Keypair.generate();
+  await airdrop(provider, voter.publicKey);
+
+  const voterTokenKeypair = Keypair.generate();
+  const voterTokenAccount = await createTokenAccount(
+    provider,
+    voterTokenKeypair,
+    mint,
+    voter.publicKey
+  );
+
+  // Mint 10_000_000 tokens to voter
+  await mintTo(
+    provider,
+    mint,
+    voterTokenAccount,
+    adminKeypair,
+    BigInt(10_000_000)
+  );
+
+  // Set a base time, then create the round with timelines accommodating 7-day timelock
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  // Round with long submission + voting periods:
+  // submission_end = base + 604800 + 2000 (7 days + 2000s buffer)
+  // voting_end = base + 604800 + 4000
+  const submissionStart = baseTime;
+  const submissionEnd = baseTime + depositTimelock + 2000;
+  const votingEnd = baseTime + depositTimelock + 4000;
+
+  const roundIndex = 0;
+  const [ideaRoundPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([roundIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .createRound(
+      new BN(submissionStart),
+      new BN(submissionEnd),
+      new BN(votingEnd),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(1)) as number[],
+      new BN(0)
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      ideaRound: ideaRoundPda,
+      admin,
+      systemProgram: SystemProgram.programId,
+      operatorRole: null,
+    })
+    .rpc();
+
+  // Submit an idea
+  const submitter = Keypair.generate();
+  await airdrop(provider, submitter.publicKey);
+
+  const ideaIndex = 0;
+  const [ideaPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea"),
+      ideaRoundPda.toBuffer(),
+      Buffer.from(new Uint32Array([ideaIndex]).buffer),
+    ],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .submitIdea(Array.from(new Uint8Array(32).fill(2)) as number[], new BN(0))
+    .accounts({
+      ideaRound: ideaRoundPda,
+      idea: ideaPda,
+      author: submitter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([submitter])
+    .rpc();
+
+  // Derive VoteDeposit PDA for voter
+  const [voteDepositPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("vote_deposit"),
+      REALM.publicKey.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+
+  // Derive VoteRecord PDA for voter + idea
+  const [voteRecordPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vote_record"), voter.publicKey.toBuffer(), ideaPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    adminKeypair,
+    mint,
+    mintKeypair,
+    vetoAuthority,
+    governanceConfigPda,
+    escrowTokenAccount,
+    voter,
+    voterTokenAccount,
+    voteDepositPda,
+    voteRecordPda,
+    ideaRoundPda,
+    ideaPda,
+    submitter,
+    baseTime,
+    submissionEnd,
+    votingEnd,
+    depositTimelock,
+  };
+}
+
+/**
+ * Deposit, vote yes on the single idea, and close the round.
+ */
+async function setupClosedRound() {
+  const s = await setupFullGovernance();
+
+  await s.program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      voteDeposit: s.voteDepositPda,
+      depositor: s.voter.publicKey,
+      userTokenAccount: s.voterTokenAccount,
+      escrowTokenAccount: s.escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  await warpToTimestamp(s.context, s.submissionEnd);
+  await s.program.methods
+    .transitionRound()
+    .accounts({ ideaRound: s.ideaRoundPda })
+    .rpc();
+  await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+
+  await s.program.methods
+    .castVote({ yes: {} }, null)
+    .accounts({
+      idea: s.ideaPda,
+      round: s.ideaRoundPda,
+      voteRecord: s.voteRecordPda,
+      voteDeposit: s.voteDepositPda,
+      governanceConfig: s.governanceConfigPda,
+      gatewayToken: null,
+      delegateProfile: null,
+      voter: s.voter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  await warpToTimestamp(s.context, s.votingEnd);
+  await s.program.methods
+    .transitionRound()
+    .accounts({ ideaRound: s.ideaRoundPda })
+    .rpc();
+
+  return initArchive(s);
+}
+
+/**
+ * Deposit, vote yes on the single idea, then cancel the round mid-vote.
+ */
+async function setupCancelledRound() {
+  const s = await setupFullGovernance();
+
+  await s.program.methods
+    .depositTokens(new BN(1_000_000))
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      voteDeposit: s.voteDepositPda,
+      depositor: s.voter.publicKey,
+      userTokenAccount: s.voterTokenAccount,
+      escrowTokenAccount: s.escrowTokenAccount,
+      tokenProgram: SPL_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  await warpToTimestamp(s.context, s.submissionEnd);
+  await s.program.methods
+    .transitionRound()
+    .accounts({ ideaRound: s.ideaRoundPda })
+    .rpc();
+  await warpToTimestamp(s.context, s.baseTime + s.depositTimelock + 2500);
+
+  await s.program.methods
+    .castVote({ yes: {} }, null)
+    .accounts({
+      idea: s.ideaPda,
+      round: s.ideaRoundPda,
+      voteRecord: s.voteRecordPda,
+      voteDeposit: s.voteDepositPda,
+      governanceConfig: s.governanceConfigPda,
+      gatewayToken: null,
+      delegateProfile: null,
+      voter: s.voter.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([s.voter])
+    .rpc();
+
+  await s.program.methods
+    .cancelRound()
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      ideaRound: s.ideaRoundPda,
+      admin: s.admin,
+    })
+    .rpc();
+
+  return initArchive(s);
+}
+
+/**
+ * Create the realm's archive tree.
+ */
+async function initArchive(s: Awaited<ReturnType<typeof setupFullGovernance>>) {
+  const merkleTree = Keypair.generate();
+  await allocateMerkleTree(s.provider, merkleTree);
+
+  const [archiveTreePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("archive_tree"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await s.program.methods
+    .initArchiveTree(MAX_DEPTH, MAX_BUFFER_SIZE)
+    .accounts({
+      governanceConfig: s.governanceConfigPda,
+      archiveTree: archiveTreePda,
+      merkleTree: merkleTree.publicKey,
+      admin: s.admin,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+      noopProgram: SPL_NOOP_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return { ...s, merkleTree: merkleTree.publicKey, archiveTreePda };
+}
+
+async function archiveIdea(s: Awaited<ReturnType<typeof setupClosedRound>>) {
+  await s.program.methods
+    .archiveIdea()
+    .accounts({
+      archiveTree: s.archiveTreePda,
+      merkleTree: s.merkleTree,
+      round: s.ideaRoundPda,
+      idea: s.ideaPda,
+      author: s.submitter.publicKey,
+      noopProgram: SPL_NOOP_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ID,
+    })
+    .rpc();
+}
+
+async function archiveRound(s: Awaited<ReturnType<typeof setupClosedRound>>) {
+  await s.program.methods
+    .archiveRound()
+    .accounts({
+      archiveTree: s.archiveTreePda,
+      ideaRound: s.ideaRoundPda,
+      rentPayer: s.admin,
+      roundBudgetEscrow: null,
+      tokenProgram: null,
+    })
+    .rpc();
+}
+
+describe("Round Archival", () => {
+  it("archives ideas, vote records and the round", async () => {
+    const s = await setupClosedRound();
+
+    await s.program.methods
+      .finalizeIdea()
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+
+    await s.program.methods
+      .closeVoteRecord()
+      .accounts({
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        voter: s.voter.publicKey,
+      })
+      .rpc();
+
+    const deposit = await s.program.account.voteDeposit.fetch(
+      s.voteDepositPda
+    );
+    expect(deposit.activeVotes).to.equal(0);
+
+    await archiveIdea(s);
+    await archiveRound(s);
+
+    for (const pda of [s.ideaPda, s.voteRecordPda, s.ideaRoundPda]) {
+      const info = await s.provider.connection.getAccountInfo(pda);
+      expect(info).to.be.null;
+    }
+
+    const archive = await s.program.account.archiveTreeConfig.fetch(
+      s.archiveTreePda
+    );
+    expect(archive.archivedIdeas.toNumber()).to.equal(1);
+    expect(archive.archivedRounds.toNumber()).to.equal(1);
+  });
+
+  it("rejects archiving an idea that was never finalized", async () => {
+    const s = await setupClosedRound();
+
+    try {
+      await archiveIdea(s);
+      expect.fail("Expected archival of submitted idea to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("IdeaNotFinalized");
+    }
+  });
+
+  it("keeps the round open while vote records remain", async () => {
+    const s = await setupClosedRound();
+
+    await s.program.methods
+      .finalizeIdea()
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+        governanceConfig: s.governanceConfigPda,
+      })
+      .rpc();
+    await archiveIdea(s);
+
+    try {
+      await archiveRound(s);
+      expect.fail("Expected round archival with open votes to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("VotesStillOpen");
+    }
+  });
+
+  it("archives a cancelled round once its ideas are voided", async () => {
+    const s = await setupCancelledRound();
+
+    await s.program.methods
+      .voidIdea()
+      .accounts({
+        idea: s.ideaPda,
+        round: s.ideaRoundPda,
+      })
+      .rpc();
+
+    await s.program.methods
+      .closeVoteRecord()
+      .accounts({
+        round: s.ideaRoundPda,
+        voteRecord: s.voteRecordPda,
+        voteDeposit: s.voteDepositPda,
+        voter: s.voter.publicKey,
+      })
+      .rpc();
+
+    await archiveIdea(s);
+    await archiveRound(s);
+
+    for (const pda of [s.ideaPda, s.voteRecordPda, s.ideaRoundPda]) {
+      const info = await s.provider.connection.getAccountInfo(pda);
+      expect(info).to.be.null;
+    }
+  });
+
+  it("keeps a round without ideas for delegate tallies", async () => {
+    const s = await setupClosedRound();
+
+    const [emptyRoundPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("idea_round"),
+        REALM.publicKey.toBuffer(),
+        Buffer.from(new Uint32Array([1]).buffer),
+      ],
+      PROGRAM_ID
+    );
+    await s.program.methods
+      .createRound(
+        new BN(s.votingEnd),
+        new BN(s.votingEnd + 1000),
+        new BN(s.votingEnd + 2000),
+        { small: {} },
+        Array.from(new Uint8Array(32).fill(3)) as number[],
+        new BN(0)
+      )
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        ideaRound: emptyRoundPda,
+        admin: s.admin,
+        systemProgram: SystemProgram.programId,
+        operatorRole: null,
+      })
+      .rpc();
+
+    await warpToTimestamp(s.context, s.votingEnd + 1000);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: emptyRoundPda })
+      .rpc();
+    await warpToTimestamp(s.context, s.votingEnd + 2000);
+    await s.program.methods
+      .transitionRound()
+      .accounts({ ideaRound: emptyRoundPda })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .archiveRound()
+        .accounts({
+          archiveTree: s.archiveTreePda,
+          ideaRound: emptyRoundPda,
+          rentPayer: s.admin,
+          roundBudgetEscrow: null,
+          tokenProgram: null,
+        })
+        .rpc();
+      expect.fail("Expected archival of a round without ideas to fail");
+    } catch (err: any) {
+      expect(err.toString()).to.include("EmptyRoundNotArchivable");
+    }
+  });
+});
@@ -0,0 +1,423 @@
+import { startAnchor } from "anchor-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Clock } from "solana-bankrun";
+import { GsdHub } from "../../../target/types/gsd_hub";
+
+const IDL = require("../../../target/idl/gsd_hub.json");
+const PROGRAM_ID = new PublicKey(IDL.address);
+
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+const MINT_SIZE = 82;
+
+// Monthly cadence: 30-day interval, 14-day submissions, 7-day voting
+const INTERVAL = 30 * 86400;
+const SUBMISSION_LENGTH = 14 * 86400;
+const VOTING_LENGTH = 7 * 86400;
+
+/**
+ * Create bankrun context with SPL Token program loaded from fixtures
+ */
+async function createContext() {
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
+}
+
+/**
+ * Transfer lamports from the provider wallet in bankrun
+ */
+async function airdrop(
+  provider: BankrunProvider,
+  destination: PublicKey,
+  lamports: number = 10_000_000_000
+) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: provider.wallet.publicKey,
+      toPubkey: destination,
+      lamports,
+    })
+  );
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer);
+  await provider.context.banksClient.processTransaction(tx);
+}
+
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34);
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  tx.recentBlockhash = provider.context.lastBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Set the bankrun clock to a specific unix timestamp
+ */
+async function warpToTimestamp(
+  context: Awaited<ReturnType<typeof startAnchor>>,
+  targetTimestamp: number
+) {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      BigInt(targetTimestamp)
+    )
+  );
+}
+
+/**
+ * Governance config + a monthly round schedule starting at baseTime.
+ */
+async function setupSchedule() {
+  const context = await createContext();
+  const provider = new BankrunProvider(context);
+  const program = new Program<GsdHub>(IDL as GsdHub, provider);
+  const admin = provider.wallet.publicKey;
+
+  const mintKeypair = Keypair.generate();
+  await createMintAccount(provider, mintKeypair, admin);
+  const vetoAuthority = Keypair.generate();
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mintKeypair.publicKey,
+      vetoAuthority: vetoAuthority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  const baseTime = 1_700_000_000;
+  await warpToTimestamp(context, baseTime);
+
+  const [roundSchedulePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("round_schedule"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  await program.methods
+    .initRoundSchedule(
+      new BN(baseTime),
+      new BN(INTERVAL),
+      new BN(SUBMISSION_LENGTH),
+      new BN(VOTING_LENGTH),
+      { small: {} },
+      Array.from(new Uint8Array(32).fill(7)) as number[]
+    )
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      roundSchedule: roundSchedulePda,
+      admin,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  return {
+    context,
+    provider,
+    program,
+    admin,
+    governanceConfigPda,
+    roundSchedulePda,
+    baseTime,
+  };
+}
+
+function ideaRoundPda(roundIndex: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("idea_round"),
+      REALM.publicKey.toBuffer(),
+      Buffer.from(new Uint32Array([roundIndex]).buffer),
+    ],
+    PROGRAM_ID
+  )[0];
+}
+
+describe("Recurring Round Schedules", () => {
+  it("crank creates the first round from the schedule", async () => {
+    const s = await setupSchedule();
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: null,
+        ideaRound: ideaRoundPda(0),
+        cranker: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(ideaRoundPda(0));
+    expect(round.roundIndex).to.equal(0);
+    expect(round.submissionStart.toNumber()).to.equal(s.baseTime);
+    expect(round.submissionEnd.toNumber()).to.equal(
+      s.baseTime + SUBMISSION_LENGTH
+    );
+    expect(round.votingEnd.toNumber()).to.equal(
+      s.baseTime + SUBMISSION_LENGTH + VOTING_LENGTH
+    );
+    expect(round.contentHash).to.deep.equal(
+      Array.from(new Uint8Array(32).fill(7))
+    );
+
+    const config = await s.program.account.governanceConfig.fetch(
+      s.governanceConfigPda
+    );
+    expect(config.roundCount).to.equal(1);
+
+    const schedule = await s.program.account.roundSchedule.fetch(
+      s.roundSchedulePda
+    );
+    expect(schedule.roundsCreated).to.equal(1);
+    expect(schedule.nextSubmissionStart.toNumber()).to.equal(
+      s.baseTime + INTERVAL
+    );
+  });
+
+  it("crank rejects while the previous round accepts submissions", async () => {
+    const s = await setupSchedule();
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: null,
+        ideaRound: ideaRoundPda(0),
+        cranker: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .createScheduledRound()
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          roundSchedule: s.roundSchedulePda,
+          previousRound: ideaRoundPda(0),
+          ideaRound: ideaRoundPda(1),
+          cranker: s.admin,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Expected crank to fail while previous round is open");
+    } catch (err: any) {
+      expect(err.toString()).to.include("PreviousRoundStillOpen");
+    }
+  });
+
+  it("crank creates the next round after the previous submission window", async () => {
+    const s = await setupSchedule();
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: null,
+        ideaRound: ideaRoundPda(0),
+        cranker: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // No transition_round crank needed once the submission window has closed
+    await warpToTimestamp(s.context, s.baseTime + SUBMISSION_LENGTH);
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: ideaRoundPda(0),
+        ideaRound: ideaRoundPda(1),
+        cranker: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(ideaRoundPda(1));
+    expect(round.roundIndex).to.equal(1);
+    expect(round.submissionStart.toNumber()).to.equal(s.baseTime + INTERVAL);
+  });
+
+  it("records a third-party cranker as the round's rent payer", async () => {
+    const s = await setupSchedule();
+    const cranker = Keypair.generate();
+    await airdrop(s.provider, cranker.publicKey);
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: null,
+        ideaRound: ideaRoundPda(0),
+        cranker: cranker.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cranker])
+      .rpc();
+
+    // archive_round refunds rent_payer, not the schedule authority
+    const round = await s.program.account.ideaRound.fetch(ideaRoundPda(0));
+    expect(round.authority.toBase58()).to.equal(s.admin.toBase58());
+    expect(round.rentPayer.toBase58()).to.equal(cranker.publicKey.toBase58());
+  });
+
+  it("stamps the current admin on rounds created after a handover", async () => {
+    const s = await setupSchedule();
+    const newAdmin = Keypair.generate();
+    await airdrop(s.provider, newAdmin.publicKey);
+
+    await s.program.methods
+      .proposeGovernanceAdmin(newAdmin.publicKey, null)
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        admin: s.admin,
+      })
+      .rpc();
+    await s.program.methods
+      .acceptGovernanceAdmin()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        newAdmin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    await s.program.methods
+      .createScheduledRound()
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        previousRound: null,
+        ideaRound: ideaRoundPda(0),
+        cranker: s.admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const round = await s.program.account.ideaRound.fetch(ideaRoundPda(0));
+    expect(round.authority.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+  });
+
+  it("paused schedule rejects the crank", async () => {
+    const s = await setupSchedule();
+
+    await s.program.methods
+      .updateRoundSchedule(
+        new BN(INTERVAL),
+        new BN(SUBMISSION_LENGTH),
+        new BN(VOTING_LENGTH),
+        { small: {} },
+        Array.from(new Uint8Array(32).fill(7)) as number[],
+        false
+      )
+      .accounts({
+        governanceConfig: s.governanceConfigPda,
+        roundSchedule: s.roundSchedulePda,
+        admin: s.admin,
+      })
+      .rpc();
+
+    try {
+      await s.program.methods
+        .createScheduledRound()
+        .accounts({
+          governanceConfig: s.governanceConfigPda,
+          roundSchedule: s.roundSchedulePda,
+          previousRound: null,
+          ideaRound: ideaRoundPda(0),
+          cranker: s.admin,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Expected inactive schedule to reject crank");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ScheduleInactive");
+    }
+  });
+});
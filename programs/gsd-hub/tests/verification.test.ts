@@ -1,11 +1,12 @@
 import { startAnchor } from "anchor-bankrun";
 import { BankrunProvider } from "anchor-bankrun";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import {
   Keypair,
   PublicKey,
   SystemProgram,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import { expect } from "chai";
 import { GsdHub } from "../../../target/types/gsd_hub";
@@ -13,11 +14,29 @@ import { GsdHub } from "../../../target/types/gsd_hub";
 const IDL = require("../../../target/idl/gsd_hub.json");
 const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Realm keypair seeding every realm-scoped PDA in this file
+const REALM = Keypair.generate();
+
+const SPL_TOKEN_PROGRAM_ID = new PublicKey(
+  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+);
+
+const MINT_SIZE = 82;
+
 /**
- * Create bankrun context for verification tests
+ * Create bankrun context with SPL Token program loaded from fixtures
  */
 async function createContext() {
-  return startAnchor(".", [], []);
+  return startAnchor(
+    ".",
+    [
+      {
+        name: "spl_token",
+        programId: SPL_TOKEN_PROGRAM_ID,
+      },
+    ],
+    []
+  );
 }
 
 /**
@@ -42,6 +61,86 @@ async function airdrop(
   await provider.context.banksClient.processTransaction(tx);
 }
 
+/**
+ * Create an SPL Token Mint account in bankrun
+ */
+async function createMintAccount(
+  provider: BankrunProvider,
+  mintKeypair: Keypair,
+  mintAuthority: PublicKey,
+  decimals: number = 6
+): Promise<PublicKey> {
+  const rent =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.wallet.publicKey,
+    newAccountPubkey: mintKeypair.publicKey,
+    lamports: rent,
+    space: MINT_SIZE,
+    programId: SPL_TOKEN_PROGRAM_ID,
+  });
+
+  const data = Buffer.alloc(67);
+  data.writeUInt8(0, 0); // InitializeMint instruction index
+  data.writeUInt8(decimals, 1);
+  mintAuthority.toBuffer().copy(data, 2);
+  data.writeUInt8(1, 34); // COption::Some for freeze_authority
+  mintAuthority.toBuffer().copy(data, 35);
+
+  const initMintIx: TransactionInstruction = {
+    keys: [
+      { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: new PublicKey("SysvarRent111111111111111111111111111111111"),
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+    programId: SPL_TOKEN_PROGRAM_ID,
+    data,
+  };
+
+  const tx = new Transaction().add(createAccountIx, initMintIx);
+  const recentBlockhash = provider.context.lastBlockhash;
+  tx.recentBlockhash = recentBlockhash;
+  tx.feePayer = provider.wallet.publicKey;
+  tx.sign(provider.wallet.payer, mintKeypair);
+  await provider.context.banksClient.processTransaction(tx);
+
+  return mintKeypair.publicKey;
+}
+
+/**
+ * Initialize the realm's GovernanceConfig that verification config hangs off
+ */
+async function setupRealm(
+  provider: BankrunProvider,
+  program: Program<GsdHub>
+): Promise<PublicKey> {
+  const admin = provider.wallet.publicKey;
+  const mint = await createMintAccount(provider, Keypair.generate(), admin);
+
+  const [governanceConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config"), REALM.publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  await program.methods
+    .initGovernanceConfig(new BN(604800), new BN(172800))
+    .accounts({
+      governanceConfig: governanceConfigPda,
+      realm: REALM.publicKey,
+      admin,
+      governanceTokenMint: mint,
+      vetoAuthority: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([REALM])
+    .rpc();
+
+  return governanceConfigPda;
+}
+
 /**
  * SHA-256 of a string to produce a 32-byte task_ref
  */
@@ -62,9 +161,10 @@ async function setupVerificationConfig(opts?: {
   const provider = new BankrunProvider(context);
   const program = new Program<GsdHub>(IDL as GsdHub, provider);
   const admin = provider.wallet.publicKey;
+  const governanceConfigPda = await setupRealm(provider, program);
 
   const [verificationConfigPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("verification_config")],
+    [Buffer.from("verification_config"), REALM.publicKey.toBuffer()],
     PROGRAM_ID
   );
 
@@ -88,10 +188,12 @@ async function setupVerificationConfig(opts?: {
       planAdherenceWeight,
       minReviewers,
       7000, // consensus_threshold_bps
-      7 // review_timeout_days
+      7, // review_timeout_days
+      5000 // min_milestone_score
     )
     .accounts({
       verificationConfig: verificationConfigPda,
+      governanceConfig: governanceConfigPda,
       admin,
       systemProgram: SystemProgram.programId,
     })
@@ -102,6 +204,7 @@ async function setupVerificationConfig(opts?: {
     provider,
     program,
     admin,
+    governanceConfigPda,
     verificationConfigPda,
     confidenceThreshold,
     minReviewers,
@@ -121,7 +224,12 @@ async function submitAiVerification(
   const taskRef = sha256TaskRef(taskRefStr);
 
   const [verificationReportPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("verification"), developer.toBuffer(), Buffer.from(taskRef)],
+    [
+      Buffer.from("verification"),
+      REALM.publicKey.toBuffer(),
+      developer.toBuffer(),
+      Buffer.from(taskRef),
+    ],
     PROGRAM_ID
   );
 
@@ -141,6 +249,7 @@ async function submitAiVerification(
       verificationConfig: s.verificationConfigPda,
       verificationReport: verificationReportPda,
       systemProgram: SystemProgram.programId,
+      operatorRole: null,
     })
     .rpc();
 
@@ -173,9 +282,10 @@ describe("Verification Instructions", () => {
     const provider = new BankrunProvider(context);
     const program = new Program<GsdHub>(IDL as GsdHub, provider);
     const admin = provider.wallet.publicKey;
+    const governanceConfigPda = await setupRealm(provider, program);
 
     const [verificationConfigPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("verification_config")],
+      [Buffer.from("verification_config"), REALM.publicKey.toBuffer()],
       PROGRAM_ID
     );
 
@@ -191,10 +301,12 @@ describe("Verification Instructions", () => {
           1500, // plan_adherence_weight (total = 9000)
           3, // min_reviewers
           7000, // consensus_threshold_bps
-          7 // review_timeout_days
+          7, // review_timeout_days
+          5000 // min_milestone_score
         )
         .accounts({
           verificationConfig: verificationConfigPda,
+          governanceConfig: governanceConfigPda,
           admin,
           systemProgram: SystemProgram.programId,
         })
@@ -239,6 +351,8 @@ describe("Verification Instructions", () => {
     );
     expect(report.reviewerCount).to.equal(0);
     expect(report.configVersion).to.equal(1);
+    expect(report.realm.toBase58()).to.equal(REALM.publicKey.toBase58());
+    expect(report.verifiedBy.toBase58()).to.equal(s.admin.toBase58());
   });
 
   it("submits AI verification with low confidence (status = Pending)", async () => {
@@ -334,6 +448,7 @@ describe("Verification Instructions", () => {
     const [verificationReportPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("verification"),
+        REALM.publicKey.toBuffer(),
         s.admin.toBuffer(),
         Buffer.from(taskRef),
       ],
@@ -356,6 +471,7 @@ describe("Verification Instructions", () => {
         verificationConfig: s.verificationConfigPda,
         verificationReport: verificationReportPda,
         systemProgram: SystemProgram.programId,
+        operatorRole: null,
       })
       .rpc();
 
@@ -447,6 +563,29 @@ describe("Verification Instructions", () => {
     );
     expect(reportBefore.reviewerCount).to.equal(3);
 
+    // Only the config admin or a VERIFICATION_ORACLE may finalize
+    const outsider = Keypair.generate();
+    await airdrop(s.provider, outsider.publicKey);
+    try {
+      await s.program.methods
+        .finalizePeerVerification(
+          10000,
+          10000,
+          sha256TaskRef("outsider-hash") as number[]
+        )
+        .accounts({
+          authority: outsider.publicKey,
+          verificationReport: verificationReportPda,
+          verificationConfig: s.verificationConfigPda,
+          operatorRole: null,
+        })
+        .signers([outsider])
+        .rpc();
+      expect.fail("Expected MissingOperatorRole error");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MissingOperatorRole");
+    }
+
     // Finalize with consensus result
     const peerReportHash = sha256TaskRef("peer-consensus-hash");
 
@@ -460,6 +599,7 @@ describe("Verification Instructions", () => {
         authority: s.admin,
         verificationReport: verificationReportPda,
         verificationConfig: s.verificationConfigPda,
+        operatorRole: null,
       })
       .rpc();
 
@@ -538,6 +678,7 @@ describe("Verification Instructions", () => {
           authority: s.admin,
           verificationReport: verificationReportPda,
           verificationConfig: s.verificationConfigPda,
+          operatorRole: null,
         })
         .rpc();
       expect.fail("Expected InsufficientReviewers error");
@@ -553,10 +694,11 @@ describe("Verification Instructions", () => {
     try {
       await s.program.methods
         .initVerificationConfig(
-          5000, 2000, 2000, 2000, 2000, 2000, 2, 8000, 14
+          5000, 2000, 2000, 2000, 2000, 2000, 2, 8000, 14, 5000
         )
         .accounts({
           verificationConfig: s.verificationConfigPda,
+          governanceConfig: s.governanceConfigPda,
           admin: s.admin,
           systemProgram: SystemProgram.programId,
         })